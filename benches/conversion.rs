@@ -0,0 +1,65 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use length::{ImperialUnit::*, Length, MetricUnit::*, SubatomicUnit::*};
+
+fn conversion(c: &mut Criterion) {
+    // Parsed lengths carry their original string, that must not be cloned by the conversions.
+    let length = Length::new_string("1234.5678 m").unwrap();
+    let small = Length::new_string("0.000123 km").unwrap();
+
+    let mut group = c.benchmark_group("conversion");
+    group.bench_function("to() into the same unit", |b| {
+        b.iter(|| black_box(&length).to(black_box(Meter)))
+    });
+    group.bench_function("to() into another unit", |b| {
+        b.iter(|| black_box(&length).to(black_box(Foot)))
+    });
+    group.bench_function("to() within the same system", |b| {
+        b.iter(|| black_box(&length).to(black_box(Millimeter)))
+    });
+    group.bench_function("to() into a unit without exact factor", |b| {
+        b.iter(|| black_box(&length).to(black_box(PlanckLength)))
+    });
+    group.bench_function("to_by_ref()", |b| {
+        b.iter(|| {
+            let mut converted = Length::new_value_unit(1234.5678, Meter);
+            converted.to_by_ref(black_box(Foot));
+            converted
+        })
+    });
+    group.bench_function("normalize() to a greater unit", |b| {
+        b.iter(|| black_box(&length).normalize())
+    });
+    group.bench_function("normalize() to a smaller unit", |b| {
+        b.iter(|| black_box(&small).normalize())
+    });
+    group.finish();
+
+    let values: Vec<f64> = (0..10_000).map(f64::from).collect();
+    let mut group = c.benchmark_group("bulk conversion");
+    group.bench_function("convert_slice() of 10000 values", |b| {
+        b.iter(|| Length::convert_slice(black_box(&values), Foot, Meter))
+    });
+    #[cfg(feature = "simd")]
+    group.bench_function("simd::convert_slice() of 10000 values", |b| {
+        b.iter(|| length::simd::convert_slice(black_box(&values), Foot, Meter))
+    });
+    group.bench_function("convert_slice_in_place() of 10000 values", |b| {
+        b.iter_batched_ref(
+            || values.clone(),
+            |in_place| Length::convert_slice_in_place(black_box(in_place), Foot, Meter),
+            BatchSize::LargeInput,
+        )
+    });
+    #[cfg(feature = "simd")]
+    group.bench_function("simd::convert_slice_in_place() of 10000 values", |b| {
+        b.iter_batched_ref(
+            || values.clone(),
+            |in_place| length::simd::convert_slice_in_place(black_box(in_place), Foot, Meter),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, conversion);
+criterion_main!(benches);
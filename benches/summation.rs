@@ -0,0 +1,40 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use length::{Length, MetricUnit::*};
+
+fn track_segments() -> Vec<Length> {
+    (0..1_000_000)
+        .map(|index| {
+            if index % 2 == 0 {
+                Length::new_value_unit(12.345, Meter)
+            } else {
+                Length::new_value_unit(0.012_345, Kilometer)
+            }
+        })
+        .collect()
+}
+
+fn summation(c: &mut Criterion) {
+    let segments = track_segments();
+
+    let naive: Length = segments.iter().sum();
+    let compensated = Length::sum_compensated(segments.iter().cloned());
+    let expected = 12.345 * 1_000_000.0;
+    println!(
+        "error of the naive summation: {:e} m, compensated: {:e} m",
+        (naive.value - expected).abs(),
+        (compensated.value - expected).abs()
+    );
+
+    let mut group = c.benchmark_group("summation of 1,000,000 lengths");
+    group.sample_size(10);
+    group.bench_function("naive", |b| {
+        b.iter(|| black_box(&segments).iter().sum::<Length>())
+    });
+    group.bench_function("compensated", |b| {
+        b.iter(|| Length::sum_compensated(black_box(&segments).iter().cloned()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, summation);
+criterion_main!(benches);
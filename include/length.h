@@ -0,0 +1,49 @@
+#ifndef LENGTH_H
+#define LENGTH_H
+
+/* Generated with cbindgen from src/ffi.rs, do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// A length with its value and the code of its unit.
+typedef struct CLength {
+  double value;
+  // The code of the unit, see length_unit_code(...).
+  uint16_t unit;
+} CLength;
+
+// Parses a string like "5 km" into out. Returns false and leaves out unchanged, if the string is
+// null, not valid UTF-8 or no length.
+//
+// # Safety
+// The string has to be null or a NUL-terminated string, out has to be null or valid for writes.
+bool length_parse(const char *string, struct CLength *out);
+
+// Converts the length into the unit with the given code into out. Returns false and leaves out
+// unchanged, if a unit code is unknown.
+//
+// # Safety
+// Out has to be null or valid for writes.
+bool length_convert(struct CLength length, uint16_t unit, struct CLength *out);
+
+// Gets the code of the unit with the given symbol or name, like "km" or "miles", into out.
+// Returns false and leaves out unchanged, if the unit is unknown.
+//
+// # Safety
+// The symbol has to be null or a NUL-terminated string, out has to be null or valid for writes.
+bool length_unit_code(const char *symbol, uint16_t *out);
+
+// Formats the length like "5 km". Returns null, if the unit code is unknown. The string has to
+// be released with length_string_free(...).
+char *length_to_string(struct CLength length);
+
+// Releases a string of length_to_string(...), null is ignored.
+//
+// # Safety
+// The string has to be null or returned by length_to_string(...) and not be released before.
+void length_string_free(char *string);
+
+#endif  /* LENGTH_H */
@@ -0,0 +1,89 @@
+//! Approximate comparisons of lengths with the approx crate (feature `approx`).
+//!
+//! The other length is converted into the unit of this length first, so the absolute epsilon is
+//! given in the unit of the left-hand side, like 1e-9 km for assert_abs_diff_eq!(a_in_km, b).
+
+use ::approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{Length, Scalar};
+
+impl<T: Scalar + AbsDiffEq> AbsDiffEq for Length<T> {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    /// Checks, if the values differ by at most epsilon in the unit of this length.
+    ///
+    /// # Example
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use length::{Length, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// let measured = Length::new_value_unit(1.0005, Meter);
+    ///
+    /// assert_abs_diff_eq!(measured, Length::new_value_unit(100.0, Centimeter), epsilon = 0.001);
+    /// assert_abs_diff_eq!(Length::new_value_unit(0.3048, Meter), Length::new_value_unit(1.0, Foot));
+    /// ```
+    fn abs_diff_eq(&self, other: &Length<T>, epsilon: T::Epsilon) -> bool {
+        self.value
+            .abs_diff_eq(&other.convert(self.unit).value, epsilon)
+    }
+}
+
+impl<T: Scalar + RelativeEq> RelativeEq for Length<T> {
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    /// Checks, if the values differ by at most epsilon or by at most max_relative of the greater
+    /// value, in the unit of this length.
+    ///
+    /// # Example
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use length::{Length, AstronomicUnit::*, MetricUnit::*};
+    ///
+    /// let distance = Length::new_value_unit(1.0, Lightyear).to(Kilometer).to(Parsec);
+    ///
+    /// assert_relative_eq!(Length::new_value_unit(1.0, Lightyear), distance);
+    /// assert_relative_eq!(
+    ///     Length::new_value_unit(0.3066, Parsec),
+    ///     distance,
+    ///     max_relative = 0.001
+    /// );
+    /// ```
+    fn relative_eq(
+        &self,
+        other: &Length<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> bool {
+        self.value
+            .relative_eq(&other.convert(self.unit).value, epsilon, max_relative)
+    }
+}
+
+impl<T: Scalar + UlpsEq> UlpsEq for Length<T> {
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    /// Checks, if the values are at most max_ulps representable numbers apart, in the unit of
+    /// this length.
+    ///
+    /// # Example
+    /// ```
+    /// use approx::assert_ulps_eq;
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// let sum = Length::new_value_unit(0.1, Meter) + Length::new_value_unit(0.2, Meter);
+    ///
+    /// assert_ulps_eq!(Length::new_value_unit(300.0, Millimeter), sum);
+    /// ```
+    fn ulps_eq(&self, other: &Length<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.value
+            .ulps_eq(&other.convert(self.unit).value, epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,88 @@
+use std::fmt;
+
+use crate::{Length, MetricUnit, Unit};
+
+/// An area, that is represented by a value in the square of a length unit.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct Area {
+    pub unit: Unit,
+    pub value: f64,
+}
+
+impl Area {
+    /// Gets a new Area struct with the given value and unit.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Area, Unit, MetricUnit::*};
+    ///
+    /// let area = Area::new_value_unit(2.5, Kilometer);
+    ///
+    /// assert_eq!(2.5, area.value);
+    /// assert_eq!(Unit::Metric(Kilometer), area.unit);
+    /// ```
+    pub fn new_value_unit<T: Into<f64>, U: Into<Unit>>(value: T, unit: U) -> Self {
+        Area {
+            unit: unit.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Gets the area of a rectangle with the given sides, in the unit of the first side.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Area, Length, Unit, MetricUnit::*};
+    ///
+    /// let width = Length::new_string("2m").unwrap();
+    /// let height = Length::new_string("50cm").unwrap();
+    /// let area = Area::from_lengths(&width, &height);
+    ///
+    /// assert_eq!(1.0, area.value);
+    /// assert_eq!(Unit::Metric(Meter), area.unit);
+    /// ```
+    pub fn from_lengths(a: &Length, b: &Length) -> Self {
+        Area {
+            unit: a.unit,
+            value: a.value * b.convert(a.unit).value,
+        }
+    }
+
+    /// Converts this area into the square of the given unit and returns a new Area-struct.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Area, Unit, MetricUnit::*};
+    ///
+    /// let one_square_meter = Area::new_value_unit(1, Meter);
+    /// let in_square_centimeter = one_square_meter.to(Centimeter);
+    ///
+    /// assert_eq!(10_000.0, in_square_centimeter.value);
+    /// assert_eq!(Unit::Metric(Centimeter), in_square_centimeter.unit);
+    /// ```
+    pub fn to<T: Into<Unit>>(&self, destination_unit: T) -> Self {
+        let destination_unit = destination_unit.into();
+        let factor = Length::new_value_unit(1, self.unit)
+            .convert(destination_unit)
+            .value;
+
+        Area {
+            unit: destination_unit,
+            value: self.value * factor * factor,
+        }
+    }
+}
+
+impl Default for Area {
+    fn default() -> Area {
+        Area::new_value_unit(0, MetricUnit::Meter)
+    }
+}
+
+impl fmt::Display for Area {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}²", self.value, self.unit)
+    }
+}
@@ -0,0 +1,39 @@
+//! Support for the reflection of the bevy game engine (feature `bevy`).
+
+use bevy_reflect::TypeRegistry;
+
+use crate::{
+    Area, AstronomicUnit, CustomUnit, ExactLength, ImperialUnit, Length, MetricUnit, SubatomicUnit,
+    TraditionalUnit, TypographicUnit, Unit, UsSurveyUnit, Volume, VolumeUnit,
+};
+
+/// Registers all reflectable types of this crate in the given registry.
+///
+/// # Example
+/// ```
+/// use bevy_reflect::TypeRegistry;
+/// use length::Length;
+///
+/// let mut registry = TypeRegistry::default();
+/// length::bevy::register_types(&mut registry);
+///
+/// assert!(registry.contains(std::any::TypeId::of::<Length>()));
+/// ```
+pub fn register_types(registry: &mut TypeRegistry) {
+    registry.register::<Length>();
+    registry.register::<Area>();
+    registry.register::<Volume>();
+    registry.register::<VolumeUnit>();
+    registry.register::<ExactLength>();
+    registry.register::<Unit>();
+    registry.register::<CustomUnit>();
+    registry.register::<AstronomicUnit>();
+    registry.register::<ImperialUnit>();
+    registry.register::<MetricUnit>();
+    registry.register::<SubatomicUnit>();
+    registry.register::<TraditionalUnit>();
+    #[cfg(feature = "historical")]
+    registry.register::<crate::HistoricalUnit>();
+    registry.register::<TypographicUnit>();
+    registry.register::<UsSurveyUnit>();
+}
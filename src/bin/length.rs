@@ -0,0 +1,143 @@
+//! The length command line tool (feature `cli`), e.g. `length convert "5 km" --to mi`.
+
+use std::fs;
+use std::io::{self, BufRead, Read};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use length::{DisplayOptions, Length, Unit};
+
+#[derive(Parser)]
+#[command(
+    name = "length",
+    version,
+    about = "Converts, normalizes and sums up lengths"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Converts lengths into another unit, like "5 km" into mi.
+    Convert {
+        /// The length to convert, every line of stdin is converted without it.
+        length: Option<String>,
+        /// The unit symbol or name to convert into.
+        #[arg(short, long)]
+        to: String,
+        /// The number of decimal places.
+        #[arg(short, long)]
+        precision: Option<usize>,
+    },
+    /// Normalizes lengths into their most readable unit, like "5000 m" into 5 km.
+    Normalize {
+        /// The length to normalize, every line of stdin is normalized without it.
+        length: Option<String>,
+        /// The number of decimal places.
+        #[arg(short, long)]
+        precision: Option<usize>,
+    },
+    /// Sums up the lengths of a file with one length per line, in the unit of the first one.
+    Sum {
+        /// The file to read, stdin is read without it or for "-".
+        file: Option<String>,
+        /// The number of decimal places.
+        #[arg(short, long)]
+        precision: Option<usize>,
+    },
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("length: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Convert {
+            length,
+            to,
+            precision,
+        } => {
+            let unit: Unit = to.parse().map_err(|_| format!("unknown unit \"{}\"", to))?;
+            for_each_length(length, |length| {
+                print_length(&length.to(unit), precision);
+            })
+        }
+        Command::Normalize { length, precision } => for_each_length(length, |length| {
+            print_length(&length.normalize(), precision);
+        }),
+        Command::Sum { file, precision } => {
+            let text = match file.as_deref() {
+                None | Some("-") => {
+                    let mut text = String::new();
+                    io::stdin()
+                        .read_to_string(&mut text)
+                        .map_err(|error| format!("unable to read stdin: {}", error))?;
+                    text
+                }
+                Some(path) => fs::read_to_string(path)
+                    .map_err(|error| format!("unable to read {}: {}", path, error))?,
+            };
+
+            let mut lengths = Vec::new();
+            for (index, line) in text.lines().enumerate() {
+                if !line.trim().is_empty() {
+                    lengths.push(parse(line, index + 1)?);
+                }
+            }
+            print_length(&lengths.into_iter().sum(), precision);
+
+            Ok(())
+        }
+    }
+}
+
+/// Calls the function with the given length, or with every non-empty line of stdin without it.
+fn for_each_length<F: FnMut(Length)>(
+    length: Option<String>,
+    mut function: F,
+) -> Result<(), String> {
+    if let Some(length) = length {
+        function(parse(&length, 0)?);
+        return Ok(());
+    }
+
+    for (index, line) in io::stdin().lock().lines().enumerate() {
+        let line = line.map_err(|error| format!("unable to read stdin: {}", error))?;
+        if !line.trim().is_empty() {
+            function(parse(&line, index + 1)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses a length, the line number is 0 for a length from the arguments.
+fn parse(string: &str, line_number: usize) -> Result<Length, String> {
+    Length::parse(string).map_err(|error| match line_number {
+        0 => error.to_string(),
+        _ => format!("line {}: {}", line_number, error),
+    })
+}
+
+fn print_length(length: &Length, precision: Option<usize>) {
+    let mut text = String::new();
+    length
+        .format_into(
+            &mut text,
+            DisplayOptions {
+                precision,
+                ..DisplayOptions::default()
+            },
+        )
+        .expect("writing into a String never fails");
+    println!("{}", text);
+}
@@ -0,0 +1,155 @@
+//! Comparisons of lengths with well-known objects, like "about 3 football fields", for
+//! explainer texts.
+
+use std::sync::RwLock;
+
+use crate::Length;
+
+/// The built-in objects as singular name, plural name and length in meters, in ascending order.
+const BUILT_IN_OBJECTS: [(&str, &str, f64); 4] = [
+    ("football field", "football fields", 91.44),
+    ("Eiffel Tower", "Eiffel Towers", 330.0),
+    ("Earth circumference", "Earth circumferences", 40_075_017.0),
+    (
+        "distance to the Moon",
+        "distances to the Moon",
+        384_400_000.0,
+    ),
+];
+
+static REGISTRY: RwLock<Vec<CommonObject>> = RwLock::new(Vec::new());
+
+struct CommonObject {
+    singular: String,
+    plural: String,
+    meters: f64,
+}
+
+/// Registers an object, that Length::compare_to_common_objects() may compare lengths with, in
+/// addition to the built-in ones: a football field, the Eiffel Tower, the circumference of the
+/// Earth and the distance to the Moon.
+///
+/// Registering an already registered name again updates its plural name and length. Returns
+/// false, if the length is not a positive finite number, so the object is not registered.
+///
+/// # Example
+/// ```
+/// use length::{Length, MetricUnit::*};
+///
+/// length::register_common_object("school bus", "school buses", Length::new_value_unit(12, Meter));
+///
+/// assert_eq!(
+///     "about 3 school buses",
+///     Length::new_value_unit(36.5, Meter).compare_to_common_objects()
+/// );
+/// ```
+pub fn register_common_object<S: Into<String>, P: Into<String>>(
+    singular: S,
+    plural: P,
+    length: Length,
+) -> bool {
+    let meters = length.value_in_meters();
+    if !meters.is_finite() || meters <= 0.0 {
+        return false;
+    }
+
+    let singular: String = singular.into();
+    let plural: String = plural.into();
+    let mut registry = REGISTRY.write().unwrap();
+    match registry
+        .iter_mut()
+        .find(|object| object.singular == singular)
+    {
+        Some(object) => {
+            object.plural = plural;
+            object.meters = meters;
+        }
+        None => registry.push(CommonObject {
+            singular,
+            plural,
+            meters,
+        }),
+    }
+
+    true
+}
+
+impl Length {
+    /// Gets a readable comparison of this length with a well-known object, like "about 3
+    /// football fields". The greatest object, that is not longer than this length, is used, or
+    /// the smallest one for shorter lengths. The count is rounded to two significant digits.
+    ///
+    /// Further objects can be added with register_common_object(...).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// assert_eq!(
+    ///     "about 3 football fields",
+    ///     Length::new_value_unit(275, Meter).compare_to_common_objects()
+    /// );
+    /// assert_eq!(
+    ///     "1 football field",
+    ///     Length::new_value_unit(100, Yard).compare_to_common_objects()
+    /// );
+    /// assert_eq!(
+    ///     "1 Eiffel Tower",
+    ///     Length::new_value_unit(330, Meter).compare_to_common_objects()
+    /// );
+    /// assert_eq!(
+    ///     "about 2.5 Earth circumferences",
+    ///     Length::new_value_unit(100_000, Kilometer).compare_to_common_objects()
+    /// );
+    /// ```
+    pub fn compare_to_common_objects(&self) -> String {
+        let meters = self.value_in_meters().abs();
+        let registry = REGISTRY.read().unwrap();
+        let objects: Vec<(&str, &str, f64)> = BUILT_IN_OBJECTS
+            .iter()
+            .copied()
+            .chain(registry.iter().map(|object| {
+                (
+                    object.singular.as_str(),
+                    object.plural.as_str(),
+                    object.meters,
+                )
+            }))
+            .collect();
+
+        let by_meters = |a: &&(&str, &str, f64), b: &&(&str, &str, f64)| a.2.total_cmp(&b.2);
+        let (singular, plural, object_meters) = *objects
+            .iter()
+            .filter(|(_, _, object_meters)| *object_meters <= meters)
+            .max_by(by_meters)
+            .or_else(|| objects.iter().min_by(by_meters))
+            .expect("there are built-in objects");
+
+        let count = meters / object_meters;
+        let rounded = round_to_two_significant_digits(count);
+        let name = if rounded == 1.0 { singular } else { plural };
+
+        if rounded == count {
+            format!("{} {}", rounded, name)
+        } else {
+            format!("about {} {}", rounded, name)
+        }
+    }
+}
+
+/// Rounds a value to two significant digits, multiplying and dividing only by exact powers of
+/// ten, so the result has no rounding error, like 120000 instead of 119999.99999999999.
+fn round_to_two_significant_digits(value: f64) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+
+    let digits = 1 - value.abs().log10().floor() as i32;
+    if digits >= 0 {
+        let scale = 10_f64.powi(digits);
+        (value * scale).round() / scale
+    } else {
+        let scale = 10_f64.powi(-digits);
+        (value / scale).round() * scale
+    }
+}
@@ -0,0 +1,88 @@
+//! The configuration of the default unit, that Length::new() and Length::default() use, for
+//! applications, that standardize on a unit like feet or kilometers.
+//!
+//! The default unit is set for all threads with set_default_unit(...), and may be overridden
+//! for a scope of the current thread with with_default_unit(...). Length::const_new(...) always
+//! takes an explicit unit, so it can be used in const contexts.
+
+use std::cell::Cell;
+use std::sync::RwLock;
+
+use crate::{MetricUnit, Unit};
+
+static DEFAULT_UNIT: RwLock<Option<Unit>> = RwLock::new(None);
+
+thread_local! {
+    static SCOPED_DEFAULT_UNIT: Cell<Option<Unit>> = const { Cell::new(None) };
+}
+
+/// Sets the default unit for all threads, replacing the previous one.
+///
+/// # Example
+/// ```
+/// use length::{Length, Unit, ImperialUnit::*};
+///
+/// length::config::set_default_unit(Foot);
+///
+/// assert_eq!(Unit::Imperial(Foot), Length::new().unit);
+/// assert_eq!(Unit::Imperial(Foot), Length::default().unit);
+/// assert_eq!(0.0, Length::default().value);
+///
+/// length::config::reset_default_unit();
+/// ```
+pub fn set_default_unit<U: Into<Unit>>(unit: U) {
+    *DEFAULT_UNIT.write().unwrap() = Some(unit.into());
+}
+
+/// Resets the default unit for all threads to meters.
+pub fn reset_default_unit() {
+    *DEFAULT_UNIT.write().unwrap() = None;
+}
+
+/// Gets the default unit of the current thread, which is the unit of the innermost
+/// with_default_unit(...) scope, else the unit, that set_default_unit(...) has set, else meters.
+///
+/// # Example
+/// ```
+/// use length::{Unit, MetricUnit::*};
+///
+/// assert_eq!(Unit::Metric(Meter), length::config::default_unit());
+/// ```
+pub fn default_unit() -> Unit {
+    SCOPED_DEFAULT_UNIT
+        .get()
+        .or_else(|| *DEFAULT_UNIT.read().unwrap())
+        .unwrap_or(Unit::Metric(MetricUnit::Meter))
+}
+
+/// Calls the function with the given default unit for the current thread, other threads keep
+/// their default unit. Scopes may be nested, the previous default unit is restored afterwards,
+/// even if the function panics.
+///
+/// # Example
+/// ```
+/// use length::{Length, Unit, MetricUnit::*};
+///
+/// let length = length::config::with_default_unit(Kilometer, Length::default);
+///
+/// assert_eq!(Unit::Metric(Kilometer), length.unit);
+/// assert_eq!(Unit::Metric(Meter), Length::default().unit);
+/// ```
+pub fn with_default_unit<U: Into<Unit>, R, F: FnOnce() -> R>(unit: U, function: F) -> R {
+    let _guard = ScopeGuard {
+        previous: SCOPED_DEFAULT_UNIT.replace(Some(unit.into())),
+    };
+
+    function()
+}
+
+/// Restores the default unit of the enclosing scope, when it is dropped.
+struct ScopeGuard {
+    previous: Option<Unit>,
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        SCOPED_DEFAULT_UNIT.set(self.previous);
+    }
+}
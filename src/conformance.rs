@@ -0,0 +1,329 @@
+//! Verification of the conversion factors against authoritative reference values.
+//!
+//! The reference values are taken from the definitions published by the NIST
+//! (SI prefixes and the international yard and pound agreement of 1959) and the
+//! IAU (astronomical unit, light-year and parsec). Every entry states how many
+//! of the destination unit make up one of the source unit and the relative
+//! tolerance the crate has to stay within.
+
+use crate::{
+    AstronomicUnit, ImperialUnit, Length, MetricUnit, SubatomicUnit, TypographicUnit, Unit,
+    UsSurveyUnit,
+};
+
+/// A single authoritative conversion value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Reference {
+    pub from: Unit,
+    pub to: Unit,
+    /// The amount of `to` units in one `from` unit.
+    pub value: f64,
+    /// The allowed relative deviation from `value`.
+    pub tolerance: f64,
+    pub source: &'static str,
+}
+
+/// The outcome of checking one reference value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Check {
+    pub reference: Reference,
+    pub actual: f64,
+    pub relative_error: f64,
+}
+
+impl Check {
+    /// Returns true, if the converted value is within the stated tolerance.
+    pub fn passed(&self) -> bool {
+        self.relative_error <= self.reference.tolerance
+    }
+}
+
+/// The result of verify_all(...).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub checks: Vec<Check>,
+}
+
+impl Report {
+    /// Returns true, if every check passed.
+    pub fn is_ok(&self) -> bool {
+        self.checks.iter().all(Check::passed)
+    }
+
+    /// Gets all checks, that exceeded their tolerance.
+    pub fn failures(&self) -> Vec<Check> {
+        self.checks
+            .iter()
+            .filter(|check| !check.passed())
+            .copied()
+            .collect()
+    }
+}
+
+const NIST_SI: &str = "NIST SP 330, SI prefixes";
+const NIST_YARD: &str = "NIST, international yard and pound (1959)";
+const IAU_AU: &str = "IAU 2012 Resolution B2";
+const IAU_LY: &str = "IAU, Julian year of 365.25 days";
+const IAU_PC: &str = "IAU 2015 Resolution B2";
+const IAU_NOMINAL: &str = "IAU 2015 Resolution B3, nominal solar and terrestrial radii";
+const POSTSCRIPT: &str = "PostScript Language Reference, 1/72 inch";
+const DIDOT: &str = "DIN 16507, Didot point of 0.376065 mm";
+const CODATA: &str = "CODATA 2022 recommended values";
+const NGS_SURVEY: &str = "NIST/NGS, US survey foot of 1200/3937 m";
+const CSS: &str = "CSS Values and Units Level 3, 96 px = 1 in";
+const TEX: &str = "The TeXbook, 65536 sp = 1 pt = 1/72.27 in";
+
+const fn reference(from: Unit, to: Unit, value: f64, source: &'static str) -> Reference {
+    Reference {
+        from,
+        to,
+        value,
+        tolerance: 1e-12,
+        source,
+    }
+}
+
+/// All reference values, that are checked by verify_all(...).
+pub const REFERENCES: &[Reference] = &[
+    reference(
+        Unit::Metric(MetricUnit::Nanometer),
+        Unit::Metric(MetricUnit::Meter),
+        1e-9,
+        NIST_SI,
+    ),
+    reference(
+        Unit::Metric(MetricUnit::Angstrom),
+        Unit::Metric(MetricUnit::Nanometer),
+        0.1,
+        NIST_SI,
+    ),
+    reference(
+        Unit::Metric(MetricUnit::Millimeter),
+        Unit::Metric(MetricUnit::Meter),
+        1e-3,
+        NIST_SI,
+    ),
+    reference(
+        Unit::Metric(MetricUnit::Kilometer),
+        Unit::Metric(MetricUnit::Meter),
+        1e3,
+        NIST_SI,
+    ),
+    reference(
+        Unit::Metric(MetricUnit::Gigameter),
+        Unit::Metric(MetricUnit::Meter),
+        1e9,
+        NIST_SI,
+    ),
+    reference(
+        Unit::Subatomic(SubatomicUnit::PlanckLength),
+        Unit::Metric(MetricUnit::Meter),
+        1.616_255e-35,
+        CODATA,
+    ),
+    reference(
+        Unit::Subatomic(SubatomicUnit::BohrRadius),
+        Unit::Metric(MetricUnit::Picometer),
+        52.917_721_054_4,
+        CODATA,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::Inch),
+        Unit::Metric(MetricUnit::Meter),
+        0.0254,
+        NIST_YARD,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::Foot),
+        Unit::Metric(MetricUnit::Meter),
+        0.3048,
+        NIST_YARD,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::Yard),
+        Unit::Metric(MetricUnit::Meter),
+        0.9144,
+        NIST_YARD,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::Mile),
+        Unit::Metric(MetricUnit::Meter),
+        1_609.344,
+        NIST_YARD,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::Mile),
+        Unit::Imperial(ImperialUnit::Foot),
+        5_280.0,
+        NIST_YARD,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::Thou),
+        Unit::Metric(MetricUnit::Micrometer),
+        25.4,
+        NIST_YARD,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::Hand),
+        Unit::Metric(MetricUnit::Meter),
+        0.1016,
+        NIST_YARD,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::Rod),
+        Unit::Metric(MetricUnit::Meter),
+        5.0292,
+        NIST_YARD,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::Chain),
+        Unit::Metric(MetricUnit::Meter),
+        20.1168,
+        NIST_YARD,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::Furlong),
+        Unit::Metric(MetricUnit::Meter),
+        201.168,
+        NIST_YARD,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::League),
+        Unit::Metric(MetricUnit::Meter),
+        4_828.032,
+        NIST_YARD,
+    ),
+    reference(
+        Unit::UsSurvey(UsSurveyUnit::SurveyFoot),
+        Unit::Metric(MetricUnit::Meter),
+        1_200.0 / 3_937.0,
+        NGS_SURVEY,
+    ),
+    reference(
+        Unit::UsSurvey(UsSurveyUnit::SurveyMile),
+        Unit::Metric(MetricUnit::Meter),
+        6_336_000.0 / 3_937.0,
+        NGS_SURVEY,
+    ),
+    reference(
+        Unit::Astronomic(AstronomicUnit::AstronomicalUnit),
+        Unit::Metric(MetricUnit::Meter),
+        149_597_870_700.0,
+        IAU_AU,
+    ),
+    reference(
+        Unit::Astronomic(AstronomicUnit::Lightsecond),
+        Unit::Metric(MetricUnit::Meter),
+        299_792_458.0,
+        IAU_LY,
+    ),
+    reference(
+        Unit::Astronomic(AstronomicUnit::Lightyear),
+        Unit::Metric(MetricUnit::Meter),
+        9_460_730_472_580_800.0,
+        IAU_LY,
+    ),
+    reference(
+        Unit::Astronomic(AstronomicUnit::Lightyear),
+        Unit::Astronomic(AstronomicUnit::Lightday),
+        365.25,
+        IAU_LY,
+    ),
+    reference(
+        Unit::Astronomic(AstronomicUnit::Parsec),
+        Unit::Metric(MetricUnit::Meter),
+        30_856_775_814_913_673.0,
+        IAU_PC,
+    ),
+    reference(
+        Unit::Astronomic(AstronomicUnit::Parsec),
+        Unit::Astronomic(AstronomicUnit::AstronomicalUnit),
+        206_264.806_247_096_36,
+        IAU_PC,
+    ),
+    reference(
+        Unit::Astronomic(AstronomicUnit::Megaparsec),
+        Unit::Astronomic(AstronomicUnit::Parsec),
+        1e6,
+        IAU_PC,
+    ),
+    reference(
+        Unit::Astronomic(AstronomicUnit::Gigaparsec),
+        Unit::Astronomic(AstronomicUnit::Megaparsec),
+        1e3,
+        IAU_PC,
+    ),
+    reference(
+        Unit::Astronomic(AstronomicUnit::SolarRadius),
+        Unit::Metric(MetricUnit::Meter),
+        695_700_000.0,
+        IAU_NOMINAL,
+    ),
+    reference(
+        Unit::Astronomic(AstronomicUnit::EarthRadius),
+        Unit::Metric(MetricUnit::Meter),
+        6_378_100.0,
+        IAU_NOMINAL,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::Inch),
+        Unit::Typographic(TypographicUnit::Point),
+        72.0,
+        POSTSCRIPT,
+    ),
+    reference(
+        Unit::Typographic(TypographicUnit::Pica),
+        Unit::Imperial(ImperialUnit::Inch),
+        1.0 / 6.0,
+        POSTSCRIPT,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::Inch),
+        Unit::Typographic(TypographicUnit::Pixel),
+        96.0,
+        CSS,
+    ),
+    reference(
+        Unit::Typographic(TypographicUnit::Cicero),
+        Unit::Metric(MetricUnit::Millimeter),
+        4.51278,
+        DIDOT,
+    ),
+    reference(
+        Unit::Imperial(ImperialUnit::Inch),
+        Unit::Typographic(TypographicUnit::ScaledPoint),
+        72.27 * 65_536.0,
+        TEX,
+    ),
+];
+
+/// Checks a single reference value against the conversion of this crate.
+pub fn verify(reference: &Reference) -> Check {
+    let actual = Length::new_value_unit(1, reference.from)
+        .convert(reference.to)
+        .value;
+    let relative_error = ((actual - reference.value) / reference.value).abs();
+
+    Check {
+        reference: *reference,
+        actual,
+        relative_error,
+    }
+}
+
+/// Checks all reference values and returns a report with the results.
+///
+/// # Example
+/// ```
+/// use length::conformance;
+///
+/// let report = conformance::verify_all();
+///
+/// assert!(report.is_ok());
+/// assert!(report.failures().is_empty());
+/// ```
+pub fn verify_all() -> Report {
+    Report {
+        checks: REFERENCES.iter().map(verify).collect(),
+    }
+}
@@ -0,0 +1,144 @@
+//! Constructors for each built-in unit, like Length::meters(5) or Length::light_years(4.2), as
+//! shortcuts for Length::new_value_unit(...).
+
+use crate::{
+    AstronomicUnit, ImperialUnit, Length, MetricUnit, SubatomicUnit, TraditionalUnit,
+    TypographicUnit, UsSurveyUnit,
+};
+
+#[cfg(feature = "historical")]
+use crate::HistoricalUnit;
+
+/// Implements a constructor for each given unit, named after the plural of the unit.
+macro_rules! unit_constructors {
+    ($($(#[$attribute:meta])* $name:ident => $unit:expr, $plural:literal;)*) => {
+        impl Length {
+            $(
+                #[doc = concat!("Gets a new Length struct with the value in ", $plural, ".")]
+                $(#[$attribute])*
+                pub fn $name<T: Into<f64>>(value: T) -> Self {
+                    Length::new_value_unit(value, $unit)
+                }
+            )*
+        }
+    };
+}
+
+unit_constructors! {
+    astronomical_units => AstronomicUnit::AstronomicalUnit, "astronomical units";
+    light_seconds => AstronomicUnit::Lightsecond, "light-seconds";
+    light_minutes => AstronomicUnit::Lightminute, "light-minutes";
+    light_hours => AstronomicUnit::Lighthour, "light-hours";
+    light_days => AstronomicUnit::Lightday, "light-days";
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, AstronomicUnit::*};
+    ///
+    /// assert_eq!(Unit::Astronomic(Lightyear), Length::light_years(4.2).unit);
+    /// ```
+    light_years => AstronomicUnit::Lightyear, "light-years";
+    parsecs => AstronomicUnit::Parsec, "parsecs";
+    kiloparsecs => AstronomicUnit::Kiloparsec, "kiloparsecs";
+    megaparsecs => AstronomicUnit::Megaparsec, "megaparsecs";
+    gigaparsecs => AstronomicUnit::Gigaparsec, "gigaparsecs";
+    solar_radii => AstronomicUnit::SolarRadius, "solar radii";
+    earth_radii => AstronomicUnit::EarthRadius, "Earth radii";
+    lunar_distances => AstronomicUnit::LunarDistance, "lunar distances";
+
+    thou => ImperialUnit::Thou, "thou";
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, ImperialUnit::*};
+    ///
+    /// assert_eq!(1.0, Length::inches(12).to(Foot).value);
+    /// ```
+    inches => ImperialUnit::Inch, "inches";
+    hands => ImperialUnit::Hand, "hands";
+    feet => ImperialUnit::Foot, "feet";
+    yards => ImperialUnit::Yard, "yards";
+    rods => ImperialUnit::Rod, "rods";
+    chains => ImperialUnit::Chain, "chains";
+    furlongs => ImperialUnit::Furlong, "furlongs";
+    miles => ImperialUnit::Mile, "miles";
+    leagues => ImperialUnit::League, "leagues";
+
+    quectometers => MetricUnit::Quectometer, "quectometers";
+    rontometers => MetricUnit::Rontometer, "rontometers";
+    yoctometers => MetricUnit::Yoctometer, "yoctometers";
+    zeptometers => MetricUnit::Zeptometer, "zeptometers";
+    attometers => MetricUnit::Attometer, "attometers";
+    femtometers => MetricUnit::Femtometer, "femtometers";
+    picometers => MetricUnit::Picometer, "picometers";
+    angstroms => MetricUnit::Angstrom, "angstroms";
+    nanometers => MetricUnit::Nanometer, "nanometers";
+    micrometers => MetricUnit::Micrometer, "micrometers";
+    millimeters => MetricUnit::Millimeter, "millimeters";
+    centimeters => MetricUnit::Centimeter, "centimeters";
+    decimeters => MetricUnit::Decimeter, "decimeters";
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let length = Length::meters(5);
+    ///
+    /// assert_eq!(5.0, length.value);
+    /// assert_eq!(Unit::Metric(Meter), length.unit);
+    /// ```
+    meters => MetricUnit::Meter, "meters";
+    decameters => MetricUnit::Decameter, "decameters";
+    hectometers => MetricUnit::Hectometer, "hectometers";
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// assert_eq!(Length::new_value_unit(2.5, Kilometer), Length::kilometers(2.5));
+    /// ```
+    kilometers => MetricUnit::Kilometer, "kilometers";
+    megameters => MetricUnit::Megameter, "megameters";
+    gigameters => MetricUnit::Gigameter, "gigameters";
+    terameters => MetricUnit::Terameter, "terameters";
+    petameters => MetricUnit::Petameter, "petameters";
+    exameters => MetricUnit::Exameter, "exameters";
+    zettameters => MetricUnit::Zettameter, "zettameters";
+    yottameters => MetricUnit::Yottameter, "yottameters";
+    ronnameters => MetricUnit::Ronnameter, "ronnameters";
+    quettameters => MetricUnit::Quettameter, "quettameters";
+
+    planck_lengths => SubatomicUnit::PlanckLength, "Planck lengths";
+    bohr_radii => SubatomicUnit::BohrRadius, "Bohr radii";
+
+    points => TypographicUnit::Point, "points";
+    didot_points => TypographicUnit::DidotPoint, "Didot points";
+    ciceros => TypographicUnit::Cicero, "ciceros";
+    scaled_points => TypographicUnit::ScaledPoint, "scaled points";
+    picas => TypographicUnit::Pica, "picas";
+    pixels => TypographicUnit::Pixel, "pixels";
+
+    survey_feet => UsSurveyUnit::SurveyFoot, "survey feet";
+    survey_chains => UsSurveyUnit::SurveyChain, "survey chains";
+    survey_miles => UsSurveyUnit::SurveyMile, "survey miles";
+
+    cun => TraditionalUnit::Cun, "cun";
+    chi => TraditionalUnit::Chi, "chi";
+    zhang => TraditionalUnit::Zhang, "zhang";
+    li => TraditionalUnit::Li, "li";
+    shaku => TraditionalUnit::Shaku, "shaku";
+    ken => TraditionalUnit::Ken, "ken";
+    ri => TraditionalUnit::Ri, "ri";
+    arshins => TraditionalUnit::Arshin, "arshins";
+    versts => TraditionalUnit::Verst, "versts";
+    scandinavian_miles => TraditionalUnit::Mil, "Scandinavian miles";
+
+    #[cfg(feature = "historical")]
+    cubits => HistoricalUnit::Cubit, "cubits";
+    #[cfg(feature = "historical")]
+    smoots => HistoricalUnit::Smoot, "smoots";
+    #[cfg(feature = "historical")]
+    fathoms => HistoricalUnit::Fathom, "fathoms";
+    #[cfg(feature = "historical")]
+    football_fields => HistoricalUnit::FootballField, "football fields";
+}
@@ -0,0 +1,181 @@
+//! Conversion of a column of lengths in CSV data (feature `csv`).
+//!
+//! The cells of the column are parsed like Length::parse(...), so they are lengths with units
+//! like "2.5 km". Plain numbers are allowed too, if the column has a declared unit.
+
+use std::error::Error;
+use std::fmt;
+use std::io::{Read, Write};
+
+use crate::{Length, ParseLengthError, Unit};
+
+/// A named column of lengths in CSV data, that can be read or converted into another unit.
+///
+/// # Example
+/// ```
+/// use length::csv::CsvColumn;
+/// use length::{ImperialUnit::*, MetricUnit::*};
+///
+/// let input = "city,distance\nBerlin,2.5 km\nParis,800\n";
+/// let column = CsvColumn::new("distance").declared_unit(Meter);
+///
+/// let mut output = Vec::new();
+/// let rows = column.convert(input.as_bytes(), &mut output, Meter).unwrap();
+///
+/// assert_eq!(2, rows);
+/// assert_eq!("city,distance\nBerlin,2500\nParis,800\n", String::from_utf8(output).unwrap());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvColumn {
+    name: String,
+    declared_unit: Option<Unit>,
+}
+
+impl CsvColumn {
+    /// Gets a new CsvColumn struct for the column with the given name in the header row.
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        CsvColumn {
+            name: name.into(),
+            declared_unit: None,
+        }
+    }
+
+    /// Sets the unit of cells, that are plain numbers, like "800". Without it, every cell needs
+    /// a unit.
+    pub fn declared_unit<U: Into<Unit>>(mut self, unit: U) -> Self {
+        self.declared_unit = Some(unit.into());
+        self
+    }
+
+    /// Reads the lengths of the column, in their own units.
+    ///
+    /// # Example
+    /// ```
+    /// use length::csv::CsvColumn;
+    /// use length::{Unit, ImperialUnit::*};
+    ///
+    /// let input = "name,height\nKilimanjaro,19341 ft\nMont Blanc,15774 ft\n";
+    /// let heights = CsvColumn::new("height").read(input.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(2, heights.len());
+    /// assert_eq!(19_341.0, heights[0].value);
+    /// assert_eq!(Unit::Imperial(Foot), heights[1].unit);
+    /// ```
+    pub fn read<R: Read>(&self, reader: R) -> Result<Vec<Length>, CsvError> {
+        let mut reader = ::csv::Reader::from_reader(reader);
+        let index = self.index(reader.headers()?)?;
+
+        let mut lengths = Vec::new();
+        for record in reader.records() {
+            let record = record?;
+            lengths.push(self.parse(&record, index)?);
+        }
+
+        Ok(lengths)
+    }
+
+    /// Converts the lengths of the column into the given unit and writes the CSV data with the
+    /// values in this unit as plain numbers, keeping all other columns. Returns the number of
+    /// converted rows.
+    pub fn convert<R: Read, W: Write, U: Into<Unit>>(
+        &self,
+        reader: R,
+        writer: W,
+        unit: U,
+    ) -> Result<usize, CsvError> {
+        let unit = unit.into();
+        let mut reader = ::csv::Reader::from_reader(reader);
+        let mut writer = ::csv::Writer::from_writer(writer);
+        let headers = reader.headers()?.clone();
+        let index = self.index(&headers)?;
+        writer.write_record(&headers)?;
+
+        let mut rows = 0;
+        for record in reader.records() {
+            let record = record?;
+            let value = self.parse(&record, index)?.convert(unit).value.to_string();
+            let converted: ::csv::StringRecord = record
+                .iter()
+                .enumerate()
+                .map(|(field_index, field)| {
+                    if field_index == index {
+                        value.as_str()
+                    } else {
+                        field
+                    }
+                })
+                .collect();
+            writer.write_record(&converted)?;
+            rows += 1;
+        }
+        writer.flush().map_err(::csv::Error::from)?;
+
+        Ok(rows)
+    }
+
+    /// Gets the index of the column in the header row.
+    fn index(&self, headers: &::csv::StringRecord) -> Result<usize, CsvError> {
+        headers
+            .iter()
+            .position(|header| header.trim() == self.name)
+            .ok_or_else(|| CsvError::MissingColumn {
+                column: self.name.clone(),
+            })
+    }
+
+    /// Parses the cell of the column in the record.
+    fn parse(&self, record: &::csv::StringRecord, index: usize) -> Result<Length, CsvError> {
+        let line = record
+            .position()
+            .map_or(0, |position| position.line() as usize);
+        let cell = record.get(index).unwrap_or("");
+
+        let number = cell.trim().parse::<f64>();
+        let result = match (self.declared_unit, number) {
+            (Some(unit), Ok(number)) => Ok(Length::new_value_unit(number, unit)),
+            _ => Length::parse(cell),
+        };
+
+        result.map_err(|error| CsvError::Parse { line, error })
+    }
+}
+
+/// The reason, why a column of CSV data could not be read or converted.
+#[derive(Debug)]
+pub enum CsvError {
+    /// The header row has no column with the name.
+    MissingColumn { column: String },
+    /// A cell of the column is not a length, the line numbers start at 1.
+    Parse {
+        line: usize,
+        error: ParseLengthError,
+    },
+    /// The CSV data could not be read or written.
+    Csv(::csv::Error),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CsvError::MissingColumn { column } => write!(f, "missing column \"{}\"", column),
+            CsvError::Parse { line, error } => write!(f, "line {}: {}", line, error),
+            CsvError::Csv(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::MissingColumn { .. } => None,
+            CsvError::Parse { error, .. } => Some(error),
+            CsvError::Csv(error) => Some(error),
+        }
+    }
+}
+
+impl From<::csv::Error> for CsvError {
+    fn from(error: ::csv::Error) -> Self {
+        CsvError::Csv(error)
+    }
+}
@@ -0,0 +1,145 @@
+//! User-defined units, that are registered at runtime.
+//!
+//! A custom unit has a symbol and a factor relative to the meter. Once registered, it can be
+//! parsed with Length::new_string(...), converted with Length::to(...) and printed like any
+//! other unit.
+
+use std::fmt;
+use std::sync::RwLock;
+
+use crate::{Length, Unit};
+
+static REGISTRY: RwLock<Vec<Definition>> = RwLock::new(Vec::new());
+
+struct Definition {
+    symbol: String,
+    meters: f64,
+}
+
+/// A handle to a registered custom unit.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct CustomUnit {
+    id: u32,
+}
+
+impl CustomUnit {
+    /// Registers a unit with the given symbol and the amount of meters in one of it.
+    ///
+    /// Registering an already registered symbol again updates its factor and returns the same unit.
+    /// Returns None, if the symbol is already used by a built-in unit, contains whitespace, starts
+    /// with a digit, if the factor is not a positive finite number or if all 64536 unit codes for
+    /// custom units (see Unit::code()) are taken. The historical units of the
+    /// feature `historical` are the exception, a custom unit with the same symbol or name shadows
+    /// them, so enabling the feature does not break registrations.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{CustomUnit, Length, Unit, MetricUnit::*};
+    ///
+    /// let smoot = CustomUnit::register("smoot", 1.7018).unwrap();
+    /// let bridge = Length::new_string("364.4 smoot").unwrap();
+    ///
+    /// assert_eq!(Unit::Custom(smoot), bridge.unit);
+    /// assert_eq!("364.4 smoot", bridge.to_string());
+    /// assert!((bridge.to(Meter).value - 620.135_92).abs() < 1e-9);
+    /// ```
+    pub fn register<S: Into<String>>(symbol: S, meters: f64) -> Option<CustomUnit> {
+        let symbol: String = symbol.into();
+        let valid_symbol = match symbol.chars().next() {
+            Some(first) => !first.is_ascii_digit() && !symbol.contains(char::is_whitespace),
+            None => false,
+        };
+        if !valid_symbol || !meters.is_finite() || meters <= 0.0 {
+            return None;
+        }
+
+        if CustomUnit::find(&symbol).is_none()
+            && symbol
+                .parse::<Unit>()
+                .is_ok_and(|unit| !unit.yields_to_custom_units())
+        {
+            return None;
+        }
+
+        let mut registry = REGISTRY.write().unwrap();
+        if let Some(id) = registry
+            .iter()
+            .position(|definition| definition.symbol == symbol)
+        {
+            registry[id].meters = meters;
+            return Some(CustomUnit { id: id as u32 });
+        }
+
+        let id = u32::try_from(registry.len()).ok()?;
+        CustomUnit::code_of(id)?;
+        registry.push(Definition { symbol, meters });
+
+        Some(CustomUnit { id })
+    }
+
+    /// Gets the registered unit with the given symbol.
+    ///
+    /// # Example
+    /// ```
+    /// use length::CustomUnit;
+    ///
+    /// let cubit = CustomUnit::register("cubit", 0.4572).unwrap();
+    ///
+    /// assert_eq!(Some(cubit), CustomUnit::find("cubit"));
+    /// assert_eq!(None, CustomUnit::find("furlongs"));
+    /// ```
+    pub fn find(symbol: &str) -> Option<CustomUnit> {
+        REGISTRY
+            .read()
+            .unwrap()
+            .iter()
+            .position(|definition| definition.symbol == symbol)
+            .map(|id| CustomUnit { id: id as u32 })
+    }
+
+    /// Gets the symbol of the unit.
+    pub fn symbol(&self) -> String {
+        REGISTRY.read().unwrap()[self.id as usize].symbol.clone()
+    }
+
+    /// Gets the amount of meters in one of this unit.
+    pub fn meters(&self) -> f64 {
+        REGISTRY.read().unwrap()[self.id as usize].meters
+    }
+
+    /// Gets the code of this unit for Unit::code(), that follows the codes of the built-in units.
+    pub(crate) fn code(&self) -> u16 {
+        CustomUnit::code_of(self.id)
+            .expect("custom units are only registered, while codes are left")
+    }
+
+    /// Gets the code for the custom unit with the given id, or None, if the u16 codes are exhausted.
+    fn code_of(id: u32) -> Option<u16> {
+        u16::try_from(id)
+            .ok()?
+            .checked_add(Length::CUSTOM_UNIT_CODE_OFFSET)
+    }
+
+    pub(crate) fn from_id(id: u32) -> Option<CustomUnit> {
+        if (id as usize) < REGISTRY.read().unwrap().len() {
+            Some(CustomUnit { id })
+        } else {
+            None
+        }
+    }
+
+    /// Gets all registered custom units in the order of their registration.
+    pub(crate) fn registered() -> Vec<CustomUnit> {
+        (0..REGISTRY.read().unwrap().len() as u32)
+            .map(|id| CustomUnit { id })
+            .collect()
+    }
+}
+
+impl fmt::Display for CustomUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&REGISTRY.read().unwrap()[self.id as usize].symbol)
+    }
+}
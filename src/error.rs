@@ -0,0 +1,122 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::ops::Range;
+
+use crate::Length;
+
+/// The reason, why a string could not be parsed into a Length.
+///
+/// The spans are byte ranges into the parsed string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseLengthError {
+    /// The string was empty or contained only whitespace.
+    EmptyInput,
+    /// The numeric part of the string is not a valid number.
+    InvalidNumber { number: String, span: Range<usize> },
+    /// The numeric part is not followed by a unit.
+    MissingUnit { span: Range<usize> },
+    /// The unit part of the string is not a known unit symbol.
+    UnknownUnit { unit: String, span: Range<usize> },
+    /// The unit contains whitespace, like "k m", but would be known without it.
+    SpacedUnit { unit: String, span: Range<usize> },
+    /// The string does not have the form "<number> <unit>".
+    MalformedFormat { input: String },
+    /// A line of io::parse_lines(...) could not be parsed, the line numbers start at 1.
+    Line {
+        line: usize,
+        error: Box<ParseLengthError>,
+    },
+    /// A line of io::parse_lines(...) could not be read.
+    Io {
+        line: usize,
+        kind: io::ErrorKind,
+        message: String,
+    },
+}
+
+impl fmt::Display for ParseLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseLengthError::EmptyInput => write!(f, "unable to parse an empty string"),
+            ParseLengthError::InvalidNumber { number, span } => write!(
+                f,
+                "invalid number \"{}\" at {}..{}",
+                number, span.start, span.end
+            ),
+            ParseLengthError::MissingUnit { span } => {
+                write!(f, "missing unit after the number at {}", span.end)
+            }
+            ParseLengthError::UnknownUnit { unit, span } => write!(
+                f,
+                "unknown unit \"{}\" at {}..{}",
+                unit, span.start, span.end
+            ),
+            ParseLengthError::SpacedUnit { unit, span } => write!(
+                f,
+                "unit \"{}\" at {}..{} must not contain whitespace",
+                unit, span.start, span.end
+            ),
+            ParseLengthError::MalformedFormat { input } => {
+                write!(f, "\"{}\" is not of the form <number> <unit>", input)
+            }
+            ParseLengthError::Line { line, error } => write!(f, "line {}: {}", line, error),
+            ParseLengthError::Io { line, message, .. } => {
+                write!(f, "unable to read line {}: {}", line, message)
+            }
+        }
+    }
+}
+
+impl Error for ParseLengthError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseLengthError::Line { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// The reason, why a value was rejected by Length::try_new(...).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LengthError {
+    /// The value is infinite or NaN.
+    NotFinite { value: f64 },
+    /// The value is negative, but only non-negative values are allowed.
+    Negative { value: f64 },
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LengthError::NotFinite { value } => write!(f, "value {} is not finite", value),
+            LengthError::Negative { value } => write!(f, "value {} must not be negative", value),
+        }
+    }
+}
+
+impl Error for LengthError {}
+
+/// The error of Length::to_checked(...), if a conversion does not round-trip within the epsilon.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrecisionLoss {
+    /// The imprecise result of the conversion.
+    pub converted: Length,
+    /// The estimated relative error, that is the relative difference of the value and the value
+    /// converted back into its unit.
+    pub relative_error: f64,
+    /// The greatest relative error, that was allowed.
+    pub epsilon: f64,
+}
+
+impl fmt::Display for PrecisionLoss {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "conversion into {} has a relative error of {:e}, which exceeds {:e}",
+            self.converted.unit, self.relative_error, self.epsilon
+        )
+    }
+}
+
+impl Error for PrecisionLoss {}
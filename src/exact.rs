@@ -0,0 +1,214 @@
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use crate::{Length, MetricUnit, Unit};
+
+/// A length, that is stored as an integer count of picometers.
+///
+/// Additions, subtractions and comparisons are exact, so it can be hashed and totally ordered.
+/// The range covers about ±18 billion light-years.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct ExactLength {
+    picometers: i128,
+}
+
+impl ExactLength {
+    /// Gets a new ExactLength struct with the given integer value and unit.
+    ///
+    /// Returns None, if the length is not a whole number of picometers or out of range. Units
+    /// without a rational factor to the meter, like the parsec, the Planck length and the custom
+    /// units, always return None.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{ExactLength, AstronomicUnit::*, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// let one_foot = ExactLength::new(1, Foot).unwrap();
+    ///
+    /// assert_eq!(304_800_000_000, one_foot.picometers());
+    /// assert_eq!(Some(one_foot), ExactLength::new(12, Inch));
+    /// assert_eq!(None, ExactLength::new(1, Femtometer));
+    /// assert_eq!(None, ExactLength::new(1, Parsec));
+    /// ```
+    pub fn new<U: Into<Unit>>(value: i128, unit: U) -> Option<Self> {
+        let (numerator, denominator) =
+            Length::exact_ratio(&unit.into(), &Unit::Metric(MetricUnit::Picometer))?;
+        let product = value.checked_mul(numerator)?;
+        if product % denominator != 0 {
+            return None;
+        }
+
+        Some(ExactLength::from_picometers(product / denominator))
+    }
+
+    /// Gets a new ExactLength struct with the given amount of picometers.
+    pub fn from_picometers(picometers: i128) -> Self {
+        ExactLength { picometers }
+    }
+
+    /// Gets the amount of picometers.
+    pub fn picometers(&self) -> i128 {
+        self.picometers
+    }
+
+    /// Gets the length rounded to the nearest picometer.
+    ///
+    /// Units with an exact rational factor are converted without any floating point rounding.
+    /// Returns None, if the value is not finite or out of range.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{ExactLength, Length};
+    ///
+    /// let one_tenth = Length::new_string("0.1 km").unwrap();
+    /// let exact = ExactLength::from_length(&one_tenth).unwrap();
+    ///
+    /// assert_eq!(100_000_000_000_000, exact.picometers());
+    /// assert_eq!(exact + exact + exact, ExactLength::from_length(&one_tenth.multiply_by(3)).unwrap());
+    /// ```
+    pub fn from_length(length: &Length) -> Option<Self> {
+        if !length.value.is_finite() {
+            return None;
+        }
+
+        if let Some((numerator, denominator)) =
+            Length::exact_ratio(&length.unit, &Unit::Metric(MetricUnit::Picometer))
+        {
+            if let Some(picometers) =
+                ExactLength::scale_rounded(length.value, numerator, denominator)
+            {
+                return Some(ExactLength::from_picometers(picometers));
+            }
+        }
+
+        let picometers = length.convert(MetricUnit::Picometer).value.round();
+        if picometers.abs() >= i128::MAX as f64 {
+            return None;
+        }
+
+        Some(ExactLength::from_picometers(picometers as i128))
+    }
+
+    /// Gets value * numerator / denominator rounded half away from zero, with the value split
+    /// into its integer mantissa and binary exponent, or None on an overflow.
+    fn scale_rounded(value: f64, numerator: i128, denominator: i128) -> Option<i128> {
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = (bits & 0x000f_ffff_ffff_ffff) as i128;
+        let mut mantissa = if biased_exponent == 0 {
+            fraction
+        } else {
+            fraction | 0x0010_0000_0000_0000
+        };
+        if mantissa == 0 {
+            return Some(0);
+        }
+        let mut exponent = biased_exponent.max(1) - 1075;
+        let zeros = mantissa.trailing_zeros();
+        mantissa >>= zeros;
+        exponent += zeros as i32;
+
+        let (dividend, divisor) = if exponent >= 0 {
+            let power = 2_i128.checked_pow(exponent as u32)?;
+            (
+                mantissa.checked_mul(power)?.checked_mul(numerator)?,
+                denominator,
+            )
+        } else {
+            let power = 2_i128.checked_pow(exponent.unsigned_abs())?;
+            (
+                mantissa.checked_mul(numerator)?,
+                denominator.checked_mul(power)?,
+            )
+        };
+
+        let mut quotient = dividend / divisor;
+        if (dividend % divisor).checked_mul(2)? >= divisor {
+            quotient += 1;
+        }
+
+        if value.is_sign_negative() {
+            Some(-quotient)
+        } else {
+            Some(quotient)
+        }
+    }
+
+    /// Converts this length into a Length-struct with the given unit.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{ExactLength, Unit, ImperialUnit::*};
+    ///
+    /// let one_foot = ExactLength::new(1, Foot).unwrap();
+    /// let in_inches = one_foot.to_length(Inch);
+    ///
+    /// assert_eq!(12.0, in_inches.value);
+    /// assert_eq!(Unit::Imperial(Inch), in_inches.unit);
+    /// ```
+    pub fn to_length<U: Into<Unit>>(&self, unit: U) -> Length {
+        let unit = unit.into();
+        let picometers = Length::new_value_unit(self.picometers as f64, MetricUnit::Picometer);
+
+        picometers
+            .to_exact(unit)
+            .unwrap_or_else(|| picometers.convert(unit))
+    }
+
+    /// Adds the length and returns None on an overflow.
+    pub fn checked_add(&self, other: ExactLength) -> Option<Self> {
+        self.picometers
+            .checked_add(other.picometers)
+            .map(ExactLength::from_picometers)
+    }
+
+    /// Subtracts the length and returns None on an overflow.
+    pub fn checked_sub(&self, other: ExactLength) -> Option<Self> {
+        self.picometers
+            .checked_sub(other.picometers)
+            .map(ExactLength::from_picometers)
+    }
+}
+
+impl From<ExactLength> for Length {
+    fn from(length: ExactLength) -> Length {
+        length.to_length(MetricUnit::Meter)
+    }
+}
+
+impl Add for ExactLength {
+    type Output = ExactLength;
+
+    fn add(self, other: ExactLength) -> ExactLength {
+        ExactLength::from_picometers(self.picometers + other.picometers)
+    }
+}
+
+impl AddAssign for ExactLength {
+    fn add_assign(&mut self, other: ExactLength) {
+        self.picometers += other.picometers;
+    }
+}
+
+impl Sub for ExactLength {
+    type Output = ExactLength;
+
+    fn sub(self, other: ExactLength) -> ExactLength {
+        ExactLength::from_picometers(self.picometers - other.picometers)
+    }
+}
+
+impl SubAssign for ExactLength {
+    fn sub_assign(&mut self, other: ExactLength) {
+        self.picometers -= other.picometers;
+    }
+}
+
+impl Neg for ExactLength {
+    type Output = ExactLength;
+
+    fn neg(self) -> ExactLength {
+        ExactLength::from_picometers(-self.picometers)
+    }
+}
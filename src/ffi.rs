@@ -0,0 +1,123 @@
+//! C bindings (feature `ffi`), declared in include/length.h.
+//!
+//! A length is passed by value as CLength with the numeric unit code of Unit::code(). To build
+//! the library for C, compile the crate as a `cdylib` or `staticlib`, e.g. with
+//! `cargo rustc --release --features ffi --crate-type cdylib`. The header is generated with
+//! `cbindgen --config cbindgen.toml --output include/length.h`.
+
+use std::ffi::{c_char, CStr, CString};
+
+use crate::{Length, Unit};
+
+/// A length with its value and the code of its unit.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CLength {
+    pub value: f64,
+    /// The code of the unit, see length_unit_code(...).
+    pub unit: u16,
+}
+
+impl CLength {
+    fn from_length(length: &Length) -> Self {
+        CLength {
+            value: length.value,
+            unit: length.unit.code(),
+        }
+    }
+
+    fn to_length(self) -> Option<Length> {
+        Some(Length::new_value_unit(
+            self.value,
+            Unit::try_from_code(self.unit)?,
+        ))
+    }
+}
+
+/// Reads a NUL-terminated UTF-8 string.
+///
+/// # Safety
+/// The pointer has to be null or point to a NUL-terminated string.
+unsafe fn read_string<'a>(string: *const c_char) -> Option<&'a str> {
+    if string.is_null() {
+        return None;
+    }
+
+    CStr::from_ptr(string).to_str().ok()
+}
+
+/// Writes the value into out, if it is Some and out is not null, and returns, if it was written.
+///
+/// # Safety
+/// The pointer has to be null or valid for writes.
+unsafe fn write_out<T>(value: Option<T>, out: *mut T) -> bool {
+    match value {
+        Some(value) if !out.is_null() => {
+            out.write(value);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Parses a string like "5 km" into out. Returns false and leaves out unchanged, if the string is
+/// null, not valid UTF-8 or no length.
+///
+/// # Safety
+/// The string has to be null or a NUL-terminated string, out has to be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn length_parse(string: *const c_char, out: *mut CLength) -> bool {
+    let length = read_string(string).and_then(|string| Length::parse(string).ok());
+
+    write_out(length.as_ref().map(CLength::from_length), out)
+}
+
+/// Converts the length into the unit with the given code into out. Returns false and leaves out
+/// unchanged, if a unit code is unknown.
+///
+/// # Safety
+/// Out has to be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn length_convert(length: CLength, unit: u16, out: *mut CLength) -> bool {
+    let converted = length
+        .to_length()
+        .zip(Unit::try_from_code(unit))
+        .map(|(length, unit)| CLength::from_length(&length.to(unit)));
+
+    write_out(converted, out)
+}
+
+/// Gets the code of the unit with the given symbol or name, like "km" or "miles", into out.
+/// Returns false and leaves out unchanged, if the unit is unknown.
+///
+/// # Safety
+/// The symbol has to be null or a NUL-terminated string, out has to be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn length_unit_code(symbol: *const c_char, out: *mut u16) -> bool {
+    let code = read_string(symbol)
+        .and_then(|symbol| symbol.parse::<Unit>().ok())
+        .map(|unit| unit.code());
+
+    write_out(code, out)
+}
+
+/// Formats the length like "5 km". Returns null, if the unit code is unknown. The string has to
+/// be released with length_string_free(...).
+#[no_mangle]
+pub extern "C" fn length_to_string(length: CLength) -> *mut c_char {
+    length
+        .to_length()
+        .and_then(|length| CString::new(length.to_string()).ok())
+        .map_or(std::ptr::null_mut(), CString::into_raw)
+}
+
+/// Releases a string of length_to_string(...), null is ignored.
+///
+/// # Safety
+/// The string has to be null or returned by length_to_string(...) and not be released before.
+#[no_mangle]
+pub unsafe extern "C" fn length_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
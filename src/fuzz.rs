@@ -0,0 +1,48 @@
+//! Entry points for fuzzing the parsers with arbitrary input, see the targets in fuzz/.
+
+use crate::{text, Length, LengthParser, LengthRange, UnitSystem};
+
+/// Feeds arbitrary bytes into all parsers and returns the result of Length::new_string(...).
+///
+/// Invalid UTF-8 is replaced like String::from_utf8_lossy(...) does. Every parser has to return
+/// None or an error for malformed input instead of panicking, and the parsers have to agree
+/// with each other, which is asserted here, so a fuzzer reports a disagreement as a crash.
+///
+/// # Example
+/// ```
+/// use length::fuzz;
+///
+/// assert_eq!(2.5, fuzz::parse_any(b"2.5 km").unwrap().value);
+/// assert!(fuzz::parse_any(b"\xff\x00 5 '").is_none());
+/// ```
+pub fn parse_any(bytes: &[u8]) -> Option<Length> {
+    let string = String::from_utf8_lossy(bytes);
+
+    let length = Length::new_string(string.as_ref());
+    let parsed = Length::parse(&string);
+    assert_eq!(
+        length.is_some(),
+        parsed.is_ok(),
+        "new_string(...) and parse(...) disagree on {:?}",
+        string
+    );
+    if let Some(length) = &length {
+        assert_eq!(
+            Some(length),
+            Length::new_string_lenient(string.as_ref()).as_ref(),
+            "the lenient parser differs on {:?}",
+            string
+        );
+    }
+
+    let _ = LengthParser::new()
+        .decimal_comma(true)
+        .thousands_separator('.')
+        .lenient(true)
+        .parse(&string);
+    let _ = LengthRange::new_string(string.as_ref());
+    let _ = text::convert_all(&string, UnitSystem::Metric);
+    let _ = Length::from_bytes(bytes);
+
+    length
+}
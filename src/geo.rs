@@ -0,0 +1,203 @@
+//! Distances between geographic coordinates on the earth.
+//!
+//! The coordinates are latitudes and longitudes in decimal degrees, the distances are returned in
+//! meters.
+//!
+//! With the feature `geo`, lengths can also be measured from and used with the geometries of the
+//! geo crate in a projected coordinate reference system (CRS).
+
+#[cfg(feature = "geo")]
+use ::geo::algorithm::line_measures::{Euclidean, LengthMeasurable};
+#[cfg(feature = "geo")]
+use ::geo::{Buffer, Line, LineString, MultiPolygon};
+
+#[cfg(feature = "geo")]
+use crate::Unit;
+use crate::{Length, MetricUnit::Meter};
+
+/// The mean radius of the earth in meters (IUGG), that is used for the haversine formula.
+pub const MEAN_EARTH_RADIUS: f64 = 6_371_008.8;
+
+#[cfg(feature = "vincenty")]
+const WGS84_SEMI_MAJOR_AXIS: f64 = 6_378_137.0;
+#[cfg(feature = "vincenty")]
+const WGS84_FLATTENING: f64 = 1.0 / 298.257_223_563;
+#[cfg(feature = "vincenty")]
+const VINCENTY_MAX_ITERATIONS: usize = 200;
+
+impl Length {
+    /// Gets the great-circle distance between two coordinates with the haversine formula, that
+    /// assumes a spherical earth with the mean earth radius. The error is up to about 0.5%
+    /// compared to the ellipsoid.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let paris_to_london = Length::from_coordinates(48.8566, 2.3522, 51.5074, -0.1278);
+    ///
+    /// assert_eq!(Unit::Metric(Meter), paris_to_london.unit);
+    /// assert_eq!(344.0, paris_to_london.to(Kilometer).value.round());
+    /// ```
+    pub fn from_coordinates(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> Self {
+        let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+        let delta_phi = (lat2 - lat1).to_radians();
+        let delta_lambda = (lon2 - lon1).to_radians();
+
+        let a = (delta_phi / 2.0).sin().powi(2)
+            + phi1.cos() * phi2.cos() * (delta_lambda / 2.0).sin().powi(2);
+        let central_angle = 2.0 * a.sqrt().min(1.0).asin();
+
+        Length::new_value_unit(MEAN_EARTH_RADIUS * central_angle, Meter)
+    }
+
+    /// Gets the geodesic distance between two coordinates on the WGS84 ellipsoid with the inverse
+    /// formula of Vincenty, that is accurate to a fraction of a millimeter (feature `vincenty`).
+    ///
+    /// Returns None, if the iteration does not converge, which happens for nearly antipodal
+    /// coordinates.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// let flinders_peak = (-37.951_033_417, 144.424_867_889);
+    /// let buninyong = (-37.652_821_139, 143.926_495_528);
+    /// let distance = Length::from_coordinates_vincenty(
+    ///     flinders_peak.0,
+    ///     flinders_peak.1,
+    ///     buninyong.0,
+    ///     buninyong.1,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!((distance.value - 54_972.271).abs() < 0.001);
+    /// ```
+    #[cfg(feature = "vincenty")]
+    pub fn from_coordinates_vincenty(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> Option<Self> {
+        let a = WGS84_SEMI_MAJOR_AXIS;
+        let f = WGS84_FLATTENING;
+        let b = a * (1.0 - f);
+
+        let l = (lon2 - lon1).to_radians();
+        let u1 = ((1.0 - f) * lat1.to_radians().tan()).atan();
+        let u2 = ((1.0 - f) * lat2.to_radians().tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda = l;
+        for _ in 0..VINCENTY_MAX_ITERATIONS {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+                + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+            .sqrt();
+            if sin_sigma == 0.0 {
+                return Some(Length::new_value_unit(0.0, Meter));
+            }
+
+            let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            let sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+            let cos_2_sigma_m = if cos_sq_alpha == 0.0 {
+                0.0
+            } else {
+                cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+            };
+            let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+
+            let previous_lambda = lambda;
+            lambda = l
+                + (1.0 - c)
+                    * f
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos_2_sigma_m
+                                + c * cos_sigma * (-1.0 + 2.0 * cos_2_sigma_m.powi(2))));
+
+            if (lambda - previous_lambda).abs() < 1e-12 {
+                let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+                let big_a = 1.0
+                    + u_sq / 16_384.0 * (4_096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+                let big_b =
+                    u_sq / 1_024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+                let delta_sigma = big_b
+                    * sin_sigma
+                    * (cos_2_sigma_m
+                        + big_b / 4.0
+                            * (cos_sigma * (-1.0 + 2.0 * cos_2_sigma_m.powi(2))
+                                - big_b / 6.0
+                                    * cos_2_sigma_m
+                                    * (-3.0 + 4.0 * sin_sigma.powi(2))
+                                    * (-3.0 + 4.0 * cos_2_sigma_m.powi(2))));
+
+                return Some(Length::new_value_unit(
+                    b * big_a * (sigma - delta_sigma),
+                    Meter,
+                ));
+            }
+        }
+
+        None
+    }
+    /// Gets the euclidean length of a geo Line, LineString or MultiLineString, whose coordinates
+    /// are in the given unit of the CRS (feature `geo`).
+    ///
+    /// # Example
+    /// ```
+    /// use geo::line_string;
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let track = line_string![(x: 0.0, y: 0.0), (x: 300.0, y: 400.0), (x: 300.0, y: 1_400.0)];
+    /// let length = Length::from_geometry(&track, Meter);
+    ///
+    /// assert_eq!(1_500.0, length.value);
+    /// assert_eq!(Unit::Metric(Meter), length.unit);
+    /// ```
+    #[cfg(feature = "geo")]
+    pub fn from_geometry<G: LengthMeasurable<f64>, U: Into<Unit>>(
+        geometry: &G,
+        crs_unit: U,
+    ) -> Self {
+        Length::new_value_unit(geometry.length(&Euclidean), crs_unit)
+    }
+
+    /// Buffers the geo geometry by this length, that is converted into the given unit of the CRS
+    /// first (feature `geo`).
+    ///
+    /// # Example
+    /// ```
+    /// use geo::{point, Area};
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// let well = point!(x: 1_000.0, y: 2_000.0);
+    /// let protection_zone = Length::new_string("0.1 km").unwrap().buffer_geometry(&well, Meter);
+    ///
+    /// assert!((protection_zone.unsigned_area() / 31_416.0 - 1.0).abs() < 0.01);
+    /// ```
+    #[cfg(feature = "geo")]
+    pub fn buffer_geometry<G: Buffer<Scalar = f64>, U: Into<Unit>>(
+        &self,
+        geometry: &G,
+        crs_unit: U,
+    ) -> MultiPolygon<f64> {
+        geometry.buffer(self.convert(crs_unit).value)
+    }
+}
+
+#[cfg(feature = "geo")]
+impl From<&Line<f64>> for Length {
+    /// Gets the euclidean length of the line in a CRS in meters.
+    fn from(line: &Line<f64>) -> Length {
+        Length::from_geometry(line, Meter)
+    }
+}
+
+#[cfg(feature = "geo")]
+impl From<&LineString<f64>> for Length {
+    /// Gets the euclidean length of the line string in a CRS in meters.
+    fn from(line_string: &LineString<f64>) -> Length {
+        Length::from_geometry(line_string, Meter)
+    }
+}
@@ -0,0 +1,179 @@
+//! Basic geometric shapes, that are measured with lengths.
+//!
+//! All results are returned in the unit of the first length of the shape.
+
+use std::f64::consts::PI;
+
+use crate::{Area, Length};
+
+/// A rectangle with a width and a height.
+#[derive(Clone)]
+pub struct Rectangle {
+    pub width: Length,
+    pub height: Length,
+}
+
+impl Rectangle {
+    pub fn new(width: Length, height: Length) -> Self {
+        Rectangle { width, height }
+    }
+
+    /// Gets the perimeter of the rectangle.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{geometry::Rectangle, Length, Unit, MetricUnit::*};
+    ///
+    /// let width = Length::new_string("2m").unwrap();
+    /// let height = Length::new_string("50cm").unwrap();
+    /// let perimeter = Rectangle::new(width, height).perimeter();
+    ///
+    /// assert_eq!(5.0, perimeter.value);
+    /// assert_eq!(Unit::Metric(Meter), perimeter.unit);
+    /// ```
+    pub fn perimeter(&self) -> Length {
+        self.width.add(self.height.clone()).multiply_by(2)
+    }
+
+    /// Gets the area of the rectangle.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{geometry::Rectangle, Length, Unit, MetricUnit::*};
+    ///
+    /// let width = Length::new_string("2m").unwrap();
+    /// let height = Length::new_string("50cm").unwrap();
+    /// let area = Rectangle::new(width, height).area();
+    ///
+    /// assert_eq!(1.0, area.value);
+    /// assert_eq!(Unit::Metric(Meter), area.unit);
+    /// ```
+    pub fn area(&self) -> Area {
+        Area::from_lengths(&self.width, &self.height)
+    }
+
+    /// Gets the length of the diagonal of the rectangle.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{geometry::Rectangle, Length, Unit, MetricUnit::*};
+    ///
+    /// let width = Length::new_string("3m").unwrap();
+    /// let height = Length::new_string("400cm").unwrap();
+    /// let diagonal = Rectangle::new(width, height).diagonal();
+    ///
+    /// assert_eq!(5.0, diagonal.value);
+    /// assert_eq!(Unit::Metric(Meter), diagonal.unit);
+    /// ```
+    pub fn diagonal(&self) -> Length {
+        let height = self.height.convert(self.width.unit);
+        Length::new_value_unit(self.width.value.hypot(height.value), self.width.unit)
+    }
+}
+
+/// A square with a side length.
+#[derive(Clone)]
+pub struct Square {
+    pub side: Length,
+}
+
+impl Square {
+    pub fn new(side: Length) -> Self {
+        Square { side }
+    }
+
+    /// Gets the perimeter of the square.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{geometry::Square, Length, Unit, ImperialUnit::*};
+    ///
+    /// let perimeter = Square::new(Length::new_string("3ft").unwrap()).perimeter();
+    ///
+    /// assert_eq!(12.0, perimeter.value);
+    /// assert_eq!(Unit::Imperial(Foot), perimeter.unit);
+    /// ```
+    pub fn perimeter(&self) -> Length {
+        self.side.multiply_by(4)
+    }
+
+    /// Gets the area of the square.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{geometry::Square, Length, Unit, ImperialUnit::*};
+    ///
+    /// let area = Square::new(Length::new_string("3ft").unwrap()).area();
+    ///
+    /// assert_eq!(9.0, area.value);
+    /// assert_eq!(Unit::Imperial(Foot), area.unit);
+    /// ```
+    pub fn area(&self) -> Area {
+        Area::from_lengths(&self.side, &self.side)
+    }
+
+    /// Gets the length of the diagonal of the square.
+    pub fn diagonal(&self) -> Length {
+        self.side.multiply_by(2.0_f64.sqrt())
+    }
+}
+
+/// A circle with a radius.
+#[derive(Clone)]
+pub struct Circle {
+    pub radius: Length,
+}
+
+impl Circle {
+    pub fn new(radius: Length) -> Self {
+        Circle { radius }
+    }
+
+    /// Gets the diameter of the circle.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{geometry::Circle, Length, Unit, MetricUnit::*};
+    ///
+    /// let diameter = Circle::new(Length::new_string("5cm").unwrap()).diameter();
+    ///
+    /// assert_eq!(10.0, diameter.value);
+    /// assert_eq!(Unit::Metric(Centimeter), diameter.unit);
+    /// ```
+    pub fn diameter(&self) -> Length {
+        self.radius.multiply_by(2)
+    }
+
+    /// Gets the perimeter (circumference) of the circle.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{geometry::Circle, Length, Unit, MetricUnit::*};
+    /// use std::f64::consts::PI;
+    ///
+    /// let perimeter = Circle::new(Length::new_string("1m").unwrap()).perimeter();
+    ///
+    /// assert_eq!(2.0 * PI, perimeter.value);
+    /// assert_eq!(Unit::Metric(Meter), perimeter.unit);
+    /// ```
+    pub fn perimeter(&self) -> Length {
+        self.radius.multiply_by(2.0 * PI)
+    }
+
+    /// Gets the area of the circle.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{geometry::Circle, Length, Unit, MetricUnit::*};
+    /// use std::f64::consts::PI;
+    ///
+    /// let area = Circle::new(Length::new_string("2m").unwrap()).area();
+    ///
+    /// assert_eq!(4.0 * PI, area.value);
+    /// assert_eq!(Unit::Metric(Meter), area.unit);
+    /// ```
+    pub fn area(&self) -> Area {
+        let square = Area::from_lengths(&self.radius, &self.radius);
+        Area::new_value_unit(square.value * PI, square.unit)
+    }
+}
@@ -0,0 +1,139 @@
+//! Historical and curious units (feature `historical`), to express distances in units, that
+//! readers can picture, like "about 3 football fields".
+//!
+//! The cubit is the English cubit of 18 inches, the fathom the international fathom of 6 feet,
+//! the smoot the height of Oliver Smoot (67 inches), that the Harvard Bridge is measured in, and
+//! the football field the playing field of American football of 100 yards, without the end
+//! zones. The land league of 3 miles is ImperialUnit::League, so it is available without this
+//! feature.
+//!
+//! These units are not related to each other, so a length in one of them is never normalized
+//! into another one.
+//!
+//! A custom unit with the same symbol or name, like a registered "smoot", takes precedence over a
+//! historical unit when parsing, so enabling this feature does not break registrations.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::{SiblingUnit, Unit};
+
+/// Historical and curious units, that are not part of any unit system.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub enum HistoricalUnit {
+    /// The English cubit of 18 inches.
+    Cubit,
+    /// The smoot of 67 inches.
+    Smoot,
+    /// The fathom of 6 feet.
+    Fathom,
+    /// The American football field of 100 yards.
+    FootballField,
+}
+
+impl HistoricalUnit {
+    /// Gets the amount of meters in one of this unit. Unlike Unit::meters_per_unit(), it can be
+    /// evaluated in const contexts.
+    ///
+    /// # Example
+    /// ```
+    /// use length::HistoricalUnit::*;
+    ///
+    /// const FACTOR: f64 = FootballField.meters_per_unit();
+    ///
+    /// assert_eq!(91.44, FACTOR);
+    /// ```
+    pub const fn meters_per_unit(&self) -> f64 {
+        match self {
+            HistoricalUnit::Cubit => 0.457_2,
+            HistoricalUnit::Smoot => 1.701_8,
+            HistoricalUnit::Fathom => 1.828_8,
+            HistoricalUnit::FootballField => 91.44,
+        }
+    }
+
+    /// Gets the symbol of this unit, like "ftm".
+    ///
+    /// # Example
+    /// ```
+    /// use length::HistoricalUnit::*;
+    ///
+    /// assert_eq!("ftm", Fathom.symbol());
+    /// assert_eq!("smoot", Smoot.symbol());
+    /// ```
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            HistoricalUnit::Cubit => "cubit",
+            HistoricalUnit::Smoot => "smoot",
+            HistoricalUnit::Fathom => "ftm",
+            HistoricalUnit::FootballField => "fbf",
+        }
+    }
+
+    /// Gets the English name of this unit in singular, like "football field".
+    ///
+    /// # Example
+    /// ```
+    /// use length::HistoricalUnit::*;
+    ///
+    /// assert_eq!("football field", FootballField.name());
+    /// ```
+    pub fn name(&self) -> &'static str {
+        Unit::from(*self)
+            .names()
+            .map_or("", |(singular, _)| singular)
+    }
+
+    /// Gets the English name of this unit in plural, like "football fields".
+    ///
+    /// # Example
+    /// ```
+    /// use length::HistoricalUnit::*;
+    ///
+    /// assert_eq!("cubits", Cubit.plural_name());
+    /// ```
+    pub fn plural_name(&self) -> &'static str {
+        Unit::from(*self).names().map_or("", |(_, plural)| plural)
+    }
+
+    /// Gets the amount of meters in one unit as numerator and denominator.
+    pub(crate) const fn exact_meters_per_unit(&self) -> Option<(i128, i128)> {
+        match self {
+            HistoricalUnit::Cubit => Some((4_572, 10_000)),
+            HistoricalUnit::Smoot => Some((17_018, 10_000)),
+            HistoricalUnit::Fathom => Some((18_288, 10_000)),
+            HistoricalUnit::FootballField => Some((9_144, 100)),
+        }
+    }
+}
+
+impl SiblingUnit for HistoricalUnit {
+    fn smaller_unit(&self) -> Option<Unit> {
+        None
+    }
+
+    fn greater_unit(&self) -> Option<Unit> {
+        None
+    }
+}
+
+impl fmt::Display for HistoricalUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.symbol())
+    }
+}
+
+impl Ord for HistoricalUnit {
+    /// Orders the units by their size in meters.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.meters_per_unit().total_cmp(&other.meters_per_unit())
+    }
+}
+
+impl PartialOrd for HistoricalUnit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,81 @@
+//! Streaming input of lengths, for files of measurements, that are too large to be read into
+//! memory at once.
+
+use std::io::{BufRead, Lines};
+use std::iter::Enumerate;
+
+use crate::{Length, ParseLengthError};
+
+/// Parses each line of the reader into a Length, like Length::parse(...), while reading it.
+/// Blank lines are skipped.
+///
+/// Errors are wrapped into ParseLengthError::Line with the line number, starting at 1. If a
+/// line can not be read, like for invalid UTF-8, a ParseLengthError::Io is the last item.
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+/// use length::{ParseLengthError, Unit, MetricUnit::*};
+///
+/// let input = Cursor::new("2.5 km\n\n300 m\n12 parsnips\n");
+/// let results: Vec<_> = length::io::parse_lines(input).collect();
+///
+/// assert_eq!(3, results.len());
+/// assert_eq!(Unit::Metric(Kilometer), results[0].as_ref().unwrap().unit);
+/// assert_eq!(300.0, results[1].as_ref().unwrap().value);
+/// assert!(matches!(results[2], Err(ParseLengthError::Line { line: 4, .. })));
+/// assert_eq!(
+///     "line 4: unknown unit \"parsnips\" at 3..11",
+///     results[2].as_ref().unwrap_err().to_string()
+/// );
+/// ```
+pub fn parse_lines<R: BufRead>(
+    reader: R,
+) -> impl Iterator<Item = Result<Length, ParseLengthError>> {
+    ParsedLines {
+        lines: reader.lines().enumerate(),
+        failed: false,
+    }
+}
+
+/// The iterator of parse_lines(...), that ends after an error of the reader, as further reads
+/// may fail forever.
+struct ParsedLines<R> {
+    lines: Enumerate<Lines<R>>,
+    failed: bool,
+}
+
+impl<R: BufRead> Iterator for ParsedLines<R> {
+    type Item = Result<Length, ParseLengthError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        for (index, line) in self.lines.by_ref() {
+            let line_number = index + 1;
+            match line {
+                Ok(line) if line.trim().is_empty() => continue,
+                Ok(line) => {
+                    return Some(
+                        Length::parse(&line).map_err(|error| ParseLengthError::Line {
+                            line: line_number,
+                            error: Box::new(error),
+                        }),
+                    )
+                }
+                Err(error) => {
+                    self.failed = true;
+                    return Some(Err(ParseLengthError::Io {
+                        line: line_number,
+                        kind: error.kind(),
+                        message: error.to_string(),
+                    }));
+                }
+            }
+        }
+
+        None
+    }
+}
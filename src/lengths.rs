@@ -0,0 +1,249 @@
+//! A columnar collection of lengths, that stores only the values and one unit for all of them.
+
+use std::cmp::Ordering;
+use std::iter::{self, Map, Repeat, Zip};
+use std::vec;
+
+use crate::{config, Length, Scalar, Unit};
+
+/// A collection of lengths in one unit, stored as a Vec of values, so large datasets need no
+/// Length struct per element and can be converted and aggregated in bulk.
+///
+/// # Example
+/// ```
+/// use length::{Length, Lengths, Unit, ImperialUnit::*, MetricUnit::*};
+///
+/// let mut altitudes = Lengths::from_values(vec![1_000.0, 2_500.0, 1_500.0], Foot);
+/// altitudes.push(Length::new_value_unit(0.9144, Meter));
+/// altitudes.convert_all(Meter);
+///
+/// assert_eq!(&[304.8, 762.0, 457.2, 0.9144], altitudes.values());
+/// assert_eq!(762.0, altitudes.max().unwrap().value);
+/// assert_eq!(Unit::Metric(Meter), altitudes.sum().unit);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lengths<T = f64> {
+    values: Vec<T>,
+    unit: Unit,
+}
+
+impl<T: Scalar> Lengths<T> {
+    /// Gets a new empty collection of lengths in the given unit.
+    pub fn new<U: Into<Unit>>(unit: U) -> Self {
+        Lengths {
+            values: Vec::new(),
+            unit: unit.into(),
+        }
+    }
+
+    /// Gets a new collection of the given values in the given unit.
+    pub fn from_values<U: Into<Unit>>(values: Vec<T>, unit: U) -> Self {
+        Lengths {
+            values,
+            unit: unit.into(),
+        }
+    }
+
+    /// Gets the unit of all lengths.
+    pub fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    /// Gets the values of the lengths in the unit of the collection.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Gets the values of the lengths, consuming the collection.
+    pub fn into_values(self) -> Vec<T> {
+        self.values
+    }
+
+    /// Gets the number of lengths.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Checks, if the collection has no lengths.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Gets the length at the given index.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Lengths, MetricUnit::*};
+    ///
+    /// let lengths = Lengths::from_values(vec![1.5, 2.5], Meter);
+    ///
+    /// assert_eq!(2.5, lengths.get(1).unwrap().value);
+    /// assert!(lengths.get(2).is_none());
+    /// ```
+    pub fn get(&self, index: usize) -> Option<Length<T>> {
+        self.values
+            .get(index)
+            .map(|&value| Length::from_scalar(value, self.unit))
+    }
+
+    /// Appends a length, that is converted into the unit of the collection.
+    pub fn push(&mut self, length: Length<T>) {
+        self.values.push(length.convert(self.unit).value);
+    }
+
+    /// Converts all lengths into the given unit in place, with one conversion factor for all
+    /// values, like Length::convert_slice_in_place(...).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Lengths, Unit, MetricUnit::*};
+    ///
+    /// let mut lengths = Lengths::from_values(vec![1_500.0, 250.0], Meter);
+    /// lengths.convert_all(Kilometer);
+    ///
+    /// assert_eq!(&[1.5, 0.25], lengths.values());
+    /// assert_eq!(Unit::Metric(Kilometer), lengths.unit());
+    /// ```
+    pub fn convert_all<U: Into<Unit>>(&mut self, unit: U) {
+        let unit = unit.into();
+        Length::convert_slice_in_place(&mut self.values, self.unit, unit);
+        self.unit = unit;
+    }
+
+    /// Gets the sum of the lengths in the unit of the collection, which is 0 for an empty one.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Lengths, ImperialUnit::*};
+    ///
+    /// assert_eq!(6.0, Lengths::from_values(vec![1.0, 2.0, 3.0], Foot).sum().value);
+    /// ```
+    pub fn sum(&self) -> Length<T> {
+        let total = self
+            .values
+            .iter()
+            .fold(T::zero(), |total, &value| total + value);
+
+        Length::from_scalar(total, self.unit)
+    }
+
+    /// Gets the arithmetic mean of the lengths, or None for an empty collection.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Lengths, MetricUnit::*};
+    ///
+    /// assert_eq!(2.0, Lengths::from_values(vec![1.0, 2.0, 3.0], Meter).mean().unwrap().value);
+    /// ```
+    pub fn mean(&self) -> Option<Length<T>> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.sum().divide_by(T::from_usize(self.len())?))
+    }
+
+    /// Gets the shortest length, or None for an empty collection.
+    pub fn min(&self) -> Option<Length<T>> {
+        self.extreme(Ordering::Less)
+    }
+
+    /// Gets the longest length, or None for an empty collection.
+    pub fn max(&self) -> Option<Length<T>> {
+        self.extreme(Ordering::Greater)
+    }
+
+    /// Sorts the lengths in ascending order. Values, that can not be compared, like NaN, are
+    /// treated as equal to all others.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Lengths, MetricUnit::*};
+    ///
+    /// let mut lengths = Lengths::from_values(vec![3.0, 1.0, 2.0], Meter);
+    /// lengths.sort();
+    ///
+    /// assert_eq!(&[1.0, 2.0, 3.0], lengths.values());
+    /// ```
+    pub fn sort(&mut self) {
+        self.values
+            .sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    }
+
+    /// Gets an iterator over the lengths.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Lengths, MetricUnit::*};
+    ///
+    /// let lengths = Lengths::from_values(vec![1.0, 2.0], Kilometer);
+    /// let texts: Vec<String> = lengths.iter().map(|length| length.to_string()).collect();
+    ///
+    /// assert_eq!(vec!["1 km", "2 km"], texts);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Length<T>> + '_ {
+        self.values
+            .iter()
+            .map(|&value| Length::from_scalar(value, self.unit))
+    }
+
+    /// Gets the first length, that compares as the given ordering to all others.
+    fn extreme(&self, ordering: Ordering) -> Option<Length<T>> {
+        let value = self.values.iter().copied().reduce(|extreme, value| {
+            if value.partial_cmp(&extreme) == Some(ordering) {
+                value
+            } else {
+                extreme
+            }
+        })?;
+
+        Some(Length::from_scalar(value, self.unit))
+    }
+}
+
+impl<T: Scalar> IntoIterator for Lengths<T> {
+    type Item = Length<T>;
+    type IntoIter = Map<Zip<vec::IntoIter<T>, Repeat<Unit>>, fn((T, Unit)) -> Length<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values
+            .into_iter()
+            .zip(iter::repeat(self.unit))
+            .map(Length::from)
+    }
+}
+
+/// Collects lengths into the unit of the first one, or into the default unit (see
+/// config::default_unit()) for no lengths.
+///
+/// # Example
+/// ```
+/// use length::{Length, Lengths, Unit, ImperialUnit::*, MetricUnit::*};
+///
+/// let lengths: Lengths = vec![Length::new_value_unit(1, Foot), Length::new_value_unit(1, Inch)]
+///     .into_iter()
+///     .collect();
+///
+/// assert_eq!(Unit::Imperial(Foot), lengths.unit());
+/// assert_eq!(1.0 + 1.0 / 12.0, lengths.sum().value);
+/// ```
+impl<T: Scalar> FromIterator<Length<T>> for Lengths<T> {
+    fn from_iter<I: IntoIterator<Item = Length<T>>>(iter: I) -> Self {
+        let mut iter = iter.into_iter().peekable();
+        let unit = iter
+            .peek()
+            .map_or_else(config::default_unit, |first| first.unit);
+        let mut lengths = Lengths::new(unit);
+        lengths.extend(iter);
+
+        lengths
+    }
+}
+
+impl<T: Scalar> Extend<Length<T>> for Lengths<T> {
+    fn extend<I: IntoIterator<Item = Length<T>>>(&mut self, iter: I) {
+        for length in iter {
+            self.push(length);
+        }
+    }
+}
@@ -1,38 +1,152 @@
-#[macro_use]
-extern crate lazy_static;
-
+use std::cmp::Ordering;
 use std::f64::consts::PI;
+use std::fmt::{self, Write};
 use std::hash::{Hash, Hasher};
+use std::iter::Sum;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Range, Rem, RemAssign, Sub, SubAssign,
+};
 use std::str::FromStr;
+use std::time::Duration;
+
+#[cfg(feature = "regex")]
+use std::sync::OnceLock;
 
+#[cfg(feature = "regex")]
 use regex::Regex;
 
 use AstronomicUnit::*;
 use ImperialUnit::*;
 use MetricUnit::*;
+use SubatomicUnit::*;
+use TypographicUnit::*;
+use UsSurveyUnit::*;
+
+#[cfg(feature = "approx")]
+mod approx;
+mod area;
+#[cfg(feature = "bevy")]
+pub mod bevy;
+mod comparison;
+pub mod config;
+pub mod conformance;
+mod constructors;
+#[cfg(feature = "csv")]
+pub mod csv;
+mod custom;
+mod error;
+mod exact;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fuzz;
+pub mod geo;
+pub mod geometry;
+#[cfg(feature = "historical")]
+pub mod historical;
+pub mod io;
+mod lengths;
+mod literal;
+pub mod locale;
+#[cfg(feature = "measurements")]
+mod measurements;
+#[cfg(feature = "uniffi")]
+pub mod mobile;
+#[cfg(feature = "node")]
+pub mod node;
+mod observer;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod parser;
+#[cfg(feature = "python")]
+pub mod python;
+mod range;
+mod scalar;
+mod scanner;
+#[cfg(feature = "simd")]
+pub mod simd;
+#[cfg(any(feature = "diesel", feature = "sqlx"))]
+pub mod sql;
+pub mod stats;
+pub mod table;
+pub mod text;
+pub mod traditional;
+#[cfg(feature = "uom")]
+mod uom;
+mod volume;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod words;
+
+pub use area::Area;
+pub use comparison::register_common_object;
+pub use custom::CustomUnit;
+pub use error::{LengthError, ParseLengthError, PrecisionLoss};
+pub use exact::ExactLength;
+#[cfg(feature = "historical")]
+pub use historical::HistoricalUnit;
+pub use lengths::Lengths;
+pub use observer::{clear_conversion_observer, set_conversion_observer, Conversion};
+pub use parser::LengthParser;
+pub use range::LengthRange;
+pub use scalar::Scalar;
+pub use traditional::TraditionalUnit;
+pub use volume::{Volume, VolumeUnit};
 
-#[derive(Clone)]
-pub struct Length {
+use scanner::{is_number, is_unit_start, Scanner};
+
+#[cfg(feature = "decimal")]
+pub use rust_decimal::Decimal;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(concrete(T = f64)))]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+pub struct Length<T = f64> {
     pub unit: Unit,
-    pub value: f64,
+    pub value: T,
+    #[cfg_attr(feature = "ts-rs", ts(skip))]
     original_string: String,
+    #[cfg_attr(feature = "ts-rs", ts(skip))]
+    error_bound: Option<f64>,
+    #[cfg_attr(feature = "ts-rs", ts(skip))]
+    exact_origin: Option<(f64, Unit)>,
 }
 
 impl Length {
-    const YARD_TO_METER_FACTOR: f64 = 0.9144;
+    const LIGHTSECOND_TO_METER_FACTOR: f64 = 299_792_458.0;
     const LIGHTYEAR_TO_METER_FACTOR: f64 = 9_460_730_472_580_800.0;
-    const LIGHTDAY_TO_LIGHTYEAR_FACTOR: f64 = 1.0 / 365.25;
-    const LIGHTHOUR_TO_LIGHTYEAR_FACTOR: f64 = 1.0 / (365.25 * 24.0);
-    const LIGHTMINUTE_TO_LIGHTYEAR_FACTOR: f64 = 1.0 / (365.25 * 24.0 * 60.0);
-    const LIGHTSECOND_TO_LIGHTYEAR_FACTOR: f64 = 1.0 / (365.25 * 24.0 * 60.0 * 60.0);
-    const ASTRONOMICAL_UNIT_TO_LIGHTYEAR_FACTOR: f64 = 149_597_870_700.0 / 9_460_730_472_580_800.0;
-    const PARSEC_TO_ASTRONOMICAL_UNITS_FACTOR: f64 = 648_000.0 / PI;
-    const PARSEC_TO_LIGHTYEAR_FACTOR: f64 =
-        Length::ASTRONOMICAL_UNIT_TO_LIGHTYEAR_FACTOR * Length::PARSEC_TO_ASTRONOMICAL_UNITS_FACTOR;
-    const KILOPARSEC_TO_LIGHTYEAR_FACTOR: f64 = Length::PARSEC_TO_LIGHTYEAR_FACTOR * 1_000.0;
-    const MEGAPARSEC_TO_LIGHTYEAR_FACTOR: f64 = Length::PARSEC_TO_LIGHTYEAR_FACTOR * 1_000_000.0;
+    const ASTRONOMICAL_UNIT_TO_METER_FACTOR: f64 = 149_597_870_700.0;
+    const PARSEC_TO_METER_FACTOR: f64 = Length::ASTRONOMICAL_UNIT_TO_METER_FACTOR * 648_000.0 / PI;
+    const SOLAR_RADIUS_TO_METER_FACTOR: f64 = 695_700_000.0;
+    const EARTH_RADIUS_TO_METER_FACTOR: f64 = 6_378_100.0;
+    const LUNAR_DISTANCE_TO_METER_FACTOR: f64 = 384_399_000.0;
+    const POINT_TO_METER_FACTOR: f64 = 0.025_4 / 72.0;
+    const DIDOT_POINT_TO_METER_FACTOR: f64 = 0.000_376_065;
+    const PLANCK_LENGTH_TO_METER_FACTOR: f64 = 1.616_255e-35;
+    const BOHR_RADIUS_TO_METER_FACTOR: f64 = 5.291_772_105_44e-11;
+    /// The resolution of the Pixel unit, that is the CSS reference pixel of 1/96 inch.
+    pub const DEFAULT_DPI: f64 = 96.0;
+    const CUSTOM_UNIT_CODE_OFFSET: u16 = 1_000;
+    const CONVERSION_OPERATIONS: u32 = 2;
+    const BINARY_FORMAT_VERSION: u8 = 1;
+    const BINARY_FLAG_ORIGINAL_STRING: u8 = 0x01;
+    /// The fraction of a step, by which the end of range_step(...) may be missed due to rounding.
+    const STEP_TOLERANCE: f64 = 1e-9;
+    /// The relative error, that to_checked(...) allows, which covers the rounding of a
+    /// conversion there and back.
+    pub const DEFAULT_PRECISION_EPSILON: f64 = 8.0 * f64::EPSILON;
 
-    /// Gets a new Length struct, that represents 0 meters.
+    /// Gets a new Length struct, that represents 0 of the default unit, which is meters, unless
+    /// another one is configured (see config::default_unit()). Use Length::const_new(0.0, ...) in
+    /// const and static contexts.
     ///
     /// # Example
     /// ```
@@ -44,11 +158,7 @@ impl Length {
     /// assert_eq!(Unit::Metric(Meter), length.unit);
     /// ```
     pub fn new() -> Self {
-        Length {
-            unit: Unit::Metric(Meter),
-            value: 0.0,
-            original_string: String::new(),
-        }
+        Length::new_value_unit(0, config::default_unit())
     }
 
     /// Gets a new Length struct with the given value and unit.
@@ -70,472 +180,3916 @@ impl Length {
             unit: unit.into(),
             value: value.into(),
             original_string: String::new(),
+            error_bound: None,
+            exact_origin: None,
         }
     }
 
-    /// Gets a new Option<Length>, that represents a length by a string.
+    /// Gets a new Length struct with the given value and unit, if the value is finite, so
+    /// infinite and NaN values of user data are rejected. See try_new_with(...) for other
+    /// validations.
     ///
     /// # Example
     /// ```
-    /// use length::{Length, Unit, MetricUnit::*};
+    /// use length::{Length, LengthError, Unit, MetricUnit::*};
     ///
-    /// let two_meters = Length::new_string("2m").unwrap();
+    /// let length = Length::try_new(-1.5, Meter).unwrap();
     ///
-    /// assert_eq!(2.0, two_meters.value);
-    /// assert_eq!(Unit::Metric(Meter), two_meters.unit);
+    /// assert_eq!(-1.5, length.value);
+    /// assert_eq!(Unit::Metric(Meter), length.unit);
+    /// assert_eq!(
+    ///     Err(LengthError::NotFinite { value: f64::INFINITY }),
+    ///     Length::try_new(f64::INFINITY, Meter)
+    /// );
     /// ```
-    pub fn new_string<S: Into<String>>(string: S) -> Option<Self> {
-        lazy_static! {
-            static ref RE_LENGTH: Regex =
-                Regex::new(r"^\s*([0-9]+(\.[0-9]+)?)\s*([a-zA-Z]{1,3})\s*$").unwrap();
-        }
-
-        let real_string: String = string.into();
+    pub fn try_new<T: Into<f64>, U: Into<Unit>>(value: T, unit: U) -> Result<Self, LengthError> {
+        Length::try_new_with(value, unit, Validation::default())
+    }
 
-        let caps = RE_LENGTH.captures(real_string.as_str());
-        if caps.is_none() {
-            return None;
+    /// Gets a new Length struct with the given value and unit, if the value passes the
+    /// validation.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, LengthError, Validation, MetricUnit::*};
+    ///
+    /// let validation = Validation {
+    ///     non_negative: true,
+    ///     ..Validation::default()
+    /// };
+    ///
+    /// assert!(Length::try_new_with(0.0, Meter, validation).is_ok());
+    /// assert_eq!(
+    ///     Err(LengthError::Negative { value: -2.0 }),
+    ///     Length::try_new_with(-2.0, Meter, validation)
+    /// );
+    /// ```
+    pub fn try_new_with<T: Into<f64>, U: Into<Unit>>(
+        value: T,
+        unit: U,
+        validation: Validation,
+    ) -> Result<Self, LengthError> {
+        let value = value.into();
+        if validation.finite && !value.is_finite() {
+            return Err(LengthError::NotFinite { value });
+        }
+        if validation.non_negative && value < 0.0 {
+            return Err(LengthError::Negative { value });
         }
 
-        let cap = caps.unwrap();
-        let original_string = String::from(&cap[0]);
-        let value: f64 = match String::from(&cap[1]).parse() {
-            Ok(val) => val,
-            Err(_) => return None,
-        };
+        Ok(Length::new_value_unit(value, unit))
+    }
 
-        let unit = match &cap[3].parse::<Unit>() {
-            Ok(parsed) => *parsed,
-            Err(_) => return None,
+    /// Gets the lengths from start up to and including end in the given step, in the unit of start.
+    ///
+    /// Every length is computed as start + index * step instead of adding up the steps, so
+    /// rounding errors do not accumulate, and the last length does not overshoot end, e.g. 0.3 m
+    /// in steps of 0.1 m. A negative step counts down. If the step is zero, only start is
+    /// yielded, if it points away from end, nothing at all.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// let ticks: Vec<f64> = Length::range_step(
+    ///     Length::new_value_unit(0.0, Centimeter),
+    ///     Length::new_value_unit(0.1, Meter),
+    ///     Length::new_value_unit(2.5, Centimeter),
+    /// )
+    /// .map(|tick| tick.value)
+    /// .collect();
+    ///
+    /// assert_eq!(vec![0.0, 2.5, 5.0, 7.5, 10.0], ticks);
+    ///
+    /// let countdown = Length::range_step(
+    ///     Length::new_value_unit(1.0, Foot),
+    ///     Length::new_value_unit(0.0, Foot),
+    ///     Length::new_value_unit(-4.0, Inch),
+    /// );
+    ///
+    /// assert_eq!(4, countdown.count());
+    /// ```
+    pub fn range_step(start: Length, end: Length, step: Length) -> impl Iterator<Item = Length> {
+        let unit = start.unit;
+        let first = start.value;
+        let last = end.convert(unit).value;
+        let step = step.convert(unit).value;
+
+        let steps = (last - first) / step;
+        let count = if step == 0.0 {
+            1
+        } else if steps.is_nan() || steps < -Length::STEP_TOLERANCE {
+            0
+        } else {
+            ((steps + Length::STEP_TOLERANCE).floor() as usize).saturating_add(1)
         };
 
-        Some(Length {
-            unit: unit,
-            value: value.into(),
-            original_string: original_string,
+        (0..count).map(move |index| {
+            let value = step.mul_add(index as f64, first);
+            let value = if (step > 0.0 && value > last) || (step < 0.0 && value < last) {
+                last
+            } else {
+                value
+            };
+            Length::new_value_unit(value, unit)
         })
     }
 
-    /// Gets the original string of the length, if it was called with new_string(...)
+    /// Gets a new Length struct with the given value and unit, that can be used in const and
+    /// static contexts, as opposed to Length::new_value_unit(...) and Length::new(), which uses the
+    /// configured default unit.
     ///
     /// # Example
     /// ```
-    /// use length::Length;
+    /// use length::{Length, Unit, MetricUnit::*};
     ///
-    /// let two_meter = Length::new_string("2m").unwrap();
-    /// let five_kilometer = Length::new_string("5 km").unwrap();
+    /// const MAX_RANGE: Length = Length::const_new(5.0, Unit::Metric(Kilometer));
     ///
-    /// assert_eq!("2m", two_meter.get_original_string());
-    /// assert_eq!("5 km", five_kilometer.get_original_string());
+    /// assert_eq!(5_000.0, MAX_RANGE.to(Meter).value);
     /// ```
-    pub fn get_original_string(&self) -> String {
-        self.original_string.clone()
+    pub const fn const_new(value: f64, unit: Unit) -> Self {
+        Length {
+            unit,
+            value,
+            original_string: String::new(),
+            error_bound: None,
+            exact_origin: None,
+        }
     }
 
-    /// Gets a normalized Length-struct.
+    /// Gets a new Option<Length>, that represents a length by a string.
+    ///
+    /// Besides the unit symbols, the English unit names in singular and plural are accepted
+    /// regardless of their case, like "5 kilometers", "3 feet" or "2.3 light years". The value
+    /// may have a sign, like "-3.5 m" or "+2 km". Several components like "6 ft 2 in" or
+    /// "1 m 75 cm" are summed up in the unit of the last component, the shorthand 5'10" (also with
+    /// fractional inches like 5' 10 1/2") is read as inches. Fractions and mixed numbers are
+    /// accepted as value, like "1/2 in", "3 5/8 in" or "3½ in".
     ///
     /// # Example
     /// ```
-    /// use length::{Length, Unit, MetricUnit::*};
+    /// use length::{Length, Unit, AstronomicUnit::*, MetricUnit::*};
     ///
-    /// let fivethousand_meter = Length::new_string("5000m").unwrap();
-    /// let five_kilometer = fivethousand_meter.normalize();
+    /// let two_meters = Length::new_string("2m").unwrap();
     ///
-    /// assert_eq!(5.0, five_kilometer.value);
-    /// assert_eq!(Unit::Metric(Kilometer), five_kilometer.unit);
+    /// assert_eq!(2.0, two_meters.value);
+    /// assert_eq!(Unit::Metric(Meter), two_meters.unit);
+    ///
+    /// let distance = Length::new_string("2.3 Light Years").unwrap();
+    ///
+    /// assert_eq!(2.3, distance.value);
+    /// assert_eq!(Unit::Astronomic(Lightyear), distance.unit);
+    ///
+    /// let height = Length::new_string("1 m 75 cm").unwrap();
+    ///
+    /// assert_eq!(175.0, height.value);
+    /// assert_eq!(Unit::Metric(Centimeter), height.unit);
     /// ```
-    pub fn normalize(&self) -> Self {
-        let mut normalized_length = self.clone();
-
-        let mut done = false;
-        let mut iterations = 0;
-        while !done && iterations < 10 {
-            iterations += 1;
-            if normalized_length.value < 1.0 {
-                let smaller_unit = normalized_length.unit.smaller_unit();
-                done = match smaller_unit {
-                    Some(unit) => {
-                        normalized_length.to_by_ref(unit);
-                        false
-                    }
-                    None => true,
-                };
-            } else {
-                let greater_unit = normalized_length.unit.greater_unit();
-                done = match greater_unit {
-                    Some(unit) => {
-                        let test_normalized = normalized_length.to(unit);
-                        if test_normalized.value >= 1.0 {
-                            normalized_length = test_normalized;
-                            false
-                        } else {
-                            true
-                        }
-                    }
-                    None => true,
-                };
-            }
-        }
+    pub fn new_string<S: Into<String>>(string: S) -> Option<Self> {
+        let real_string: String = string.into();
 
-        normalized_length
+        Length::parse(real_string.as_str()).ok()
     }
 
-    /// Gets a normalized Length-struct.
+    /// Parses a string into a Length and reports, why the parsing failed.
     ///
     /// # Example
     /// ```
-    /// use length::{Length, Unit, MetricUnit::*};
+    /// use length::{Length, ParseLengthError, Unit, MetricUnit::*};
     ///
-    /// let mut fivethousand_meter = Length::new_string("5000m").unwrap();
-    /// fivethousand_meter.normalize_by_ref();
+    /// let two_meters = Length::parse("2m").unwrap();
     ///
-    /// assert_eq!(5.0, fivethousand_meter.value);
-    /// assert_eq!(Unit::Metric(Kilometer), fivethousand_meter.unit);
+    /// assert_eq!(2.0, two_meters.value);
+    /// assert_eq!(Unit::Metric(Meter), two_meters.unit);
+    ///
+    /// assert_eq!(Some(ParseLengthError::EmptyInput), Length::parse("  ").err());
+    /// assert_eq!(
+    ///     Some(ParseLengthError::UnknownUnit {
+    ///         unit: String::from("xyz"),
+    ///         span: 2..5,
+    ///     }),
+    ///     Length::parse("2 xyz").err()
+    /// );
     /// ```
-    pub fn normalize_by_ref(&mut self) -> &mut Self {
-        let normalized = self.normalize();
-        self.value = normalized.value;
-        self.unit = normalized.unit;
-
-        self
+    pub fn parse(string: &str) -> Result<Self, ParseLengthError> {
+        Length::parse_with(string, false)
     }
 
-    /// Converts this length into the given unit and returns a new Length-struct.
+    /// Gets a new Option<Length> like new_string(...), but accepts unit symbols regardless of
+    /// their case, like "5 KM" or "3 Ft".
+    ///
+    /// If a symbol is ambiguous without its case, the unit with the exactly matching symbol is
+    /// taken first, then the unit with the lowercase symbol (so "MM" is millimeters and not
+    /// megameters), then the smallest unit. Custom units still need their exact symbol.
     ///
     /// # Example
     /// ```
     /// use length::{Length, Unit, MetricUnit::*};
     ///
-    /// let five_kilometer = Length::new_string("5km").unwrap();
-    /// let fivethousand_meter1 = five_kilometer.to(Unit::Metric(Meter));
-    /// let fivethousand_meter2 = five_kilometer.to(Meter);
-    ///
-    /// assert_eq!(5000.0, fivethousand_meter1.value);
-    /// assert_eq!(Unit::Metric(Meter), fivethousand_meter1.unit);
-    /// assert_eq!(5000.0, fivethousand_meter2.value);
-    /// assert_eq!(Unit::Metric(Meter), fivethousand_meter2.unit);
+    /// assert_eq!(Unit::Metric(Kilometer), Length::new_string_lenient("5 KM").unwrap().unit);
+    /// assert_eq!(Unit::Metric(Millimeter), Length::new_string_lenient("5 MM").unwrap().unit);
+    /// assert_eq!(Unit::Metric(Megameter), Length::new_string_lenient("5 Mm").unwrap().unit);
+    /// assert!(Length::new_string("5 KM").is_none());
     /// ```
-    pub fn to<T: Into<Unit>>(&self, destination_unit: T) -> Self {
-        let destination_unit = destination_unit.into();
+    pub fn new_string_lenient<S: Into<String>>(string: S) -> Option<Self> {
+        let real_string: String = string.into();
 
-        let mut self_cloned = self.clone();
+        Length::parse_with(real_string.as_str(), true).ok()
+    }
 
-        if self_cloned.unit == destination_unit {
-            return self_cloned;
+    /// Parses a string into a Length, with case-insensitive unit symbols, if lenient is set.
+    pub(crate) fn parse_with(string: &str, lenient: bool) -> Result<Self, ParseLengthError> {
+        if let Some(result) = Length::parse_feet_inches(string) {
+            return result;
+        }
+        if let Some(result) = Length::parse_fraction(string, lenient) {
+            return result;
         }
 
-        if self_cloned.unit.system() != destination_unit.system() {
-            match destination_unit.system() {
-                UnitSystem::Astronomic => match self_cloned.unit.system() {
-                    UnitSystem::Metric => {
-                        let source_in_m = self_cloned.to(Unit::Metric(Meter));
-                        let ly = source_in_m.value / Length::LIGHTYEAR_TO_METER_FACTOR;
-                        self_cloned = Length::new_value_unit(ly, Unit::Astronomic(Lightyear));
-                    }
-                    UnitSystem::Imperial => {
-                        let source_in_m = self_cloned.to(Unit::Metric(Meter));
-                        let ly = source_in_m.value / Length::LIGHTYEAR_TO_METER_FACTOR;
-                        self_cloned = Length::new_value_unit(ly, Unit::Astronomic(Lightyear));
-                    }
-                    _ => {}
-                },
-                UnitSystem::Imperial => match self_cloned.unit.system() {
-                    UnitSystem::Astronomic => {
-                        let source_in_ly = self_cloned.to(Unit::Astronomic(Lightyear));
-                        let m = source_in_ly.value * Length::LIGHTYEAR_TO_METER_FACTOR;
-                        let yards = m / Length::YARD_TO_METER_FACTOR;
-                        self_cloned = Length::new_value_unit(yards, Unit::Imperial(Yard));
-                    }
-                    UnitSystem::Metric => {
-                        let source_in_m = self_cloned.to(Unit::Metric(Meter));
-                        let yards = source_in_m.value / Length::YARD_TO_METER_FACTOR;
-                        self_cloned = Length::new_value_unit(yards, Unit::Imperial(Yard));
-                    }
-                    _ => {}
-                },
-                UnitSystem::Metric => match self_cloned.unit.system() {
-                    UnitSystem::Astronomic => {
-                        let source_in_ly = self_cloned.to(Unit::Astronomic(Lightyear));
-                        let m = source_in_ly.value * Length::LIGHTYEAR_TO_METER_FACTOR;
-                        self_cloned = Length::new_value_unit(m, Unit::Metric(Meter));
-                    }
-                    UnitSystem::Imperial => {
-                        let source_in_yd = self_cloned.to(Unit::Imperial(Yard));
-                        let meter = source_in_yd.value * Length::YARD_TO_METER_FACTOR;
-                        self_cloned = Length::new_value_unit(meter, Unit::Metric(Meter));
-                    }
-                    _ => {}
-                },
+        let (number_span, unit_span) = match Length::match_value_unit(string) {
+            Some(spans) => spans,
+            None => {
+                return Length::parse_compound(string, lenient)
+                    .unwrap_or_else(|| Err(Length::classify_parse_error(string)))
             }
+        };
+        let number = &string[number_span.clone()];
+        let value: f64 = match number.parse() {
+            Ok(val) => val,
+            Err(_) => {
+                return Err(ParseLengthError::InvalidNumber {
+                    number: String::from(number),
+                    span: number_span,
+                });
+            }
+        };
+
+        let unit_string = &string[unit_span.clone()];
+        let unit = match Unit::from_str_with(unit_string, lenient) {
+            Ok(parsed) => parsed,
+            Err(_) if unit_string.contains(char::is_whitespace) => {
+                let joined: String = unit_string.split_whitespace().collect();
+                if Unit::from_str_with(&joined, lenient).is_ok() {
+                    return Err(ParseLengthError::SpacedUnit {
+                        unit: String::from(unit_string),
+                        span: unit_span,
+                    });
+                }
+                return Err(Length::classify_parse_error(string));
+            }
+            Err(_) => {
+                return Err(ParseLengthError::UnknownUnit {
+                    unit: String::from(unit_string),
+                    span: unit_span,
+                })
+            }
+        };
+
+        Ok(Length {
+            unit,
+            value,
+            original_string: String::from(string),
+            error_bound: None,
+            exact_origin: None,
+        })
+    }
+
+    /// Matches a string of the form "<number> <unit>", where the number may have a sign and an
+    /// exponent, and gets the spans of the number and the unit.
+    #[cfg(not(feature = "regex"))]
+    fn match_value_unit(string: &str) -> Option<(Range<usize>, Range<usize>)> {
+        // Without the exponent, a unit like "e5 m" is tried, like a backtracking regex would.
+        [true, false].iter().find_map(|&with_exponent| {
+            let mut scanner = Scanner::new(string);
+            scanner.skip_whitespace();
+            let start = scanner.position();
+            scanner.sign();
+            scanner.number()?;
+            if with_exponent {
+                scanner.exponent()?;
+            }
+            let number_span = start..scanner.position();
+            scanner.skip_whitespace();
+            let unit_span = scanner.words(is_unit_start, is_unit_start)?;
+
+            scanner.finish().then_some((number_span, unit_span))
+        })
+    }
+
+    /// Matches a string of the form "<number> <unit>" with the regex of the former parser (feature
+    /// `regex`).
+    #[cfg(feature = "regex")]
+    fn match_value_unit(string: &str) -> Option<(Range<usize>, Range<usize>)> {
+        static RE_LENGTH: OnceLock<Regex> = OnceLock::new();
+        let re_length = RE_LENGTH.get_or_init(|| {
+            Regex::new(
+                r"^\s*([+\-]?[0-9]+(\.[0-9]+)?([eE][+\-]?[0-9]+)?)\s*([^\s0-9.,+\-]\S*(\s+[^\s0-9.,+\-]\S*)*)\s*$",
+            )
+            .unwrap()
+        });
+
+        let cap = re_length.captures(string)?;
+
+        Some((cap.get(1)?.range(), cap.get(4)?.range()))
+    }
+
+    /// Parses a string with several components like "6 ft 2 in" into the sum of them, in the
+    /// unit of the last component. A sign in front applies to the whole sum.
+    ///
+    /// Returns None, if the string does not consist of at least two components.
+    fn parse_compound(string: &str, lenient: bool) -> Option<Result<Self, ParseLengthError>> {
+        let mut scanner = Scanner::new(string);
+        scanner.skip_whitespace();
+        let negative = scanner.sign() == Some('-');
+
+        let mut components = Vec::new();
+        loop {
+            let mut next = scanner;
+            next.skip_whitespace();
+            let number_span = match next.number() {
+                Some(number_span) => number_span,
+                None => break,
+            };
+            next.skip_whitespace();
+            let unit_span = next.word(is_unit_start, |c| !c.is_whitespace())?;
+            components.push((number_span, unit_span));
+            scanner = next;
+        }
+        if components.len() < 2 || !scanner.finish() {
+            return None;
+        }
+
+        let mut parts = Vec::new();
+        for (number_span, unit_span) in components {
+            let value: f64 = string[number_span].parse().ok()?;
+            let unit = match Unit::from_str_with(&string[unit_span.clone()], lenient) {
+                Ok(unit) => unit,
+                Err(_) => {
+                    return Some(Err(ParseLengthError::UnknownUnit {
+                        unit: String::from(&string[unit_span.clone()]),
+                        span: unit_span,
+                    }));
+                }
+            };
+            parts.push(Length::new_value_unit(value, unit));
+        }
+
+        let mut sum = Length::new_value_unit(0, parts.last()?.unit);
+        for part in parts {
+            sum.add_by_ref(part);
+        }
+        if negative {
+            sum.value = -sum.value;
         }
+        sum.original_string = String::from(string);
 
-        let factor = self_cloned.unit.factor() * (1.0 / destination_unit.factor());
-        Length::new_value_unit(self_cloned.value * factor, destination_unit)
+        Some(Ok(sum))
     }
 
-    /// Converts this length into the given unit.
+    /// Parses the feet and inches shorthand like 5'10" or 5' 10 1/2" into inches.
     ///
-    /// # Example
-    /// ```
-    /// use length::{Length, Unit, MetricUnit::*};
+    /// Returns None, if the string does not have feet and inches.
+    fn parse_feet_inches(string: &str) -> Option<Result<Self, ParseLengthError>> {
+        let mut scanner = Scanner::new(string);
+        scanner.skip_whitespace();
+        let negative = scanner.sign() == Some('-');
+        let feet_span = scanner.number()?;
+        scanner.skip_whitespace();
+        scanner.eat_char_if(|c| c == '\'' || c == '′')?;
+        scanner.skip_whitespace();
+        let inches_span = scanner.number()?;
+
+        let mut fraction = scanner;
+        let fraction_spans = if fraction.skip_whitespace() {
+            match (fraction.digits(), fraction.eat("/"), fraction.digits()) {
+                (Some(numerator), true, Some(denominator)) => {
+                    scanner = fraction;
+                    Some((numerator, denominator))
+                }
+                _ => None,
+            }
+        } else {
+            None
+        };
+        scanner.skip_whitespace();
+        scanner.eat_char_if(|c| c == '"' || c == '″');
+        if !scanner.finish() {
+            return None;
+        }
+
+        let feet: f64 = string[feet_span].parse().ok()?;
+        let mut inches: f64 = string[inches_span].parse().ok()?;
+        if let Some((numerator, denominator)) = fraction_spans {
+            let denominator_value: f64 = string[denominator.clone()].parse().ok()?;
+            if denominator_value == 0.0 {
+                return Some(Err(ParseLengthError::InvalidNumber {
+                    number: String::from(&string[numerator.start..denominator.end]),
+                    span: numerator.start..denominator.end,
+                }));
+            }
+            inches += string[numerator].parse::<f64>().ok()? / denominator_value;
+        }
+
+        let mut length = Length::new_value_unit(feet, Foot).convert(Inch);
+        length.value += inches;
+        if negative {
+            length.value = -length.value;
+        }
+        length.original_string = String::from(string);
+
+        Some(Ok(length))
+    }
+
+    /// Parses a fraction or mixed number like "1/2 in", "3 5/8 in" or "3½ in".
     ///
-    /// let mut five_kilometer = Length::new_string("5km").unwrap();
-    /// five_kilometer.to_by_ref(Unit::Metric(Meter));
+    /// Returns None, if the value of the string is not a fraction.
+    fn parse_fraction(string: &str, lenient: bool) -> Option<Result<Self, ParseLengthError>> {
+        let mut scanner = Scanner::new(string);
+        scanner.skip_whitespace();
+        let negative = scanner.sign() == Some('-');
+        let after_sign = scanner;
+
+        // A whole number needs whitespace before a fraction like "3 5/8", but not before a
+        // vulgar fraction like "3½".
+        let mut whole = None;
+        let mut with_whole = after_sign;
+        if let Some(whole_span) = with_whole.digits() {
+            let separated = with_whole.skip_whitespace();
+            let next = with_whole.peek();
+            if separated || next.and_then(Length::vulgar_fraction).is_some() {
+                whole = Some(whole_span);
+                scanner = with_whole;
+            }
+        }
+
+        let (fraction_span, numerator, denominator) = match Length::scan_fraction(&mut scanner) {
+            Some(fraction) => fraction,
+            None if whole.is_some() => {
+                whole = None;
+                scanner = after_sign;
+                Length::scan_fraction(&mut scanner)?
+            }
+            None => return None,
+        };
+
+        scanner.skip_whitespace();
+        let unit_span = scanner.words(|c| is_unit_start(c) && c != '/', is_unit_start)?;
+        if !scanner.finish() {
+            return None;
+        }
+
+        if denominator == 0.0 {
+            return Some(Err(ParseLengthError::InvalidNumber {
+                number: String::from(&string[fraction_span.clone()]),
+                span: fraction_span,
+            }));
+        }
+
+        let unit = match Unit::from_str_with(&string[unit_span.clone()], lenient) {
+            Ok(unit) => unit,
+            Err(_) => {
+                return Some(Err(ParseLengthError::UnknownUnit {
+                    unit: String::from(&string[unit_span.clone()]),
+                    span: unit_span,
+                }))
+            }
+        };
+
+        let whole: f64 = match whole {
+            Some(whole) => string[whole].parse().ok()?,
+            None => 0.0,
+        };
+        let mut value = whole + numerator / denominator;
+        if negative {
+            value = -value;
+        }
+
+        let mut length = Length::new_value_unit(value, unit);
+        length.original_string = String::from(string);
+
+        Some(Ok(length))
+    }
+
+    /// Scans a fraction like "5/8" or a vulgar fraction like "⅝" and gets its span, numerator and
+    /// denominator.
+    fn scan_fraction(scanner: &mut Scanner) -> Option<(Range<usize>, f64, f64)> {
+        let start = scanner.position();
+        let mut fraction = *scanner;
+        let (numerator, denominator) = match fraction.digits() {
+            Some(numerator) => {
+                if !fraction.eat("/") {
+                    return None;
+                }
+                let denominator = fraction.digits()?;
+                (
+                    fraction.slice(numerator).parse().ok()?,
+                    fraction.slice(denominator).parse().ok()?,
+                )
+            }
+            None => {
+                let vulgar = fraction.eat_char_if(|c| Length::vulgar_fraction(c).is_some())?;
+                Length::vulgar_fraction(vulgar)?
+            }
+        };
+        *scanner = fraction;
+
+        Some((start..scanner.position(), numerator, denominator))
+    }
+
+    fn vulgar_fraction(fraction: char) -> Option<(f64, f64)> {
+        match fraction {
+            '½' => Some((1.0, 2.0)),
+            '⅓' => Some((1.0, 3.0)),
+            '⅔' => Some((2.0, 3.0)),
+            '¼' => Some((1.0, 4.0)),
+            '¾' => Some((3.0, 4.0)),
+            '⅕' => Some((1.0, 5.0)),
+            '⅖' => Some((2.0, 5.0)),
+            '⅗' => Some((3.0, 5.0)),
+            '⅘' => Some((4.0, 5.0)),
+            '⅙' => Some((1.0, 6.0)),
+            '⅚' => Some((5.0, 6.0)),
+            '⅐' => Some((1.0, 7.0)),
+            '⅛' => Some((1.0, 8.0)),
+            '⅜' => Some((3.0, 8.0)),
+            '⅝' => Some((5.0, 8.0)),
+            '⅞' => Some((7.0, 8.0)),
+            '⅑' => Some((1.0, 9.0)),
+            '⅒' => Some((1.0, 10.0)),
+            _ => None,
+        }
+    }
+
+    fn classify_parse_error(string: &str) -> ParseLengthError {
+        let trimmed = string.trim();
+        if trimmed.is_empty() {
+            return ParseLengthError::EmptyInput;
+        }
+
+        let number_start = string.len() - string.trim_start().len();
+        let number_length = trimmed
+            .find(|c: char| !(c.is_ascii_digit() || "+-.,".contains(c)))
+            .unwrap_or(trimmed.len());
+        if number_length == 0 {
+            return ParseLengthError::MalformedFormat {
+                input: String::from(string),
+            };
+        }
+
+        let number_span = number_start..number_start + number_length;
+        let number = &string[number_span.clone()];
+        if !is_number(number) {
+            return ParseLengthError::InvalidNumber {
+                number: String::from(number),
+                span: number_span,
+            };
+        }
+
+        let unit = trimmed[number_length..].trim_start();
+        if unit.is_empty() {
+            return ParseLengthError::MissingUnit { span: number_span };
+        }
+        if unit.contains(char::is_whitespace) {
+            return ParseLengthError::MalformedFormat {
+                input: String::from(string),
+            };
+        }
+
+        let unit_start = number_start + trimmed.len() - unit.len();
+        ParseLengthError::UnknownUnit {
+            unit: String::from(unit),
+            span: unit_start..unit_start + unit.len(),
+        }
+    }
+
+    /// Converts this length into the given unit with the exact rational factors of both units.
     ///
-    /// assert_eq!(5000.0, five_kilometer.value);
-    /// assert_eq!(Unit::Metric(Meter), five_kilometer.unit);
+    /// If this length was itself created by to_exact(...), the conversion starts from the original
+    /// value, so chains like km → mi → km return exactly the original value. Returns None, if one of
+    /// the units has no exact rational factor, like the parsec, the Planck length or custom units.
     ///
-    /// five_kilometer.to_by_ref(Kilometer);
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, ImperialUnit::*, MetricUnit::*};
     ///
-    /// assert_eq!(5.0, five_kilometer.value);
-    /// assert_eq!(Unit::Metric(Kilometer), five_kilometer.unit);
+    /// let distance = Length::new_value_unit(0.3, Kilometer);
+    /// let in_miles = distance.to_exact(Mile).unwrap();
+    /// let back = in_miles.to_exact(Kilometer).unwrap();
+    ///
+    /// assert_eq!(0.3, back.value);
+    /// assert_eq!(Unit::Metric(Kilometer), back.unit);
     /// ```
-    pub fn to_by_ref<T: Into<Unit>>(&mut self, destination_unit: T) -> &mut Self {
-        let new_length = self.to(destination_unit);
-        self.value = new_length.value;
-        self.unit = new_length.unit;
+    pub fn to_exact<T: Into<Unit>>(&self, destination_unit: T) -> Option<Self> {
+        let destination_unit = destination_unit.into();
+        let (value, unit) = match self.exact_origin {
+            Some((value, unit))
+                if Length::scale_exact(value, unit, self.unit) == Some(self.value) =>
+            {
+                (value, unit)
+            }
+            _ => (self.value, self.unit),
+        };
 
-        self
+        let mut converted = self.convert(destination_unit);
+        converted.value = Length::scale_exact(value, unit, destination_unit)?;
+        converted.exact_origin = Some((value, unit));
+        self.observe(destination_unit, converted.value);
+
+        Some(converted)
     }
 
-    /// Adds the length and returns a new Length-struct.
+    /// Gets value * numerator / denominator of the exact ratio between the units, with the
+    /// rounding errors of the multiplication and the division compensated.
+    fn scale_exact(value: f64, from: Unit, to: Unit) -> Option<f64> {
+        if from == to {
+            return Some(value);
+        }
+
+        let (numerator, denominator) = Length::exact_ratio(&from, &to)?;
+        let (numerator, denominator) = (numerator as f64, denominator as f64);
+        let product = value * numerator;
+        let product_error = value.mul_add(numerator, -product);
+        let quotient = product / denominator;
+        let remainder = (-quotient).mul_add(denominator, product) + product_error;
+
+        Some(quotient + remainder / denominator)
+    }
+
+    /// Gets the reduced ratio between the units, if both have an exact rational factor.
+    ///
+    /// The ratios of the built-in units are looked up in EXACT_RATIOS, custom units have none.
+    fn exact_ratio(from: &Unit, to: &Unit) -> Option<(i128, i128)> {
+        EXACT_RATIOS[from.index()?][to.index()?]
+    }
+
+    /// Splits this length into components of the given units in descending order, like 1.86 m into
+    /// 6 ft and 1.228... in. All but the last (smallest) component are whole numbers.
     ///
     /// # Example
     /// ```
     /// use length::{Length, Unit, MetricUnit::*};
     ///
-    /// let five_kilometer = Length::new_string("5km").unwrap();
-    /// let twothousand_meter = Length::new_string("2000m").unwrap();
-    /// let seven_kilometer = five_kilometer.add(twothousand_meter);
+    /// let length = Length::new_string("1234.5 m").unwrap();
+    /// let components = length.to_components(&[
+    ///     Unit::Metric(Meter),
+    ///     Unit::Metric(Kilometer),
+    ///     Unit::Metric(Centimeter),
+    /// ]);
     ///
-    /// assert_eq!(7.0, seven_kilometer.value);
-    /// assert_eq!(Unit::Metric(Kilometer), seven_kilometer.unit);
+    /// assert_eq!(1.0, components[0].value);
+    /// assert_eq!(Unit::Metric(Kilometer), components[0].unit);
+    /// assert_eq!(234.0, components[1].value);
+    /// assert_eq!(50.0, components[2].value);
     /// ```
-    pub fn add(&self, length: Length) -> Self {
-        let length_with_source_unit = length.to(self.unit);
-        Length {
-            value: self.value + length_with_source_unit.value,
-            unit: self.unit,
-            ..Default::default()
+    pub fn to_components(&self, units: &[Unit]) -> Vec<Length> {
+        let mut units = units.to_vec();
+        units.sort_by(|a, b| b.meters_per_unit().total_cmp(&a.meters_per_unit()));
+        units.dedup();
+        let smallest = match units.last() {
+            Some(smallest) => *smallest,
+            None => return Vec::new(),
+        };
+
+        let sign = if self.is_negative() { -1.0 } else { 1.0 };
+        let mut remaining = self.convert(smallest).value.abs();
+        let mut components = Vec::with_capacity(units.len());
+        for unit in &units[..units.len() - 1] {
+            let per_unit = Length::new_value_unit(1, *unit).convert(smallest).value;
+            let count = (remaining / per_unit * (1.0 + 4.0 * f64::EPSILON)).floor();
+            remaining = (remaining - count * per_unit).max(0.0);
+            components.push(Length::new_value_unit(sign * count, *unit));
         }
+        components.push(Length::new_value_unit(sign * remaining, smallest));
+
+        components
     }
 
-    /// Adds the length.
+    /// Formats this length as whole numbers of the given units, like "6 ft 1 in" for 1.86 m.
+    ///
+    /// The length is rounded to a whole number of the smallest unit, components that are zero
+    /// are left out.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, ImperialUnit::*};
+    ///
+    /// let height = Length::new_string("1.86 m").unwrap();
+    ///
+    /// assert_eq!(
+    ///     "6 ft 1 in",
+    ///     height.format_mixed(&[Unit::Imperial(Foot), Unit::Imperial(Inch)])
+    /// );
+    /// ```
+    pub fn format_mixed(&self, units: &[Unit]) -> String {
+        let smallest = match units
+            .iter()
+            .min_by(|a, b| a.meters_per_unit().total_cmp(&b.meters_per_unit()))
+        {
+            Some(smallest) => *smallest,
+            None => return String::new(),
+        };
+
+        let rounded = Length::new_value_unit(self.convert(smallest).value.abs().round(), smallest);
+        let parts: Vec<String> = rounded
+            .to_components(units)
+            .iter()
+            .map(|component| Length::new_value_unit(component.value.round(), component.unit))
+            .filter(|component| component.value != 0.0)
+            .map(|component| component.to_string())
+            .collect();
+
+        let text = if parts.is_empty() {
+            format!("0 {}", smallest)
+        } else {
+            parts.join(" ")
+        };
+
+        if self.is_negative() && !parts.is_empty() {
+            format!("-{}", text)
+        } else {
+            text
+        }
+    }
+
+    /// Formats this length in feet and inches like 5' 10 1/2", rounded to the nearest fraction of
+    /// an inch with the given denominator (like 2, 4, 8 or 16), the fraction is reduced.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// let height = Length::new_string("70.4 in").unwrap();
+    ///
+    /// assert_eq!("5' 10 1/2\"", height.format_feet_inches(2));
+    /// assert_eq!("5' 10 3/8\"", height.format_feet_inches(8));
+    /// assert_eq!("5' 10\"", height.format_feet_inches(1));
+    /// ```
+    pub fn format_feet_inches(&self, denominator: u32) -> String {
+        let denominator = u64::from(denominator.max(1));
+        let fractions = (self.convert(Inch).value.abs() * denominator as f64).round() as u64;
+        let feet = fractions / (12 * denominator);
+        let inches = fractions % (12 * denominator) / denominator;
+        let numerator = fractions % denominator;
+
+        let sign = if self.is_negative() && fractions > 0 {
+            "-"
+        } else {
+            ""
+        };
+        let inch_text = if numerator == 0 {
+            inches.to_string()
+        } else {
+            let gcd = gcd(numerator as i128, denominator as i128) as u64;
+            let fraction = format!("{}/{}", numerator / gcd, denominator / gcd);
+            if inches == 0 {
+                fraction
+            } else {
+                format!("{} {}", inches, fraction)
+            }
+        };
+
+        format!("{}{}' {}\"", sign, feet, inch_text)
+    }
+
+    /// Converts this length into inches and rounds it to the nearest fraction of an inch with the
+    /// given denominator (like 16, 32 or 64). Returns the snapped length and the residual error,
+    /// that is the original length minus the snapped one, both in inches.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, ImperialUnit::*};
+    ///
+    /// let (snapped, residual) = Length::new_string("20 mm").unwrap().snap_to_fraction(16);
+    ///
+    /// assert_eq!(0.8125, snapped.value);
+    /// assert_eq!(Unit::Imperial(Inch), snapped.unit);
+    /// assert!((residual.value + 0.025_098_425_196_850_4).abs() < 1e-12);
+    /// ```
+    pub fn snap_to_fraction(&self, denominator: u32) -> (Self, Self) {
+        let denominator = f64::from(denominator.max(1));
+        let inches = self.convert(Inch).value;
+        let snapped = (inches * denominator).round() / denominator;
+
+        (
+            Length::new_value_unit(snapped, Inch),
+            Length::new_value_unit(inches - snapped, Inch),
+        )
+    }
+
+    /// Gets a readable text of this length in the best unit of its unit system (see Unit::best_for)
+    /// with the value rounded to two significant digits, like "about 3.2 km" or "7 in".
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, ImperialUnit::*};
+    ///
+    /// let height = Length::new_string("17.8 cm").unwrap().to(Foot);
+    ///
+    /// assert_eq!("about 7 in", height.humanize());
+    /// assert_eq!("about 3.2 km", Length::new_string("3217 m").unwrap().humanize());
+    /// assert_eq!("250 m", Length::new_string("250 m").unwrap().humanize());
+    /// ```
+    pub fn humanize(&self) -> String {
+        let value_in_meters = self.value_in_meters();
+        let unit = Unit::best_for(value_in_meters, self.unit.system());
+        let value = self.convert(unit).value;
+        let rounded = if value == 0.0 || !value.is_finite() {
+            value
+        } else {
+            let scale = 10_f64.powi(1 - value.abs().log10().floor() as i32);
+            (value * scale).round() / scale
+        };
+
+        if rounded == value {
+            format!("{} {}", rounded, unit)
+        } else {
+            format!("about {} {}", rounded, unit)
+        }
+    }
+
+    /// Gets a text of this length in the metric unit with an SI prefix, whose exponent is a
+    /// multiple of 3, so the value is at least 1 and below 1000, like "42 µm" or "13 Mm". The
+    /// length itself is not changed.
+    ///
+    /// Unlike normalize(), centimeters, decimeters, decameters and hectometers are never used.
+    /// Lengths below 1 qm or from 1000 Qm keep the smallest or greatest prefix, and the value is
+    /// rounded to 15 significant digits, which hides rounding errors of the conversion.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// assert_eq!("42 µm", Length::new_value_unit(0.000042, Meter).format_engineering());
+    /// assert_eq!("13 Mm", Length::new_value_unit(1.3e7, Meter).format_engineering());
+    /// assert_eq!("304.8 mm", Length::new_value_unit(1, Foot).format_engineering());
+    /// ```
+    pub fn format_engineering(&self) -> String {
+        let meters = self.value_in_meters();
+        if meters == 0.0 || !meters.is_finite() {
+            return format!("{} {}", meters, Unit::Metric(Meter));
+        }
+
+        let last = ENGINEERING_UNITS.len() - 1;
+        let mut index =
+            ((meters.abs().log10() / 3.0).floor() as i64 + 10).clamp(0, last as i64) as usize;
+        // log10 may be off by one for values next to a power of 1000.
+        let mut value = self.convert(ENGINEERING_UNITS[index]).value;
+        if value.abs() >= 1000.0 && index < last {
+            index += 1;
+            value = self.convert(ENGINEERING_UNITS[index]).value;
+        } else if value.abs() < 1.0 && index > 0 {
+            index -= 1;
+            value = self.convert(ENGINEERING_UNITS[index]).value;
+        }
+
+        let rounded: f64 = format!("{:.14e}", value)
+            .parse()
+            .expect("a formatted f64 is parsable");
+
+        format!("{} {}", rounded, Unit::Metric(ENGINEERING_UNITS[index]))
+    }
+
+    /// Converts this length into the given unit and rounds it to the given number of decimal places.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// let length = Length::new_string("1234.5 m").unwrap();
+    ///
+    /// assert_eq!(1.23, length.round_to(Kilometer, 2).value);
+    /// assert_eq!("1.23 km", length.round_to(Kilometer, 2).to_string());
+    /// ```
+    pub fn round_to<T: Into<Unit>>(&self, unit: T, decimals: u32) -> Self {
+        let scale = 10_f64.powi(decimals as i32);
+        let mut rounded = self.convert(unit);
+        rounded.value = (rounded.value * scale).round() / scale;
+
+        rounded
+    }
+
+    /// Converts this length into the given unit and rounds it down to a whole number.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// assert_eq!(17.0, Length::new_string("17.8 mm").unwrap().floor_to(Millimeter).value);
+    /// ```
+    pub fn floor_to<T: Into<Unit>>(&self, unit: T) -> Self {
+        let mut rounded = self.convert(unit);
+        rounded.value = rounded.value.floor();
+
+        rounded
+    }
+
+    /// Converts this length into the given unit and rounds it up to a whole number.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// assert_eq!(18.0, Length::new_string("1.73 cm").unwrap().ceil_to(Millimeter).value);
+    /// ```
+    pub fn ceil_to<T: Into<Unit>>(&self, unit: T) -> Self {
+        let mut rounded = self.convert(unit);
+        rounded.value = rounded.value.ceil();
+
+        rounded
+    }
+
+    /// Converts this length into the given unit and removes the fractional part of the value.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// assert_eq!(-17.0, Length::new_string("-17.8 mm").unwrap().trunc_to(Millimeter).value);
+    /// ```
+    pub fn trunc_to<T: Into<Unit>>(&self, unit: T) -> Self {
+        let mut rounded = self.convert(unit);
+        rounded.value = rounded.value.trunc();
+
+        rounded
+    }
+
+    /// Sums the lengths into the unit of the first length with the compensated (Neumaier)
+    /// summation, an empty iterator sums up to 0 m.
+    ///
+    /// The naive summation loses up to n * ε of the magnitude of the summands for n lengths,
+    /// while the compensated summation keeps the error of the summation itself at about 2 * ε of
+    /// the result, independent of n (ε = f64::EPSILON). Each length is still rounded once, when
+    /// it is converted into the unit of the first length.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// let segments = vec![Length::new_string("0.1 m").unwrap(); 10];
+    /// let naive: Length = segments.iter().sum();
+    /// let compensated = Length::sum_compensated(segments);
+    ///
+    /// assert_ne!(1.0, naive.value);
+    /// assert_eq!(1.0, compensated.value);
+    /// ```
+    pub fn sum_compensated<I: IntoIterator<Item = Length>>(lengths: I) -> Self {
+        let mut lengths = lengths.into_iter();
+        let first = match lengths.next() {
+            Some(first) => first,
+            None => return Length::new_value_unit(0.0, Meter),
+        };
+
+        let (mut sum, mut compensation) = (first.value, 0.0);
+        for length in lengths {
+            let value = length.convert(first.unit).value;
+            let total = sum + value;
+            if sum.abs() >= value.abs() {
+                compensation += (sum - total) + value;
+            } else {
+                compensation += (value - total) + sum;
+            }
+            sum = total;
+        }
+
+        Length::new_value_unit(sum + compensation, first.unit)
+    }
+
+    /// Gets the time, that light needs in vacuum to travel this distance. Negative lengths are
+    /// treated as their absolute value, and lengths, that are not finite or too long for a
+    /// Duration saturate to Duration::MAX.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use length::Length;
+    ///
+    /// let moon = Length::new_string("384399 km").unwrap();
+    ///
+    /// assert_eq!(1_282, moon.light_travel_time().as_millis());
+    /// assert_eq!(Duration::from_secs(60), Length::new_string("1 lm").unwrap().light_travel_time());
+    /// ```
+    pub fn light_travel_time(&self) -> Duration {
+        let seconds = self.convert(Lightsecond).value.abs();
+
+        Duration::try_from_secs_f64(seconds).unwrap_or(Duration::MAX)
+    }
+
+    /// Gets the distance in light-seconds, that light travels in vacuum in the given time.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use length::{Length, Unit, AstronomicUnit::*, MetricUnit::*};
+    ///
+    /// let distance = Length::from_light_travel(Duration::from_millis(1_500));
+    ///
+    /// assert_eq!(1.5, distance.value);
+    /// assert_eq!(Unit::Astronomic(Lightsecond), distance.unit);
+    /// assert_eq!(449_688_687.0, distance.to(Meter).value);
+    /// ```
+    pub fn from_light_travel(duration: Duration) -> Self {
+        Length::new_value_unit(duration.as_secs_f64(), Lightsecond)
+    }
+
+    /// Gets the volume of a cube with this length as its edge, in the cube of the unit of this
+    /// length.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, VolumeUnit};
+    ///
+    /// let edge = Length::new_string("10 cm").unwrap();
+    ///
+    /// assert_eq!("1000 cm³", edge.cubed().to_string());
+    /// assert!((edge.cubed().to(VolumeUnit::Liter).value - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn cubed(&self) -> Volume {
+        Volume::from_lengths(self, self, self)
+    }
+
+    /// Gets the number of pixels, that this length covers at the given resolution in dots per inch.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// let one_inch = Length::new_string("1in").unwrap();
+    ///
+    /// assert_eq!(96.0, one_inch.to_pixels(Length::DEFAULT_DPI));
+    /// assert_eq!(300.0, one_inch.to_pixels(300.0));
+    /// ```
+    pub fn to_pixels(&self, dpi: f64) -> f64 {
+        self.convert(Unit::Imperial(Inch)).value * dpi
+    }
+
+    /// Compares two lengths by their physical magnitude with a total ordering (see f64::total_cmp),
+    /// so lengths in different units can be sorted.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// let mut lengths = vec![
+    ///     Length::new_string("1 km").unwrap(),
+    ///     Length::new_string("900 m").unwrap(),
+    ///     Length::new_string("1 mi").unwrap(),
+    /// ];
+    /// lengths.sort_by(Length::total_cmp);
+    ///
+    /// assert_eq!("900 m", lengths[0].to_string());
+    /// assert_eq!("1 km", lengths[1].to_string());
+    /// assert_eq!("1 mi", lengths[2].to_string());
+    /// ```
+    pub fn total_cmp(&self, other: &Length) -> Ordering {
+        self.value_in_meters().total_cmp(&other.value_in_meters())
+    }
+
+    /// Checks, if the physical magnitude of both lengths differ by at most the given tolerance.
+    ///
+    /// In contrast to ==, this is robust against rounding errors of conversions.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// let one_inch = Length::new_string("1 in").unwrap();
+    /// let cm = Length::new_string("2.54 cm").unwrap();
+    /// let tolerance = Length::new_string("1 nm").unwrap();
+    ///
+    /// assert!(one_inch.eq_within(&cm, &tolerance));
+    /// assert!(!one_inch.eq_within(&Length::new_string("2.55 cm").unwrap(), &tolerance));
+    /// ```
+    pub fn eq_within(&self, other: &Length, tolerance: &Length) -> bool {
+        (self.value_in_meters() - other.value_in_meters()).abs()
+            <= tolerance.value_in_meters().abs()
+    }
+
+    /// Starts tracking an absolute error bound (in the unit of this length), that grows with
+    /// every conversion and arithmetic operation by the possible floating point rounding error.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// let measured = Length::new_string("100m").unwrap().with_error_bound(0.005);
+    /// let in_feet = measured.to(length::ImperialUnit::Foot);
+    ///
+    /// assert!(in_feet.error_bound().unwrap() > 0.005 / 0.3048);
+    /// assert!(in_feet.error_bound().unwrap() < 0.0165);
+    /// ```
+    pub fn with_error_bound(mut self, error_bound: f64) -> Self {
+        self.error_bound = Some(error_bound.abs());
+        self
+    }
+
+    /// Converts this length into the given unit like to(...), but fails, if the result can not be
+    /// converted back into the original value within a relative error of
+    /// DEFAULT_PRECISION_EPSILON, like when the result underflows into subnormal numbers.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// let tiny = Length::new_value_unit(3e-275, Yoctometer);
+    /// assert_eq!(3_000.0, Length::new_value_unit(3, Kilometer).to_checked(Meter).unwrap().value);
+    ///
+    /// let loss = tiny.to_checked(Yottameter).unwrap_err();
+    /// assert!(loss.relative_error > 1e-3);
+    /// assert_eq!(tiny.to(Yottameter), loss.converted);
+    /// ```
+    pub fn to_checked<U: Into<Unit>>(&self, unit: U) -> Result<Self, PrecisionLoss> {
+        self.to_checked_with(unit, Length::DEFAULT_PRECISION_EPSILON)
+    }
+
+    /// Converts this length into the given unit like to_checked(...), with a custom greatest
+    /// relative error.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// let tiny = Length::new_value_unit(3e-275, Yoctometer);
+    ///
+    /// assert!(tiny.to_checked_with(Yottameter, 1e-3).is_err());
+    /// assert!(tiny.to_checked_with(Yottameter, 0.5).is_ok());
+    /// ```
+    pub fn to_checked_with<U: Into<Unit>>(
+        &self,
+        unit: U,
+        epsilon: f64,
+    ) -> Result<Self, PrecisionLoss> {
+        let converted = self.convert(unit);
+        let round_trip = converted.converted_value(&self.unit).0;
+        let relative_error = if self.value == round_trip || self.value.is_nan() {
+            0.0
+        } else {
+            ((round_trip - self.value) / self.value).abs()
+        };
+
+        if relative_error <= epsilon {
+            Ok(converted)
+        } else {
+            Err(PrecisionLoss {
+                converted,
+                relative_error,
+                epsilon,
+            })
+        }
+    }
+
+    fn rounding_error(value: f64, operations: u32) -> f64 {
+        value.abs() * f64::EPSILON * f64::from(operations)
+    }
+
+    fn combine_error_bounds(a: Option<f64>, b: Option<f64>, result: f64) -> Option<f64> {
+        match (a, b) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or(0.0) + b.unwrap_or(0.0) + Length::rounding_error(result, 1)),
+        }
+    }
+
+    /// Encodes this length into a compact, versioned binary representation.
+    ///
+    /// The layout (all numbers in little endian) is:
+    /// version (u8), flags (u8), unit code (u16), value (f64) and, if the flag 0x01 is set,
+    /// the byte length (u32) followed by the UTF-8 bytes of the original string.
+    ///
+    /// The unit code of a custom unit depends on the order of the registrations (see Unit::code()),
+    /// so the bytes of a length in a custom unit are not portable to other processes.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// let bytes = Length::new_string("5 km").unwrap().to_bytes();
+    ///
+    /// assert_eq!(1, bytes[0]);
+    /// assert_eq!(20, bytes.len());
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(12 + 4 + self.original_string.len());
+        bytes.push(Length::BINARY_FORMAT_VERSION);
+        if self.original_string.is_empty() {
+            bytes.push(0);
+        } else {
+            bytes.push(Length::BINARY_FLAG_ORIGINAL_STRING);
+        }
+        bytes.extend_from_slice(&self.unit.code().to_le_bytes());
+        bytes.extend_from_slice(&self.value.to_le_bytes());
+        if !self.original_string.is_empty() {
+            bytes.extend_from_slice(&(self.original_string.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(self.original_string.as_bytes());
+        }
+
+        bytes
+    }
+
+    /// Decodes a length, that was encoded by to_bytes().
+    ///
+    /// Returns None, if the bytes are truncated, have trailing bytes or are not a valid encoding.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let bytes = Length::new_string("5 km").unwrap().to_bytes();
+    /// let decoded = Length::from_bytes(&bytes).unwrap();
+    ///
+    /// assert_eq!(5.0, decoded.value);
+    /// assert_eq!(Unit::Metric(Kilometer), decoded.unit);
+    /// assert_eq!("5 km", decoded.get_original_string());
+    /// assert!(Length::from_bytes(&bytes[..5]).is_none());
+    /// assert!(Length::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_none());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 12 || bytes[0] != Length::BINARY_FORMAT_VERSION {
+            return None;
+        }
+
+        let flags = bytes[1];
+        let unit = Unit::try_from_code(u16::from_le_bytes([bytes[2], bytes[3]]))?;
+        let value = f64::from_le_bytes(bytes[4..12].try_into().ok()?);
+        let mut length = Length::new_value_unit(value, unit);
+
+        if flags & Length::BINARY_FLAG_ORIGINAL_STRING == 0 {
+            return (bytes.len() == 12).then_some(length);
+        }
+
+        let string_length = u32::from_le_bytes(bytes.get(12..16)?.try_into().ok()?) as usize;
+        if bytes.len() != 16usize.checked_add(string_length)? {
+            return None;
+        }
+        length.original_string = String::from_utf8(bytes[16..].to_vec()).ok()?;
+
+        Some(length)
+    }
+}
+
+impl<T: Scalar> Length<T> {
+    /// Gets a new Length struct with a value of any Scalar type, like f32 or rust_decimal::Decimal.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let length = Length::from_scalar(2.5_f32, Kilometer);
+    ///
+    /// assert_eq!(2.5_f32, length.value);
+    /// assert_eq!(2_500.0_f32, length.to(Meter).value);
+    /// assert_eq!(Unit::Metric(Kilometer), length.unit);
+    /// ```
+    pub fn from_scalar<U: Into<Unit>>(value: T, unit: U) -> Self {
+        Length {
+            unit: unit.into(),
+            value,
+            original_string: String::new(),
+            error_bound: None,
+            exact_origin: None,
+        }
+    }
+
+    /// Converts the value of this length into another Scalar type.
+    ///
+    /// Returns None, if the value is not representable in the other type.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// let five_kilometer = Length::new_string("5 km").unwrap();
+    /// let single_precision = five_kilometer.cast::<f32>().unwrap();
+    ///
+    /// assert_eq!(5.0_f32, single_precision.value);
+    /// assert_eq!(five_kilometer.unit, single_precision.unit);
+    /// ```
+    pub fn cast<S: Scalar>(&self) -> Option<Length<S>> {
+        Some(Length {
+            unit: self.unit,
+            value: S::from_f64(self.value.to_f64()?)?,
+            original_string: self.original_string.clone(),
+            error_bound: self.error_bound,
+            exact_origin: None,
+        })
+    }
+
+    /// Gets the original string of the length, if it was called with new_string(...)
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// let two_meter = Length::new_string("2m").unwrap();
+    /// let five_kilometer = Length::new_string("5 km").unwrap();
+    ///
+    /// assert_eq!("2m", two_meter.get_original_string());
+    /// assert_eq!("5 km", five_kilometer.get_original_string());
+    /// ```
+    pub fn get_original_string(&self) -> String {
+        self.original_string.clone()
+    }
+
+    /// Sets the original string, that get_original_string() returns. Conversions and arithmetic
+    /// operations do not keep the original string, so this is the way to record, where a
+    /// converted or calculated length came from.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// let parsed = Length::new_string("5 km").unwrap();
+    /// let in_meters = parsed.to(Meter);
+    /// assert_eq!("", in_meters.get_original_string());
+    ///
+    /// let in_meters = in_meters.with_original_string(parsed.get_original_string());
+    /// assert_eq!("5 km", in_meters.get_original_string());
+    /// assert_eq!(5_000.0, in_meters.value);
+    /// ```
+    pub fn with_original_string<S: Into<String>>(mut self, original_string: S) -> Self {
+        self.original_string = original_string.into();
+        self
+    }
+
+    /// Gets a normalized Length-struct.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let fivethousand_meter = Length::new_string("5000m").unwrap();
+    /// let five_kilometer = fivethousand_meter.normalize();
+    ///
+    /// assert_eq!(5.0, five_kilometer.value);
+    /// assert_eq!(Unit::Metric(Kilometer), five_kilometer.unit);
+    /// ```
+    pub fn normalize(&self) -> Self {
+        let mut normalized_length = Length {
+            error_bound: self.error_bound,
+            exact_origin: self.exact_origin,
+            ..Length::from_scalar(self.value, self.unit)
+        };
+
+        let mut done = false;
+        let mut iterations = 0;
+        while !done && iterations < 10 {
+            iterations += 1;
+            if normalized_length.value < T::one() {
+                let smaller_unit = normalized_length.unit.smaller_unit();
+                done = match smaller_unit.and_then(|unit| {
+                    Some((unit, normalized_length.checked_converted_value(&unit)?))
+                }) {
+                    Some((unit, (value, error_bound))) => {
+                        normalized_length.value = value;
+                        normalized_length.unit = unit;
+                        normalized_length.error_bound = error_bound;
+                        false
+                    }
+                    None => true,
+                };
+            } else {
+                let greater_unit = normalized_length.unit.greater_unit();
+                done = match greater_unit.and_then(|unit| {
+                    Some((unit, normalized_length.checked_converted_value(&unit)?))
+                }) {
+                    Some((unit, (value, error_bound))) if value >= T::one() => {
+                        normalized_length.value = value;
+                        normalized_length.unit = unit;
+                        normalized_length.error_bound = error_bound;
+                        false
+                    }
+                    _ => true,
+                };
+            }
+        }
+
+        normalized_length
+    }
+
+    /// Gets a normalized Length-struct.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let mut fivethousand_meter = Length::new_string("5000m").unwrap();
+    /// fivethousand_meter.normalize_by_ref();
+    ///
+    /// assert_eq!(5.0, fivethousand_meter.value);
+    /// assert_eq!(Unit::Metric(Kilometer), fivethousand_meter.unit);
+    /// ```
+    pub fn normalize_by_ref(&mut self) -> &mut Self {
+        let normalized = self.normalize();
+        self.value = normalized.value;
+        self.unit = normalized.unit;
+        self.error_bound = normalized.error_bound;
+
+        self
+    }
+
+    /// Gets a length, that is normalized to the best unit of the given unit system.
+    ///
+    /// In contrast to normalize(), the unit system of this length may be left and only the commonly
+    /// used units of Unit::best_for(...) are chosen. For UnitSystem::Custom the length is returned
+    /// unchanged, as custom units have no order.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, UnitSystem, AstronomicUnit::*, MetricUnit::*};
+    ///
+    /// let distance = Length::new_string("1800 m").unwrap();
+    /// let imperial = distance.normalize_to_system(UnitSystem::Imperial);
+    ///
+    /// assert_eq!("1.12 mi", format!("{:.2}", imperial));
+    ///
+    /// let far = Length::new_value_unit(2e16, Meter);
+    /// let astronomic = far.normalize_to_system(UnitSystem::Astronomic);
+    ///
+    /// assert_eq!(Unit::Astronomic(Lightyear), astronomic.unit);
+    /// assert_eq!("2.11 ly", format!("{:.2}", astronomic));
+    /// ```
+    pub fn normalize_to_system(&self, system: UnitSystem) -> Self {
+        match system.base_unit() {
+            Some(_) => self.convert(Unit::best_for(self.approximate_meters(), system)),
+            None => self.clone(),
+        }
+    }
+
+    /// Converts this length into the given unit and returns a new Length-struct.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let five_kilometer = Length::new_string("5km").unwrap();
+    /// let fivethousand_meter1 = five_kilometer.to(Unit::Metric(Meter));
+    /// let fivethousand_meter2 = five_kilometer.to(Meter);
+    ///
+    /// assert_eq!(5000.0, fivethousand_meter1.value);
+    /// assert_eq!(Unit::Metric(Meter), fivethousand_meter1.unit);
+    /// assert_eq!(5000.0, fivethousand_meter2.value);
+    /// assert_eq!(Unit::Metric(Meter), fivethousand_meter2.unit);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics, if the scalar type can not represent the conversion factors, like a Decimal, that
+    /// is converted from quettameters or into Planck lengths. Use checked_to(...) for these.
+    pub fn to<U: Into<Unit>>(&self, destination_unit: U) -> Self {
+        let converted = self.convert(destination_unit);
+        self.observe(converted.unit, converted.value);
+
+        converted
+    }
+
+    /// Converts this length like to(...), but without calling the conversion observer, for the
+    /// conversions within other operations, like comparing or adding lengths.
+    pub(crate) fn convert<U: Into<Unit>>(&self, destination_unit: U) -> Self {
+        let destination_unit = destination_unit.into();
+        let (value, error_bound) = self.converted_value(&destination_unit);
+        let exact_origin = if self.unit == destination_unit {
+            self.exact_origin
+        } else {
+            None
+        };
+
+        Length {
+            error_bound,
+            exact_origin,
+            ..Length::from_scalar(value, destination_unit)
+        }
+    }
+
+    /// Converts this length into the given unit like to(...), but returns None, if the scalar type
+    /// can not represent the conversion factors or the converted value, or if the converted value
+    /// is infinite or NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// let length = Length::new_value_unit(1e300, Yottameter);
+    ///
+    /// assert_eq!(Some(1e303), length.checked_to(Zettameter).map(|length| length.value));
+    /// assert_eq!(None, length.checked_to(Yoctometer));
+    /// ```
+    pub fn checked_to<U: Into<Unit>>(&self, destination_unit: U) -> Option<Self> {
+        let destination_unit = destination_unit.into();
+        self.checked_converted_value(&destination_unit)?;
+
+        Some(self.to(destination_unit)).filter(Length::is_finite)
+    }
+
+    /// Passes an explicit conversion of this length to the conversion observer, unless the unit
+    /// does not change.
+    fn observe(&self, destination_unit: Unit, converted_value: T) {
+        if self.unit == destination_unit {
+            return;
+        }
+
+        observer::observe(
+            self.unit,
+            destination_unit,
+            Length::approximate(self.value),
+            Length::approximate(converted_value),
+        );
+    }
+
+    /// Gets the value and the error bound of this length in the given unit, without creating a
+    /// new Length.
+    ///
+    /// Panics, if the scalar type can not represent the conversion factors or the converted value,
+    /// see checked_converted_value(...).
+    fn converted_value(&self, destination_unit: &Unit) -> (T, Option<f64>) {
+        self.checked_converted_value(destination_unit).expect(
+            "the conversion factors are not representable in the scalar type, use checked_to(...)",
+        )
+    }
+
+    /// Gets the value and the error bound like converted_value(...), or None, if the scalar type
+    /// can not represent the conversion factors or the converted value.
+    fn checked_converted_value(&self, destination_unit: &Unit) -> Option<(T, Option<f64>)> {
+        if self.unit == *destination_unit {
+            return Some((self.value, self.error_bound));
+        }
+
+        let (from_factor, to_factor) = Self::checked_factors(&self.unit, destination_unit)?;
+        let product = Length::approximate(self.value) * Length::approximate(from_factor);
+        T::from_f64(product)?;
+        T::from_f64(product / Length::approximate(to_factor))?;
+
+        let value = self.value * from_factor / to_factor;
+        let error_bound = self.error_bound.map(|error_bound| {
+            let scale = Length::approximate(from_factor) / Length::approximate(to_factor);
+            error_bound * scale.abs()
+                + Length::rounding_error(Length::approximate(value), Length::CONVERSION_OPERATIONS)
+        });
+
+        Some((value, error_bound))
+    }
+
+    /// Converts values from one unit into another, like to(...) does for every value, but gets the
+    /// conversion factors only once and does not create a Length per value.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// let heights = Length::convert_slice(&[1.0, 2.5, 100.0], Foot, Meter);
+    ///
+    /// assert_eq!(vec![0.3048, 0.762, 30.48], heights);
+    /// ```
+    pub fn convert_slice<F: Into<Unit>, D: Into<Unit>>(values: &[T], from: F, to: D) -> Vec<T> {
+        let (from_factor, to_factor) = Self::factors(&from.into(), &to.into());
+
+        values
+            .iter()
+            .map(|&value| value * from_factor / to_factor)
+            .collect()
+    }
+
+    /// Converts values from one unit into another in place, like convert_slice(...).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// let mut points = [1_250.0, -80.0, 3.0];
+    /// Length::convert_slice_in_place(&mut points, Millimeter, Meter);
+    ///
+    /// assert_eq!([1.25, -0.08, 0.003], points);
+    /// ```
+    pub fn convert_slice_in_place<F: Into<Unit>, D: Into<Unit>>(values: &mut [T], from: F, to: D) {
+        let (from_factor, to_factor) = Self::factors(&from.into(), &to.into());
+
+        for value in values.iter_mut() {
+            *value = *value * from_factor / to_factor;
+        }
+    }
+
+    /// Converts this length into the given unit.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let mut five_kilometer = Length::new_string("5km").unwrap();
+    /// five_kilometer.to_by_ref(Unit::Metric(Meter));
+    ///
+    /// assert_eq!(5000.0, five_kilometer.value);
+    /// assert_eq!(Unit::Metric(Meter), five_kilometer.unit);
+    ///
+    /// five_kilometer.to_by_ref(Kilometer);
+    ///
+    /// assert_eq!(5.0, five_kilometer.value);
+    /// assert_eq!(Unit::Metric(Kilometer), five_kilometer.unit);
+    /// ```
+    pub fn to_by_ref<U: Into<Unit>>(&mut self, destination_unit: U) -> &mut Self {
+        let destination_unit = destination_unit.into();
+        let (value, error_bound) = self.converted_value(&destination_unit);
+        self.observe(destination_unit, value);
+        (self.value, self.error_bound) = (value, error_bound);
+        self.unit = destination_unit;
+
+        self
+    }
+
+    /// Adds the length and returns a new Length-struct.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let five_kilometer = Length::new_string("5km").unwrap();
+    /// let twothousand_meter = Length::new_string("2000m").unwrap();
+    /// let seven_kilometer = five_kilometer.add(twothousand_meter);
+    ///
+    /// assert_eq!(7.0, seven_kilometer.value);
+    /// assert_eq!(Unit::Metric(Kilometer), seven_kilometer.unit);
+    /// ```
+    pub fn add(&self, length: Length<T>) -> Self {
+        let length_with_source_unit = length.convert(self.unit);
+        let value = self.value + length_with_source_unit.value;
+        Length {
+            error_bound: Length::combine_error_bounds(
+                self.error_bound,
+                length_with_source_unit.error_bound,
+                Length::approximate(value),
+            ),
+            ..Length::from_scalar(value, self.unit)
+        }
+    }
+
+    /// Adds the length.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let mut five_kilometer = Length::new_string("5km").unwrap();
+    /// let twothousand_meter = Length::new_string("2000m").unwrap();
+    /// five_kilometer.add_by_ref(twothousand_meter);
+    ///
+    /// assert_eq!(7.0, five_kilometer.value);
+    /// assert_eq!(Unit::Metric(Kilometer), five_kilometer.unit);
+    /// ```
+    pub fn add_by_ref(&mut self, length: Length<T>) -> &mut Self {
+        let length_with_source_unit = length.convert(self.unit);
+        self.value = self.value + length_with_source_unit.value;
+        self.error_bound = Length::combine_error_bounds(
+            self.error_bound,
+            length_with_source_unit.error_bound,
+            Length::approximate(self.value),
+        );
+        self
+    }
+
+    /// Subtracts the length and returns a new Length-struct.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let five_kilometer = Length::new_string("5km").unwrap();
+    /// let twothousand_meter = Length::new_string("2000m").unwrap();
+    /// let three_kilometer = five_kilometer.subtract(twothousand_meter);
+    ///
+    /// assert_eq!(3.0, three_kilometer.value);
+    /// assert_eq!(Unit::Metric(Kilometer), three_kilometer.unit);
+    /// ```
+    pub fn subtract(&self, length: Length<T>) -> Self {
+        let length_with_source_unit = length.convert(self.unit);
+        let value = self.value - length_with_source_unit.value;
+        Length {
+            error_bound: Length::combine_error_bounds(
+                self.error_bound,
+                length_with_source_unit.error_bound,
+                Length::approximate(value),
+            ),
+            ..Length::from_scalar(value, self.unit)
+        }
+    }
+
+    /// Subtracts the length.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let mut five_kilometer = Length::new_string("5km").unwrap();
+    /// let twothousand_meter = Length::new_string("2000m").unwrap();
+    /// five_kilometer.subtract_by_ref(twothousand_meter);
+    ///
+    /// assert_eq!(3.0, five_kilometer.value);
+    /// assert_eq!(Unit::Metric(Kilometer), five_kilometer.unit);
+    /// ```
+    pub fn subtract_by_ref(&mut self, length: Length<T>) -> &mut Self {
+        let length_with_source_unit = length.convert(self.unit);
+        self.value = self.value - length_with_source_unit.value;
+        self.error_bound = Length::combine_error_bounds(
+            self.error_bound,
+            length_with_source_unit.error_bound,
+            Length::approximate(self.value),
+        );
+        self
+    }
+
+    /// Gets the leftover after fitting the given length as often as possible into this length and
+    /// returns a new Length-struct in the unit of this length. The result has the sign of this
+    /// length.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let stock = Length::new_string("10 m").unwrap();
+    /// let piece = Length::new_string("300 cm").unwrap();
+    /// let leftover = stock.rem(piece);
+    ///
+    /// assert_eq!(1.0, leftover.value);
+    /// assert_eq!(Unit::Metric(Meter), leftover.unit);
+    /// ```
+    pub fn rem(&self, length: Length<T>) -> Self {
+        let length_with_source_unit = length.convert(self.unit);
+        let value = self.value % length_with_source_unit.value;
+        Length {
+            error_bound: Length::combine_error_bounds(
+                self.error_bound,
+                length_with_source_unit.error_bound,
+                Length::approximate(value),
+            ),
+            ..Length::from_scalar(value, self.unit)
+        }
+    }
+
+    /// Replaces this length with the leftover after fitting the given length as often as possible
+    /// into it.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, ImperialUnit::*};
+    ///
+    /// let mut stock = Length::new_string("10 ft").unwrap();
+    /// stock.rem_by_ref(Length::new_string("18 in").unwrap());
+    ///
+    /// assert_eq!(1.0, stock.value);
+    /// assert_eq!(Unit::Imperial(Foot), stock.unit);
+    /// ```
+    pub fn rem_by_ref(&mut self, length: Length<T>) -> &mut Self {
+        let length_with_source_unit = length.convert(self.unit);
+        self.value = self.value % length_with_source_unit.value;
+        self.error_bound = Length::combine_error_bounds(
+            self.error_bound,
+            length_with_source_unit.error_bound,
+            Length::approximate(self.value),
+        );
+        self
+    }
+
+    /// Multiplies the length and returns a new Length-struct.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let five_kilometer = Length::new_string("5km").unwrap();
+    /// let fifty_kilometer = five_kilometer.multiply_by(10);
+    ///
+    /// assert_eq!(50.0, fifty_kilometer.value);
+    /// assert_eq!(Unit::Metric(Kilometer), fifty_kilometer.unit);
+    /// ```
+    pub fn multiply_by<F: Into<T>>(&self, factor: F) -> Self {
+        let real_factor: T = factor.into();
+        let value = self.value * real_factor;
+        Length {
+            error_bound: self.error_bound.map(|error_bound| {
+                error_bound * Length::approximate(real_factor).abs()
+                    + Length::rounding_error(Length::approximate(value), 1)
+            }),
+            ..Length::from_scalar(value, self.unit)
+        }
+    }
+
+    /// Multiplies the length by a factor.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let mut five_kilometer = Length::new_string("5km").unwrap();
+    /// five_kilometer.multiply_by_ref(10);
+    ///
+    /// assert_eq!(50.0, five_kilometer.value);
+    /// assert_eq!(Unit::Metric(Kilometer), five_kilometer.unit);
+    /// ```
+    pub fn multiply_by_ref<F: Into<T>>(&mut self, factor: F) -> &mut Self {
+        let real_factor: T = factor.into();
+        self.value = self.value * real_factor;
+        self.error_bound = self.error_bound.map(|error_bound| {
+            error_bound * Length::approximate(real_factor).abs()
+                + Length::rounding_error(Length::approximate(self.value), 1)
+        });
+        self
+    }
+
+    /// Divides the length and returns a new Length-struct.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let five_kilometer = Length::new_string("5km").unwrap();
+    /// let one_kilometer = five_kilometer.divide_by(5);
+    ///
+    /// assert_eq!(1.0, one_kilometer.value);
+    /// assert_eq!(Unit::Metric(Kilometer), one_kilometer.unit);
+    /// ```
+    pub fn divide_by<F: Into<T>>(&self, factor: F) -> Self {
+        let real_factor: T = factor.into();
+        let value = self.value / real_factor;
+        Length {
+            error_bound: self.error_bound.map(|error_bound| {
+                error_bound * (1.0 / Length::approximate(real_factor)).abs()
+                    + Length::rounding_error(Length::approximate(value), 1)
+            }),
+            ..Length::from_scalar(value, self.unit)
+        }
+    }
+
+    /// Divides the length by a factor.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let mut five_kilometer = Length::new_string("5km").unwrap();
+    /// five_kilometer.divide_by_ref(5);
+    ///
+    /// assert_eq!(1.0, five_kilometer.value);
+    /// assert_eq!(Unit::Metric(Kilometer), five_kilometer.unit);
+    /// ```
+    pub fn divide_by_ref<F: Into<T>>(&mut self, factor: F) -> &mut Self {
+        let real_factor: T = factor.into();
+        self.value = self.value / real_factor;
+        self.error_bound = self.error_bound.map(|error_bound| {
+            error_bound * (1.0 / Length::approximate(real_factor)).abs()
+                + Length::rounding_error(Length::approximate(self.value), 1)
+        });
+        self
+    }
+
+    /// Adds the length like add(...), but returns None, if the sum is infinite or NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// let meter = Length::new_value_unit(1.0, Meter);
+    /// let huge = Length::new_value_unit(f64::MAX, Meter);
+    ///
+    /// assert_eq!(Some(2.0), meter.checked_add(meter.clone()).map(|sum| sum.value));
+    /// assert_eq!(None, huge.checked_add(huge.clone()));
+    /// ```
+    pub fn checked_add(&self, length: Length<T>) -> Option<Self> {
+        Some(self.add(length)).filter(Length::is_finite)
+    }
+
+    /// Subtracts the length like subtract(...), but returns None, if the difference is infinite or
+    /// NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// let yottameter = Length::new_value_unit(1e300, Yottameter);
+    ///
+    /// assert_eq!(None, Length::new_value_unit(1.0, Yoctometer).checked_sub(yottameter));
+    /// ```
+    pub fn checked_sub(&self, length: Length<T>) -> Option<Self> {
+        Some(self.subtract(length)).filter(Length::is_finite)
+    }
+
+    /// Multiplies the length like multiply_by(...), but returns None, if the product is infinite or
+    /// NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// let length = Length::new_value_unit(1e300, Kilometer);
+    ///
+    /// assert_eq!(Some(2e300), length.checked_mul(2).map(|product| product.value));
+    /// assert_eq!(None, length.checked_mul(1e10));
+    /// assert_eq!(None, length.checked_mul(f64::NAN));
+    /// ```
+    pub fn checked_mul<F: Into<T>>(&self, factor: F) -> Option<Self> {
+        Some(self.multiply_by(factor)).filter(Length::is_finite)
+    }
+
+    /// Divides the length like divide_by(...), but returns None, if the factor is zero or the
+    /// quotient is infinite or NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// let length = Length::new_value_unit(5.0, Meter);
+    ///
+    /// assert_eq!(Some(2.5), length.checked_div(2).map(|quotient| quotient.value));
+    /// assert_eq!(None, length.checked_div(0));
+    /// assert_eq!(None, length.checked_div(1e-320));
+    /// ```
+    pub fn checked_div<F: Into<T>>(&self, factor: F) -> Option<Self> {
+        let real_factor: T = factor.into();
+        if real_factor.is_zero() {
+            return None;
+        }
+
+        Some(self.divide_by(real_factor)).filter(Length::is_finite)
+    }
+
+    /// Checks, if the value of this length is neither infinite nor NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// assert!(Length::new_value_unit(1e300, Yottameter).is_finite());
+    /// assert!(!Length::new_value_unit(1e300, Yottameter).to(Yoctometer).is_finite());
+    /// assert!(!Length::new_value_unit(f64::NAN, Meter).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        Length::approximate(self.value).is_finite()
+    }
+
+    /// Gets the dimensionless ratio of this length to the given length, that is converted into the
+    /// unit of this length first.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// let total = Length::new_string("3 km").unwrap();
+    /// let segment = Length::new_string("250 m").unwrap();
+    ///
+    /// assert_eq!(12.0, total.ratio_to(&segment));
+    /// assert_eq!(12.0, total / segment);
+    /// ```
+    pub fn ratio_to(&self, other: &Length<T>) -> T {
+        self.value / other.convert(self.unit).value
+    }
+
+    /// Gets the linear interpolation between this length (t = 0) and the given length (t = 1) in
+    /// the unit of this length. Values of t outside of 0..=1 extrapolate.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let start = Length::new_string("1 m").unwrap();
+    /// let end = Length::new_string("300 cm").unwrap();
+    /// let quarter = start.lerp(&end, 0.25);
+    ///
+    /// assert_eq!(1.5, quarter.value);
+    /// assert_eq!(Unit::Metric(Meter), quarter.unit);
+    /// ```
+    pub fn lerp(&self, other: &Length<T>, t: T) -> Self {
+        let difference = other.convert(self.unit).subtract(self.clone());
+
+        self.add(difference.multiply_by(t))
+    }
+
+    /// Gets the length halfway between this length and the given length in the unit of this
+    /// length.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, ImperialUnit::*};
+    ///
+    /// let start = Length::new_string("1 ft").unwrap();
+    /// let middle = start.midpoint(&Length::new_string("36 in").unwrap());
+    ///
+    /// assert_eq!(2.0, middle.value);
+    /// assert_eq!(Unit::Imperial(Foot), middle.unit);
+    /// ```
+    pub fn midpoint(&self, other: &Length<T>) -> Self {
+        self.lerp(other, T::one() / (T::one() + T::one()))
+    }
+
+    fn value_in_meters(&self) -> T {
+        self.convert(Unit::Metric(Meter)).value
+    }
+
+    /// Gets the value of this length in the given unit, like to(unit).value, without keeping the
+    /// converted Length.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// let length = Length::new_value_unit(3, Foot);
+    ///
+    /// assert_eq!(36.0, length.value_in(Inch));
+    /// assert_eq!(0.9144, length.value_in(Meter));
+    /// ```
+    pub fn value_in<U: Into<Unit>>(&self, unit: U) -> T {
+        self.convert(unit).value
+    }
+
+    /// Gets the value of the length in millimeters, like value_in(Millimeter).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// assert_eq!(25.0, Length::new_value_unit(2.5, Centimeter).as_millimeters());
+    /// ```
+    pub fn as_millimeters(&self) -> T {
+        self.value_in(Millimeter)
+    }
+
+    /// Gets the value of the length in centimeters, like value_in(Centimeter).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// assert_eq!(2.54, Length::new_value_unit(1, Inch).as_centimeters());
+    /// ```
+    pub fn as_centimeters(&self) -> T {
+        self.value_in(Centimeter)
+    }
+
+    /// Gets the value of the length in meters, like value_in(Meter).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// assert_eq!(2_500.0, Length::new_value_unit(2.5, Kilometer).as_meters());
+    /// ```
+    pub fn as_meters(&self) -> T {
+        self.value_in(Meter)
+    }
+
+    /// Gets the value of the length in kilometers, like value_in(Kilometer).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// assert_eq!(2.5, Length::new_value_unit(2_500, Meter).as_kilometers());
+    /// ```
+    pub fn as_kilometers(&self) -> T {
+        self.value_in(Kilometer)
+    }
+
+    /// Gets the value of the length in inches, like value_in(Inch).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, ImperialUnit::*};
+    ///
+    /// assert_eq!(24.0, Length::new_value_unit(2, Foot).as_inches());
+    /// ```
+    pub fn as_inches(&self) -> T {
+        self.value_in(Inch)
+    }
+
+    /// Gets the value of the length in feet, like value_in(Foot).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, ImperialUnit::*};
+    ///
+    /// assert_eq!(9.0, Length::new_value_unit(3, Yard).as_feet());
+    /// ```
+    pub fn as_feet(&self) -> T {
+        self.value_in(Foot)
+    }
+
+    /// Gets the value of the length in yards, like value_in(Yard).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, ImperialUnit::*};
+    ///
+    /// assert_eq!(1_760.0, Length::new_value_unit(1, Mile).as_yards());
+    /// ```
+    pub fn as_yards(&self) -> T {
+        self.value_in(Yard)
+    }
+
+    /// Gets the value of the length in miles, like value_in(Mile).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// assert_eq!(1.0, Length::new_value_unit(1_609.344, Meter).as_miles());
+    /// ```
+    pub fn as_miles(&self) -> T {
+        self.value_in(Mile)
+    }
+
+    /// Gets the value of the length in light-years, like value_in(Lightyear).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, AstronomicUnit::*, MetricUnit::*};
+    ///
+    /// assert_eq!(1.0, Length::new_value_unit(9_460_730_472_580_800.0, Meter).as_light_years());
+    /// ```
+    pub fn as_light_years(&self) -> T {
+        self.value_in(Lightyear)
+    }
+
+    /// Gets the tracked absolute error bound, if error tracking was started with with_error_bound(...).
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// let untracked = Length::new_string("2km").unwrap();
+    /// let tracked = Length::new_string("2km").unwrap().with_error_bound(0.0);
+    ///
+    /// assert_eq!(None, untracked.error_bound());
+    /// assert_eq!(Some(0.0), tracked.error_bound());
+    /// assert!(tracked.multiply_by(3).error_bound().unwrap() > 0.0);
+    /// ```
+    pub fn error_bound(&self) -> Option<f64> {
+        self.error_bound
+    }
+
+    /// Checks, if the value of this length is less than zero.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// assert!(Length::new_string("-3.5 m").unwrap().is_negative());
+    /// assert!(!Length::new_string("+2 km").unwrap().is_negative());
+    /// ```
+    pub fn is_negative(&self) -> bool {
+        self.value < T::zero()
+    }
+
+    /// Gets the length with the absolute value, in the same unit.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// let displacement = Length::new_string("-3.5 m").unwrap();
+    ///
+    /// assert_eq!(3.5, displacement.abs().value);
+    /// assert_eq!("3.5 m", displacement.abs().to_string());
+    /// ```
+    pub fn abs(&self) -> Self {
+        let mut length = self.clone();
+        if length.is_negative() {
+            length.value = T::zero() - length.value;
+        }
+
+        length
+    }
+
+    /// Gets 1 for a positive, -1 for a negative and the value itself for a zero (or NaN) length.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    ///
+    /// assert_eq!(-1.0, Length::new_string("-3.5 m").unwrap().signum());
+    /// assert_eq!(1.0, Length::new_string("2 km").unwrap().signum());
+    /// assert_eq!(0.0, Length::new_string("0 km").unwrap().signum());
+    /// ```
+    pub fn signum(&self) -> T {
+        if self.value > T::zero() {
+            T::one()
+        } else if self.is_negative() {
+            T::zero() - T::one()
+        } else {
+            self.value
+        }
+    }
+
+    /// Gets the smaller one of this length and the given length, both compared in meters. The
+    /// returned length keeps its unit. If the lengths are not comparable (NaN), this length is
+    /// returned.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, ImperialUnit::*};
+    ///
+    /// let one_meter = Length::new_string("1 m").unwrap();
+    /// let shorter = one_meter.min(Length::new_string("3 ft").unwrap());
+    ///
+    /// assert_eq!(3.0, shorter.value);
+    /// assert_eq!(Unit::Imperial(Foot), shorter.unit);
+    /// ```
+    pub fn min(&self, other: Length<T>) -> Self {
+        if other < *self {
+            other
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Gets the larger one of this length and the given length, both compared in meters. The
+    /// returned length keeps its unit. If the lengths are not comparable (NaN), this length is
+    /// returned.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let one_meter = Length::new_string("1 m").unwrap();
+    /// let longer = one_meter.max(Length::new_string("3 ft").unwrap());
+    ///
+    /// assert_eq!(1.0, longer.value);
+    /// assert_eq!(Unit::Metric(Meter), longer.unit);
+    /// ```
+    pub fn max(&self, other: Length<T>) -> Self {
+        if other > *self {
+            other
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Restricts this length to the given interval and returns it in the unit of this length.
+    ///
+    /// # Panics
+    /// Panics, if min is greater than max.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let min = Length::new_string("1 mm").unwrap();
+    /// let max = Length::new_string("10 km").unwrap();
+    /// let input = Length::new_string("12000 m").unwrap();
+    ///
+    /// assert_eq!(10_000.0, input.clamp(min.clone(), max.clone()).value);
+    /// assert_eq!(Unit::Metric(Meter), input.clamp(min.clone(), max.clone()).unit);
+    /// assert_eq!(0.001, Length::new_string("0 m").unwrap().clamp(min, max).value);
+    /// ```
+    pub fn clamp(&self, min: Length<T>, max: Length<T>) -> Self {
+        assert!(min <= max, "min must not be greater than max");
+
+        if *self < min {
+            min.convert(self.unit)
+        } else if *self > max {
+            max.convert(self.unit)
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Gets the factors of value * from / to, preferring the exact ratio between both units.
+    ///
+    /// Panics, if the scalar type can not represent the factors, see checked_factors(...).
+    fn factors(from: &Unit, to: &Unit) -> (T, T) {
+        Self::checked_factors(from, to).expect(
+            "the conversion factors are not representable in the scalar type, use checked_to(...)",
+        )
+    }
+
+    /// Gets the factors like factors(...), or None, if the scalar type can not represent them,
+    /// because they are out of its range or are rounded to zero, like the factor of the Planck
+    /// length in a Decimal.
+    fn checked_factors(from: &Unit, to: &Unit) -> Option<(T, T)> {
+        Length::exact_ratio(from, to)
+            .and_then(|(numerator, denominator)| {
+                Some((T::from_i128(numerator)?, T::from_i128(denominator)?))
+            })
+            .or_else(|| {
+                Some((
+                    T::from_f64(from.meters_per_unit())?,
+                    T::from_f64(to.meters_per_unit())?,
+                ))
+            })
+            .filter(|(from_factor, to_factor)| !from_factor.is_zero() && !to_factor.is_zero())
+    }
+
+    /// Checks, if the value is NaN, which only floating point scalar types can be.
+    fn is_nan(&self) -> bool {
+        Length::approximate(self.value).is_nan()
+    }
+
+    /// Gets the value in meters as f64, for the lengths, that the scalar type can not represent in
+    /// meters.
+    fn approximate_meters(&self) -> f64 {
+        Length::approximate(self.value) * self.unit.meters_per_unit()
+    }
+
+    /// Gets the value as f64 for the error bound calculations.
+    fn approximate(value: T) -> f64 {
+        value.to_f64().unwrap_or(f64::NAN)
+    }
+}
+
+impl<T: Scalar + fmt::Display> Length<T> {
+    /// Writes the formatted length into the given writer, without allocating a String.
+    ///
+    /// The value is converted and its trailing zeros are trimmed while writing, and the symbols and
+    /// names of the built-in units are static strings, so only the writer itself may allocate.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{DisplayOptions, Length};
+    ///
+    /// let length = Length::new_string("1.609344 km").unwrap();
+    /// let mut output = String::new();
+    ///
+    /// length.format_into(&mut output, DisplayOptions::default()).unwrap();
+    /// assert_eq!("1.609344 km", output);
+    ///
+    /// output.clear();
+    /// let options = DisplayOptions {
+    ///     precision: Some(2),
+    ///     ..DisplayOptions::default()
+    /// };
+    /// length.format_into(&mut output, options).unwrap();
+    /// assert_eq!("1.61 km", output);
+    ///
+    /// output.clear();
+    /// let options = DisplayOptions {
+    ///     ascii: true,
+    ///     ..DisplayOptions::default()
+    /// };
+    /// Length::new_string("3 µm").unwrap().format_into(&mut output, options).unwrap();
+    /// assert_eq!("3 um", output);
+    /// ```
+    pub fn format_into<W: fmt::Write>(&self, w: &mut W, options: DisplayOptions) -> fmt::Result {
+        let (value, unit) = match options.unit {
+            Some(unit) => (self.converted_value(&unit).0, unit),
+            None => (self.value, self.unit),
+        };
+
+        match options.precision {
+            Some(precision) if options.trim_zeros => {
+                let mut trimmed = TrimZeros::new(w);
+                write!(trimmed, "{:.*}", precision, value)?
+            }
+            Some(precision) => write!(w, "{:.*}", precision, value)?,
+            None => write!(w, "{}", value)?,
+        }
+        if options.space {
+            w.write_char(' ')?;
+        }
+
+        let text = match unit.names() {
+            Some((singular, _)) if options.names && Self::approximate(value).abs() == 1.0 => {
+                Some(singular)
+            }
+            Some((_, plural)) if options.names => Some(plural),
+            _ if options.ascii => unit.ascii_replacement(),
+            _ => None,
+        };
+        match text {
+            Some(text) => w.write_str(text),
+            None => write!(w, "{}", unit),
+        }
+    }
+
+    /// Formats the length with the given options, like format_into(...) into a new String.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{DisplayOptions, Length, MetricUnit::*};
+    ///
+    /// let options = DisplayOptions::new()
+    ///     .unit(Kilometer)
+    ///     .precision(3)
+    ///     .trim_zeros(true)
+    ///     .names(true);
+    ///
+    /// assert_eq!("2.5 kilometers", Length::new_value_unit(2_500, Meter).format_with(&options));
+    /// assert_eq!("1 kilometer", Length::new_value_unit(1_000, Meter).format_with(&options));
+    /// assert_eq!(
+    ///     "12.5cm",
+    ///     Length::new_value_unit(12.5, Centimeter).format_with(&DisplayOptions::new().space(false))
+    /// );
+    /// ```
+    pub fn format_with(&self, options: &DisplayOptions) -> String {
+        let mut text = String::new();
+        self.format_into(&mut text, *options)
+            .expect("writing into a String never fails");
+
+        text
+    }
+}
+
+/// Removes the trailing zeros of the decimal places and a trailing decimal point of the number,
+/// that is written through it, like "2.50" to "2.5" or "3.00" to "3".
+///
+/// The zeros after the decimal point and the decimal point itself are held back as a count, until
+/// another digit follows, so no buffer is needed.
+struct TrimZeros<'a, W: fmt::Write> {
+    writer: &'a mut W,
+    decimal_places: bool,
+    pending_point: bool,
+    pending_zeros: usize,
+}
+
+impl<'a, W: fmt::Write> TrimZeros<'a, W> {
+    fn new(writer: &'a mut W) -> Self {
+        TrimZeros {
+            writer,
+            decimal_places: false,
+            pending_point: false,
+            pending_zeros: 0,
+        }
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for TrimZeros<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if !self.decimal_places && c == '.' {
+                self.decimal_places = true;
+                self.pending_point = true;
+            } else if self.decimal_places && c == '0' {
+                self.pending_zeros += 1;
+            } else {
+                if self.pending_point {
+                    self.writer.write_char('.')?;
+                    self.pending_point = false;
+                }
+                for _ in 0..self.pending_zeros {
+                    self.writer.write_char('0')?;
+                }
+                self.pending_zeros = 0;
+                self.writer.write_char(c)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Gets 0 in the default unit, which is meters, unless another one is configured with
+/// config::set_default_unit(...) or config::with_default_unit(...).
+impl Default for Length {
+    fn default() -> Length {
+        Length::new()
+    }
+}
+
+/// Honors the precision (number of decimal places of the value) and the width, fill and
+/// alignment (of the whole text) of the format specifier. The alternate flag writes the unit with
+/// ASCII characters only.
+///
+/// # Example
+/// ```
+/// use length::Length;
+///
+/// let length = Length::new_string("1.609344 km").unwrap();
+///
+/// assert_eq!("1.61 km", format!("{:.2}", length));
+/// assert_eq!("1.6 km   ", format!("{:<9.1}", length));
+/// assert_eq!("**1.6 km**", format!("{:*^10.1}", length));
+/// assert_eq!("2.5 angstrom", format!("{:#}", Length::new_string("2.5 Å").unwrap()));
+/// ```
+impl<T: Scalar + fmt::Display> fmt::Display for Length<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let options = DisplayOptions {
+            precision: f.precision(),
+            ascii: f.alternate(),
+            ..DisplayOptions::default()
+        };
+
+        if f.width().is_none() {
+            return self.format_into(f, options);
+        }
+
+        let mut text = String::new();
+        self.format_into(&mut text, options)?;
+        pad(f, &text)
+    }
+}
+
+/// Formats the value in scientific notation with a lowercase "e", like "1.5e3 m". Honors the
+/// same format specifier parts as Display.
+///
+/// # Example
+/// ```
+/// use length::{Length, AstronomicUnit::*, MetricUnit::*};
+///
+/// assert_eq!("1.5e3 m", format!("{:e}", Length::new_value_unit(1500, Meter)));
+/// assert_eq!("4.2e0 ly", format!("{:.1e}", Length::new_value_unit(4.2465, Lightyear)));
+/// assert_eq!("3e-6 um", format!("{:#e}", Length::new_value_unit(0.000003, Micrometer)));
+/// ```
+impl<T: Scalar + fmt::LowerExp> fmt::LowerExp for Length<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match f.precision() {
+            Some(precision) => format!("{:.*e}", precision, self.value),
+            None => format!("{:e}", self.value),
+        };
+        self.format_exp(f, text)
+    }
+}
+
+/// Formats the value in scientific notation with an uppercase "E", like "1.5E3 m". Honors the
+/// same format specifier parts as Display.
+///
+/// # Example
+/// ```
+/// use length::{Length, MetricUnit::*};
+///
+/// assert_eq!("1.5E3 m", format!("{:E}", Length::new_value_unit(1500, Meter)));
+/// assert_eq!("  4.2E-9 m", format!("{:>10E}", Length::new_value_unit(4.2e-9, Meter)));
+/// ```
+impl<T: Scalar + fmt::UpperExp> fmt::UpperExp for Length<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match f.precision() {
+            Some(precision) => format!("{:.*E}", precision, self.value),
+            None => format!("{:E}", self.value),
+        };
+        self.format_exp(f, text)
+    }
+}
+
+impl<T: Scalar> Length<T> {
+    /// Appends the unit to the value formatted in scientific notation and pads the text.
+    fn format_exp(&self, f: &mut fmt::Formatter, mut value: String) -> fmt::Result {
+        value.push(' ');
+        if f.alternate() {
+            value.push_str(&self.unit.ascii_symbol());
+        } else {
+            value.push_str(&self.unit.symbol());
+        }
+
+        pad(f, &value)
+    }
+}
+
+/// Writes the text with the width, fill and alignment of the format specifier.
+fn pad(f: &mut fmt::Formatter, text: &str) -> fmt::Result {
+    let width = f.width().unwrap_or(0);
+    let padding = width.saturating_sub(text.chars().count());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(text)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+impl<T: Scalar> PartialEq for Length<T> {
+    fn eq(&self, other: &Length<T>) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+/// Lengths are equal, if they have the same value in meters, regardless of their unit. All lengths
+/// with a NaN value are equal to each other, so every length can be used as a key.
+impl Eq for Length {}
+
+impl Hash for Length {
+    /// Hashes the value in meters, so equal lengths in different units have the same hash.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use length::Length;
+    ///
+    /// let mut heights = HashMap::new();
+    /// heights.insert(Length::new_string("1 km").unwrap(), "hill");
+    ///
+    /// assert_eq!(Some(&"hill"), heights.get(&Length::new_string("1000 m").unwrap()));
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let meters = self.value_in_meters();
+        // 0.0 and -0.0 are equal, like all NaN values, so they need the same hash.
+        let meters = if meters == 0.0 {
+            0.0
+        } else if meters.is_nan() {
+            f64::NAN
+        } else {
+            meters
+        };
+        meters.to_bits().hash(state);
+    }
+}
+
+impl<T: Scalar> PartialOrd for Length<T> {
+    /// Compares the lengths in meters. If the scalar type can not represent a length in meters,
+    /// like a Decimal in quettameters or Planck lengths, the lengths are compared as f64 instead.
+    ///
+    /// Two NaN lengths are equal, so that == is reflexive, but a NaN length is unordered to every
+    /// length, that is not NaN.
+    fn partial_cmp(&self, other: &Length<T>) -> Option<Ordering> {
+        let meter = Unit::Metric(Meter);
+        let ordering = match (
+            self.checked_converted_value(&meter),
+            other.checked_converted_value(&meter),
+        ) {
+            (Some((value, _)), Some((other_value, _))) => value.partial_cmp(&other_value),
+            _ => self
+                .approximate_meters()
+                .partial_cmp(&other.approximate_meters()),
+        };
+
+        ordering.or_else(|| (self.is_nan() && other.is_nan()).then_some(Ordering::Equal))
+    }
+}
+
+impl FromStr for Length {
+    type Err = ParseLengthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Length::parse(s)
+    }
+}
+
+/// Gets a length from a tuple of value and unit, like Length::from_scalar(...).
+///
+/// # Example
+/// ```
+/// use length::{Length, Unit, ImperialUnit::*, MetricUnit::*};
+///
+/// let length = Length::from((2.5, Kilometer));
+/// assert_eq!(2.5, length.value);
+/// assert_eq!(Unit::Metric(Kilometer), length.unit);
+///
+/// let lengths: Vec<Length> = vec![(1.0, Unit::Imperial(Foot)), (2.0, Unit::Metric(Meter))]
+///     .into_iter()
+///     .map(Length::from)
+///     .collect();
+/// assert_eq!(Unit::Imperial(Foot), lengths[0].unit);
+/// ```
+impl<T: Scalar, U: Into<Unit>> From<(T, U)> for Length<T> {
+    fn from((value, unit): (T, U)) -> Self {
+        Length::from_scalar(value, unit)
+    }
+}
+
+/// Gets the value and unit of a length as tuple, so it can be destructured.
+///
+/// # Example
+/// ```
+/// use length::{Length, Unit, MetricUnit::*};
+///
+/// let (value, unit) = Length::new_value_unit(42, Meter).into();
+/// assert_eq!(42.0, value);
+/// assert_eq!(Unit::Metric(Meter), unit);
+/// ```
+impl<T: Scalar> From<Length<T>> for (T, Unit) {
+    fn from(length: Length<T>) -> Self {
+        (length.value, length.unit)
+    }
+}
+
+impl<T: Scalar> From<&Length<T>> for (T, Unit) {
+    fn from(length: &Length<T>) -> Self {
+        (length.value, length.unit)
+    }
+}
+
+impl<T: Scalar> Add for Length<T> {
+    type Output = Length<T>;
+
+    fn add(self, other: Length<T>) -> Length<T> {
+        Length::add(&self, other)
+    }
+}
+
+impl<T: Scalar> AddAssign for Length<T> {
+    fn add_assign(&mut self, other: Length<T>) {
+        self.add_by_ref(other);
+    }
+}
+
+impl<T: Scalar> Sub for Length<T> {
+    type Output = Length<T>;
+
+    fn sub(self, other: Length<T>) -> Length<T> {
+        self.subtract(other)
+    }
+}
+
+impl<T: Scalar> SubAssign for Length<T> {
+    fn sub_assign(&mut self, other: Length<T>) {
+        self.subtract_by_ref(other);
+    }
+}
+
+impl<T: Scalar> Sum for Length<T> {
+    /// Sums the lengths into the unit of the first length, an empty iterator sums up to 0 m.
+    fn sum<I: Iterator<Item = Length<T>>>(iter: I) -> Length<T> {
+        iter.reduce(|total, length| total + length)
+            .unwrap_or_else(|| Length::from_scalar(T::zero(), Meter))
+    }
+}
+
+impl<'a, T: Scalar> Sum<&'a Length<T>> for Length<T> {
+    fn sum<I: Iterator<Item = &'a Length<T>>>(iter: I) -> Length<T> {
+        iter.cloned().sum()
+    }
+}
+
+impl<T: Scalar> Rem for Length<T> {
+    type Output = Length<T>;
+
+    fn rem(self, other: Length<T>) -> Length<T> {
+        Length::rem(&self, other)
+    }
+}
+
+impl<T: Scalar> RemAssign for Length<T> {
+    fn rem_assign(&mut self, other: Length<T>) {
+        self.rem_by_ref(other);
+    }
+}
+
+impl Mul<f64> for Length {
+    type Output = Length;
+
+    fn mul(self, factor: f64) -> Length {
+        self.multiply_by(factor)
+    }
+}
+
+impl Mul<Length> for f64 {
+    type Output = Length;
+
+    fn mul(self, length: Length) -> Length {
+        length.multiply_by(self)
+    }
+}
+
+impl MulAssign<f64> for Length {
+    fn mul_assign(&mut self, factor: f64) {
+        self.multiply_by_ref(factor);
+    }
+}
+
+impl Div<f64> for Length {
+    type Output = Length;
+
+    fn div(self, factor: f64) -> Length {
+        self.divide_by(factor)
+    }
+}
+
+impl DivAssign<f64> for Length {
+    fn div_assign(&mut self, factor: f64) {
+        self.divide_by_ref(factor);
+    }
+}
+
+impl<T: Scalar> Div for Length<T> {
+    type Output = T;
+
+    fn div(self, other: Length<T>) -> T {
+        self.ratio_to(&other)
+    }
+}
+
+impl Neg for Length {
+    type Output = Length;
+
+    fn neg(self) -> Length {
+        self.multiply_by(-1)
+    }
+}
+
+/// The checks of Length::try_new_with(...).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Validation {
+    /// Rejects infinite and NaN values.
+    pub finite: bool,
+    /// Rejects negative values, -0.0 is allowed.
+    pub non_negative: bool,
+}
+
+impl Default for Validation {
+    /// Rejects infinite and NaN values, but allows negative ones.
+    fn default() -> Self {
+        Validation {
+            finite: true,
+            non_negative: false,
+        }
+    }
+}
+
+/// Options for Length::format_into(...) and Length::format_with(...).
+///
+/// The fields can be set directly or with the builder methods, like
+/// DisplayOptions::new().precision(2).names(true).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DisplayOptions {
+    /// The number of decimal places, or None for the shortest representation.
+    pub precision: Option<usize>,
+    /// Writes the unit with Unit::ascii_symbol() instead of its symbol, like "um" for "µm".
+    pub ascii: bool,
+    /// The unit, into which the length is converted before it is formatted, or None for its own
+    /// unit.
+    pub unit: Option<Unit>,
+    /// Writes the English name of the unit instead of its symbol, like "2.5 kilometers".
+    pub names: bool,
+    /// Writes a space between the value and the unit.
+    pub space: bool,
+    /// Removes trailing zeros of the decimal places, that the precision produces, like "2.5"
+    /// instead of "2.50".
+    pub trim_zeros: bool,
+}
+
+impl DisplayOptions {
+    /// Gets the default options: the shortest representation of the value and the unit symbol,
+    /// separated by a space.
+    pub fn new() -> Self {
+        DisplayOptions::default()
+    }
+
+    /// Sets the number of decimal places.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets, if the unit is written with ASCII characters only.
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Sets the unit, into which the length is converted before it is formatted.
+    pub fn unit<U: Into<Unit>>(mut self, unit: U) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Sets, if the English name of the unit is written instead of its symbol.
+    pub fn names(mut self, names: bool) -> Self {
+        self.names = names;
+        self
+    }
+
+    /// Sets, if a space is written between the value and the unit.
+    pub fn space(mut self, space: bool) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Sets, if trailing zeros of the decimal places are removed.
+    pub fn trim_zeros(mut self, trim_zeros: bool) -> Self {
+        self.trim_zeros = trim_zeros;
+        self
+    }
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        DisplayOptions {
+            precision: None,
+            ascii: false,
+            unit: None,
+            names: false,
+            space: true,
+            trim_zeros: false,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub enum Unit {
+    Astronomic(AstronomicUnit),
+    Custom(CustomUnit),
+    #[cfg(feature = "historical")]
+    Historical(HistoricalUnit),
+    Imperial(ImperialUnit),
+    Metric(MetricUnit),
+    Subatomic(SubatomicUnit),
+    Traditional(TraditionalUnit),
+    Typographic(TypographicUnit),
+    UsSurvey(UsSurveyUnit),
+}
+
+impl Unit {
+    /// Gets the amount of meters in one of this unit.
+    ///
+    /// Every conversion is done with these factors, as value * from.meters_per_unit() / to.meters_per_unit().
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Unit, ImperialUnit::*};
+    ///
+    /// assert_eq!(0.3048, Unit::Imperial(Foot).meters_per_unit());
+    /// ```
+    pub fn meters_per_unit(&self) -> f64 {
+        match (self, self.index()) {
+            (Unit::Custom(custom_unit), _) => custom_unit.meters(),
+            (_, Some(index)) => METERS_PER_UNIT[index],
+            (_, None) => unreachable!("every built-in unit has an index"),
+        }
+    }
+
+    /// Gets the dense index of a built-in unit in 0..UNIT_COUNT, which the factor tables are
+    /// indexed by. Custom units have no index.
+    const fn index(&self) -> Option<usize> {
+        match self {
+            Unit::Astronomic(unit) => Some(*unit as usize),
+            Unit::Custom(_) => None,
+            Unit::Imperial(unit) => Some(IMPERIAL_INDEX_OFFSET + *unit as usize),
+            Unit::Metric(unit) => Some(METRIC_INDEX_OFFSET + *unit as usize),
+            Unit::Subatomic(unit) => Some(SUBATOMIC_INDEX_OFFSET + *unit as usize),
+            Unit::Traditional(unit) => Some(TRADITIONAL_INDEX_OFFSET + *unit as usize),
+            #[cfg(feature = "historical")]
+            Unit::Historical(unit) => Some(HISTORICAL_INDEX_OFFSET + *unit as usize),
+            Unit::Typographic(unit) => Some(TYPOGRAPHIC_INDEX_OFFSET + *unit as usize),
+            Unit::UsSurvey(unit) => Some(US_SURVEY_INDEX_OFFSET + *unit as usize),
+        }
+    }
+
+    /// This method is mainly intended for internal use only.
+    #[deprecated(since = "0.3.0", note = "use meters_per_unit() instead")]
+    pub fn factor(&self) -> f64 {
+        self.meters_per_unit()
+    }
+
+    /// This method is mainly intended for internal use only.
+    pub fn is_astronomic(&self) -> bool {
+        matches!(self, Unit::Astronomic(_))
+    }
+
+    /// This method is mainly intended for internal use only.
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Unit::Custom(_))
+    }
+
+    /// This method is mainly intended for internal use only.
+    #[cfg(feature = "historical")]
+    pub fn is_historical(&self) -> bool {
+        matches!(self, Unit::Historical(_))
+    }
+
+    /// Checks, if a custom unit with the same symbol or name takes precedence over this unit when
+    /// parsing. This applies to the historical units, so enabling the feature `historical` does
+    /// not change the meaning of registered custom units.
+    pub(crate) fn yields_to_custom_units(&self) -> bool {
+        #[cfg(feature = "historical")]
+        {
+            self.is_historical()
+        }
+        #[cfg(not(feature = "historical"))]
+        {
+            false
+        }
+    }
+
+    /// This method is mainly intended for internal use only.
+    pub fn is_imperial(&self) -> bool {
+        matches!(self, Unit::Imperial(_))
+    }
+
+    /// This method is mainly intended for internal use only.
+    pub fn is_metric(&self) -> bool {
+        matches!(self, Unit::Metric(_))
+    }
+
+    /// This method is mainly intended for internal use only.
+    pub fn is_subatomic(&self) -> bool {
+        matches!(self, Unit::Subatomic(_))
+    }
+
+    /// This method is mainly intended for internal use only.
+    pub fn is_traditional(&self) -> bool {
+        matches!(self, Unit::Traditional(_))
+    }
+
+    /// This method is mainly intended for internal use only.
+    pub fn is_typographic(&self) -> bool {
+        matches!(self, Unit::Typographic(_))
+    }
+
+    /// This method is mainly intended for internal use only.
+    pub fn is_us_survey(&self) -> bool {
+        matches!(self, Unit::UsSurvey(_))
+    }
+
+    /// This method is mainly intended for internal use only.
+    pub fn system(&self) -> UnitSystem {
+        match self {
+            Unit::Astronomic(_) => UnitSystem::Astronomic,
+            Unit::Custom(_) => UnitSystem::Custom,
+            #[cfg(feature = "historical")]
+            Unit::Historical(_) => UnitSystem::Historical,
+            Unit::Imperial(_) => UnitSystem::Imperial,
+            Unit::Metric(_) => UnitSystem::Metric,
+            Unit::Subatomic(_) => UnitSystem::Subatomic,
+            Unit::Traditional(
+                TraditionalUnit::Cun
+                | TraditionalUnit::Chi
+                | TraditionalUnit::Zhang
+                | TraditionalUnit::Li,
+            ) => UnitSystem::Chinese,
+            Unit::Traditional(
+                TraditionalUnit::Shaku | TraditionalUnit::Ken | TraditionalUnit::Ri,
+            ) => UnitSystem::Japanese,
+            Unit::Traditional(TraditionalUnit::Arshin | TraditionalUnit::Verst) => {
+                UnitSystem::Russian
+            }
+            Unit::Traditional(TraditionalUnit::Mil) => UnitSystem::Scandinavian,
+            Unit::Typographic(_) => UnitSystem::Typographic,
+            Unit::UsSurvey(_) => UnitSystem::UsSurvey,
+        }
+    }
+
+    /// Gets all built-in and registered custom units in ascending order of their size.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Unit, MetricUnit::*, SubatomicUnit::*};
+    ///
+    /// let units: Vec<Unit> = Unit::all().collect();
+    ///
+    /// assert_eq!(Some(&Unit::Subatomic(PlanckLength)), units.first());
+    /// assert!(units.contains(&Unit::Metric(Kilometer)));
+    /// ```
+    pub fn all() -> impl Iterator<Item = Unit> {
+        let mut units: Vec<Unit> = UNIT_CODES
+            .iter()
+            .map(|(_, unit)| *unit)
+            .chain(CustomUnit::registered().into_iter().map(Unit::Custom))
+            .collect();
+        units.sort();
+
+        units.into_iter()
+    }
+
+    /// Gets the most readable unit of the unit system for the given amount of meters, that is the
+    /// greatest commonly used unit, in which the value is at least 1.
+    ///
+    /// Rarely used units like the rod or the light-hour are skipped, for UnitSystem::Custom the
+    /// metric units are used.
     ///
     /// # Example
     /// ```
-    /// use length::{Length, Unit, MetricUnit::*};
-    ///
-    /// let mut five_kilometer = Length::new_string("5km").unwrap();
-    /// let twothousand_meter = Length::new_string("2000m").unwrap();
-    /// five_kilometer.add_by_ref(twothousand_meter);
+    /// use length::{Unit, UnitSystem, ImperialUnit::*, MetricUnit::*};
     ///
-    /// assert_eq!(7.0, five_kilometer.value);
-    /// assert_eq!(Unit::Metric(Kilometer), five_kilometer.unit);
+    /// assert_eq!(Unit::Metric(Kilometer), Unit::best_for(3_200.0, UnitSystem::Metric));
+    /// assert_eq!(Unit::Imperial(Inch), Unit::best_for(0.18, UnitSystem::Imperial));
+    /// assert_eq!(Unit::Imperial(Foot), Unit::best_for(100.0, UnitSystem::Imperial));
     /// ```
-    pub fn add_by_ref(&mut self, length: Length) -> &mut Self {
-        let length_with_source_unit = length.to(self.unit);
-        self.value += length_with_source_unit.value;
-        self
+    pub fn best_for(value_in_meters: f64, system: UnitSystem) -> Unit {
+        let units = system.readable_units();
+        let value_in_meters = value_in_meters.abs();
+        if value_in_meters == 0.0 || !value_in_meters.is_finite() {
+            return system.base_unit().unwrap_or(Unit::Metric(Meter));
+        }
+
+        units
+            .iter()
+            .rev()
+            .find(|unit| value_in_meters / unit.meters_per_unit() >= 1.0)
+            .copied()
+            .unwrap_or(units[0])
     }
 
-    /// Subtracts the length and returns a new Length-struct.
+    /// Gets the stable numeric code of this unit.
+    ///
+    /// The codes are independent of the order of the enum variants and are never reused,
+    /// so they can be used to store units compactly in databases or to pass them through FFI layers.
+    /// Custom units get the codes from 1000 to 65535 in the order of their registration, these are
+    /// only stable within one process.
     ///
     /// # Example
     /// ```
-    /// use length::{Length, Unit, MetricUnit::*};
-    ///
-    /// let five_kilometer = Length::new_string("5km").unwrap();
-    /// let twothousand_meter = Length::new_string("2000m").unwrap();
-    /// let three_kilometer = five_kilometer.subtract(twothousand_meter);
+    /// use length::{Unit, MetricUnit::*};
     ///
-    /// assert_eq!(3.0, three_kilometer.value);
-    /// assert_eq!(Unit::Metric(Kilometer), three_kilometer.unit);
+    /// assert_eq!(112, Unit::Metric(Meter).code());
     /// ```
-    pub fn subtract(&self, length: Length) -> Self {
-        let length_with_source_unit = length.to(self.unit);
-        Length {
-            value: self.value - length_with_source_unit.value,
-            unit: self.unit,
-            ..Default::default()
+    pub fn code(&self) -> u16 {
+        if let Unit::Custom(custom_unit) = self {
+            return custom_unit.code();
         }
+
+        UNIT_CODES
+            .iter()
+            .find(|(_, unit)| unit == self)
+            .map(|(code, _)| *code)
+            .expect("every unit has a code")
     }
 
-    /// Subtracts the length.
+    /// Gets the unit for a numeric code, that was returned by code().
     ///
     /// # Example
     /// ```
-    /// use length::{Length, Unit, MetricUnit::*};
-    ///
-    /// let mut five_kilometer = Length::new_string("5km").unwrap();
-    /// let twothousand_meter = Length::new_string("2000m").unwrap();
-    /// five_kilometer.subtract_by_ref(twothousand_meter);
+    /// use length::{Unit, ImperialUnit::*};
     ///
-    /// assert_eq!(3.0, five_kilometer.value);
-    /// assert_eq!(Unit::Metric(Kilometer), five_kilometer.unit);
+    /// assert_eq!(Some(Unit::Imperial(Mile)), Unit::try_from_code(203));
+    /// assert_eq!(None, Unit::try_from_code(0));
     /// ```
-    pub fn subtract_by_ref(&mut self, length: Length) -> &mut Self {
-        let length_with_source_unit = length.to(self.unit);
-        self.value -= length_with_source_unit.value;
-        self
+    pub fn try_from_code(code: u16) -> Option<Unit> {
+        if code >= Length::CUSTOM_UNIT_CODE_OFFSET {
+            return CustomUnit::from_id((code - Length::CUSTOM_UNIT_CODE_OFFSET) as u32)
+                .map(Unit::Custom);
+        }
+
+        UNIT_CODES
+            .iter()
+            .find(|(unit_code, _)| *unit_code == code)
+            .map(|(_, unit)| *unit)
     }
 
-    /// Multiplies the length and returns a new Length-struct.
+    /// Gets the symbol of this unit, like "km", which is also its Display representation.
     ///
     /// # Example
     /// ```
-    /// use length::{Length, Unit, MetricUnit::*};
+    /// use length::{Unit, ImperialUnit::*, MetricUnit::*};
     ///
-    /// let five_kilometer = Length::new_string("5km").unwrap();
-    /// let fifty_kilometer = five_kilometer.multiply_by(10);
-    ///
-    /// assert_eq!(50.0, fifty_kilometer.value);
-    /// assert_eq!(Unit::Metric(Kilometer), fifty_kilometer.unit);
+    /// assert_eq!("km", Unit::Metric(Kilometer).symbol());
+    /// assert_eq!("ft", Unit::Imperial(Foot).symbol());
     /// ```
-    pub fn multiply_by<T: Into<f64>>(&self, factor: T) -> Self {
-        let real_factor: f64 = factor.into();
-        Length {
-            value: self.value * real_factor,
-            unit: self.unit,
-            ..Default::default()
-        }
+    pub fn symbol(&self) -> String {
+        self.to_string()
     }
 
-    /// Multiplies the length by a factor.
+    /// Gets the symbol of this unit with ASCII characters only, for terminals and file formats,
+    /// that can not handle other characters, like "um" for "µm". Custom units keep their symbol.
     ///
     /// # Example
     /// ```
-    /// use length::{Length, Unit, MetricUnit::*};
-    ///
-    /// let mut five_kilometer = Length::new_string("5km").unwrap();
-    /// five_kilometer.multiply_by_ref(10);
+    /// use length::{Unit, AstronomicUnit::*, MetricUnit::*};
     ///
-    /// assert_eq!(50.0, five_kilometer.value);
-    /// assert_eq!(Unit::Metric(Kilometer), five_kilometer.unit);
+    /// assert_eq!("um", Unit::Metric(Micrometer).ascii_symbol());
+    /// assert_eq!("Rsun", Unit::Astronomic(SolarRadius).ascii_symbol());
+    /// assert_eq!("km", Unit::Metric(Kilometer).ascii_symbol());
     /// ```
-    pub fn multiply_by_ref<T: Into<f64>>(&mut self, factor: T) -> &mut Self {
-        let real_factor: f64 = factor.into();
-        self.value *= real_factor;
-        self
+    pub fn ascii_symbol(&self) -> String {
+        match self.ascii_replacement() {
+            Some(symbol) => String::from(symbol),
+            None => self.symbol(),
+        }
     }
 
-    /// Divides the length and returns a new Length-struct.
+    /// Gets the ASCII symbol of this unit, or None, if its symbol has ASCII characters only.
+    fn ascii_replacement(&self) -> Option<&'static str> {
+        match self {
+            Unit::Astronomic(EarthRadius) => Some("Rearth"),
+            Unit::Astronomic(SolarRadius) => Some("Rsun"),
+            Unit::Metric(Angstrom) => Some("angstrom"),
+            Unit::Metric(Micrometer) => Some("um"),
+            _ => None,
+        }
+    }
+
+    /// Gets the English name of this unit in singular, like "kilometer". Custom units are named
+    /// by their symbol.
     ///
     /// # Example
     /// ```
-    /// use length::{Length, Unit, MetricUnit::*};
-    ///
-    /// let five_kilometer = Length::new_string("5km").unwrap();
-    /// let one_kilometer = five_kilometer.divide_by(5);
+    /// use length::{Unit, AstronomicUnit::*, MetricUnit::*};
     ///
-    /// assert_eq!(1.0, one_kilometer.value);
-    /// assert_eq!(Unit::Metric(Kilometer), one_kilometer.unit);
+    /// assert_eq!("kilometer", Unit::Metric(Kilometer).name());
+    /// assert_eq!("light-year", Unit::Astronomic(Lightyear).name());
     /// ```
-    pub fn divide_by<T: Into<f64>>(&self, factor: T) -> Self {
-        let real_factor: f64 = factor.into();
-        Length {
-            value: self.value / real_factor,
-            unit: self.unit,
-            ..Default::default()
+    pub fn name(&self) -> String {
+        match self.names() {
+            Some((singular, _)) => String::from(singular),
+            None => self.symbol(),
         }
     }
 
-    /// Divides the length by a factor.
+    /// Gets the English name of this unit in plural, like "kilometers". Custom units are named
+    /// by their symbol.
     ///
     /// # Example
     /// ```
-    /// use length::{Length, Unit, MetricUnit::*};
+    /// use length::{Unit, ImperialUnit::*, MetricUnit::*};
     ///
-    /// let mut five_kilometer = Length::new_string("5km").unwrap();
-    /// five_kilometer.divide_by_ref(5);
-    ///
-    /// assert_eq!(1.0, five_kilometer.value);
-    /// assert_eq!(Unit::Metric(Kilometer), five_kilometer.unit);
+    /// assert_eq!("kilometers", Unit::Metric(Kilometer).plural_name());
+    /// assert_eq!("feet", Unit::Imperial(Foot).plural_name());
     /// ```
-    pub fn divide_by_ref<T: Into<f64>>(&mut self, factor: T) -> &mut Self {
-        let real_factor: f64 = factor.into();
-        self.value /= real_factor;
-        self
+    pub fn plural_name(&self) -> String {
+        match self.names() {
+            Some((_, plural)) => String::from(plural),
+            None => self.symbol(),
+        }
     }
-}
 
-impl Default for Length {
-    fn default() -> Length {
-        Length::new()
+    /// Gets the singular and plural name of this unit, or None for a custom unit.
+    fn names(&self) -> Option<(&'static str, &'static str)> {
+        UNIT_NAMES
+            .iter()
+            .find(|(unit, _, _)| unit == self)
+            .map(|(_, singular, plural)| (*singular, *plural))
     }
-}
 
-impl ToString for Length {
-    fn to_string(&self) -> String {
-        format!("{} {}", self.value, self.unit.to_string())
+    /// Parses a unit like FromStr, but with case-insensitive symbols, if lenient is set. See
+    /// Length::new_string_lenient(...) for the precedence of ambiguous symbols.
+    pub(crate) fn from_str_with(s: &str, lenient: bool) -> Result<Unit, &'static str> {
+        match s.parse::<Unit>() {
+            Ok(unit) => Ok(unit),
+            Err(error) if !lenient => Err(error),
+            Err(error) => {
+                let lowercase = s.to_lowercase();
+                let candidates = UNIT_SYMBOLS
+                    .iter()
+                    .filter(|(symbol, _)| symbol.to_lowercase() == lowercase);
+
+                candidates
+                    .clone()
+                    .find(|(symbol, _)| *symbol == lowercase)
+                    .or_else(|| candidates.min_by_key(|(_, unit)| *unit))
+                    .map(|(_, unit)| *unit)
+                    .ok_or(error)
+            }
+        }
     }
-}
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum Unit {
-    Astronomic(AstronomicUnit),
-    Imperial(ImperialUnit),
-    Metric(MetricUnit),
+    /// Gets the unit with the given symbol, like "km" or "ft". It can be evaluated in const contexts.
+    pub(crate) const fn from_symbol(symbol: &str) -> Option<Unit> {
+        let mut index = 0;
+        while index < UNIT_SYMBOLS.len() {
+            let (unit_symbol, unit) = UNIT_SYMBOLS[index];
+            if bytes_eq(unit_symbol.as_bytes(), symbol.as_bytes()) {
+                return Some(unit);
+            }
+            index += 1;
+        }
+
+        None
+    }
+
+    /// Gets the unit with the given singular or plural name, like "kilometers" or "light years".
+    ///
+    /// The comparison ignores the case, whitespace and hyphens and accepts "metre" for "meter".
+    fn from_name(name: &str) -> Option<Unit> {
+        let key = Unit::name_key(name);
+
+        UNIT_NAMES
+            .iter()
+            .find(|(_, singular, plural)| {
+                Unit::name_key(singular) == key || Unit::name_key(plural) == key
+            })
+            .map(|(unit, _, _)| *unit)
+    }
+
+    fn name_key(name: &str) -> String {
+        name.to_lowercase()
+            .replace("metre", "meter")
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect()
+    }
 }
 
-impl Unit {
-    /// This method is mainly intended for internal use only.
-    pub fn factor(&self) -> f64 {
-        match self {
-            Unit::Astronomic(system) => system.factor(),
-            Unit::Metric(system) => system.factor(),
-            Unit::Imperial(system) => system.factor(),
+/// The stable numeric codes of all units.
+///
+/// A code is never changed or reused, new units only get new codes.
+const UNIT_CODES: &[(u16, Unit)] = &[
+    (100, Unit::Metric(MetricUnit::Quectometer)),
+    (101, Unit::Metric(MetricUnit::Rontometer)),
+    (102, Unit::Metric(MetricUnit::Yoctometer)),
+    (103, Unit::Metric(MetricUnit::Zeptometer)),
+    (104, Unit::Metric(MetricUnit::Attometer)),
+    (105, Unit::Metric(MetricUnit::Femtometer)),
+    (106, Unit::Metric(MetricUnit::Picometer)),
+    (107, Unit::Metric(MetricUnit::Nanometer)),
+    (108, Unit::Metric(MetricUnit::Micrometer)),
+    (109, Unit::Metric(MetricUnit::Millimeter)),
+    (110, Unit::Metric(MetricUnit::Centimeter)),
+    (111, Unit::Metric(MetricUnit::Decimeter)),
+    (112, Unit::Metric(MetricUnit::Meter)),
+    (113, Unit::Metric(MetricUnit::Decameter)),
+    (114, Unit::Metric(MetricUnit::Hectometer)),
+    (115, Unit::Metric(MetricUnit::Kilometer)),
+    (116, Unit::Metric(MetricUnit::Megameter)),
+    (117, Unit::Metric(MetricUnit::Gigameter)),
+    (118, Unit::Metric(MetricUnit::Terameter)),
+    (119, Unit::Metric(MetricUnit::Petameter)),
+    (120, Unit::Metric(MetricUnit::Exameter)),
+    (121, Unit::Metric(MetricUnit::Zettameter)),
+    (122, Unit::Metric(MetricUnit::Yottameter)),
+    (123, Unit::Metric(MetricUnit::Ronnameter)),
+    (124, Unit::Metric(MetricUnit::Quettameter)),
+    (125, Unit::Metric(MetricUnit::Angstrom)),
+    (200, Unit::Imperial(ImperialUnit::Inch)),
+    (201, Unit::Imperial(ImperialUnit::Foot)),
+    (202, Unit::Imperial(ImperialUnit::Yard)),
+    (203, Unit::Imperial(ImperialUnit::Mile)),
+    (204, Unit::Imperial(ImperialUnit::Thou)),
+    (205, Unit::Imperial(ImperialUnit::Hand)),
+    (206, Unit::Imperial(ImperialUnit::Rod)),
+    (207, Unit::Imperial(ImperialUnit::Chain)),
+    (208, Unit::Imperial(ImperialUnit::Furlong)),
+    (209, Unit::Imperial(ImperialUnit::League)),
+    (300, Unit::Astronomic(AstronomicUnit::AstronomicalUnit)),
+    (301, Unit::Astronomic(AstronomicUnit::Lightsecond)),
+    (302, Unit::Astronomic(AstronomicUnit::Lightminute)),
+    (303, Unit::Astronomic(AstronomicUnit::Lighthour)),
+    (304, Unit::Astronomic(AstronomicUnit::Lightday)),
+    (305, Unit::Astronomic(AstronomicUnit::Lightyear)),
+    (306, Unit::Astronomic(AstronomicUnit::Parsec)),
+    (307, Unit::Astronomic(AstronomicUnit::Kiloparsec)),
+    (308, Unit::Astronomic(AstronomicUnit::Megaparsec)),
+    (309, Unit::Astronomic(AstronomicUnit::Gigaparsec)),
+    (310, Unit::Astronomic(AstronomicUnit::SolarRadius)),
+    (311, Unit::Astronomic(AstronomicUnit::EarthRadius)),
+    (312, Unit::Astronomic(AstronomicUnit::LunarDistance)),
+    (400, Unit::Typographic(TypographicUnit::Point)),
+    (401, Unit::Typographic(TypographicUnit::DidotPoint)),
+    (402, Unit::Typographic(TypographicUnit::Cicero)),
+    (403, Unit::Typographic(TypographicUnit::ScaledPoint)),
+    (404, Unit::Typographic(TypographicUnit::Pica)),
+    (405, Unit::Typographic(TypographicUnit::Pixel)),
+    (500, Unit::UsSurvey(UsSurveyUnit::SurveyFoot)),
+    (501, Unit::UsSurvey(UsSurveyUnit::SurveyChain)),
+    (502, Unit::UsSurvey(UsSurveyUnit::SurveyMile)),
+    (600, Unit::Subatomic(SubatomicUnit::PlanckLength)),
+    (601, Unit::Subatomic(SubatomicUnit::BohrRadius)),
+    (700, Unit::Traditional(TraditionalUnit::Cun)),
+    (701, Unit::Traditional(TraditionalUnit::Chi)),
+    (702, Unit::Traditional(TraditionalUnit::Zhang)),
+    (703, Unit::Traditional(TraditionalUnit::Li)),
+    (704, Unit::Traditional(TraditionalUnit::Shaku)),
+    (705, Unit::Traditional(TraditionalUnit::Ken)),
+    (706, Unit::Traditional(TraditionalUnit::Ri)),
+    (707, Unit::Traditional(TraditionalUnit::Arshin)),
+    (708, Unit::Traditional(TraditionalUnit::Verst)),
+    (709, Unit::Traditional(TraditionalUnit::Mil)),
+    #[cfg(feature = "historical")]
+    (800, Unit::Historical(HistoricalUnit::Cubit)),
+    #[cfg(feature = "historical")]
+    (801, Unit::Historical(HistoricalUnit::Smoot)),
+    #[cfg(feature = "historical")]
+    (802, Unit::Historical(HistoricalUnit::Fathom)),
+    #[cfg(feature = "historical")]
+    (803, Unit::Historical(HistoricalUnit::FootballField)),
+];
+
+/// The number of built-in units, see Unit::index().
+const UNIT_COUNT: usize = UNIT_CODES.len();
+
+const IMPERIAL_INDEX_OFFSET: usize = AstronomicUnit::Gigaparsec as usize + 1;
+const METRIC_INDEX_OFFSET: usize = IMPERIAL_INDEX_OFFSET + ImperialUnit::League as usize + 1;
+const SUBATOMIC_INDEX_OFFSET: usize = METRIC_INDEX_OFFSET + MetricUnit::Quettameter as usize + 1;
+const TYPOGRAPHIC_INDEX_OFFSET: usize =
+    SUBATOMIC_INDEX_OFFSET + SubatomicUnit::BohrRadius as usize + 1;
+const US_SURVEY_INDEX_OFFSET: usize =
+    TYPOGRAPHIC_INDEX_OFFSET + TypographicUnit::Cicero as usize + 1;
+const TRADITIONAL_INDEX_OFFSET: usize =
+    US_SURVEY_INDEX_OFFSET + UsSurveyUnit::SurveyMile as usize + 1;
+#[cfg(feature = "historical")]
+const HISTORICAL_INDEX_OFFSET: usize = TRADITIONAL_INDEX_OFFSET + TraditionalUnit::Mil as usize + 1;
+
+#[cfg(not(feature = "historical"))]
+const _: () = assert!(
+    TRADITIONAL_INDEX_OFFSET + TraditionalUnit::Mil as usize + 1 == UNIT_COUNT,
+    "every built-in unit needs a code and a dense index"
+);
+#[cfg(feature = "historical")]
+const _: () = assert!(
+    HISTORICAL_INDEX_OFFSET + HistoricalUnit::FootballField as usize + 1 == UNIT_COUNT,
+    "every built-in unit needs a code and a dense index"
+);
+
+/// The amount of meters in one of each built-in unit, indexed by Unit::index().
+const METERS_PER_UNIT: [f64; UNIT_COUNT] = {
+    let mut table = [f64::NAN; UNIT_COUNT];
+    let mut i = 0;
+    while i < UNIT_COUNT {
+        let unit = UNIT_CODES[i].1;
+        if let Some(index) = unit.index() {
+            table[index] = match unit {
+                Unit::Astronomic(unit) => unit.meters_per_unit(),
+                Unit::Custom(_) => f64::NAN,
+                #[cfg(feature = "historical")]
+                Unit::Historical(unit) => unit.meters_per_unit(),
+                Unit::Imperial(unit) => unit.meters_per_unit(),
+                Unit::Metric(unit) => unit.meters_per_unit(),
+                Unit::Subatomic(unit) => unit.meters_per_unit(),
+                Unit::Traditional(unit) => unit.meters_per_unit(),
+                Unit::Typographic(unit) => unit.meters_per_unit(),
+                Unit::UsSurvey(unit) => unit.meters_per_unit(),
+            };
         }
+        i += 1;
     }
+    table
+};
 
-    /// This method is mainly intended for internal use only.
-    pub fn is_astronomic(&self) -> bool {
-        match self {
-            Unit::Astronomic(_) => true,
-            _ => false,
+/// The reduced exact ratios from each built-in unit (first index) into each other one (second
+/// index), computed at compile time, so a conversion needs no gcd at runtime. None where a unit
+/// has no rational factor or where the ratio does not fit into an i128.
+static EXACT_RATIOS: [[Option<(i128, i128)>; UNIT_COUNT]; UNIT_COUNT] = {
+    let mut exact_factors = [None; UNIT_COUNT];
+    let mut i = 0;
+    while i < UNIT_COUNT {
+        let unit = UNIT_CODES[i].1;
+        if let Some(index) = unit.index() {
+            exact_factors[index] = match unit {
+                Unit::Astronomic(unit) => unit.exact_meters_per_unit(),
+                Unit::Custom(_) => None,
+                #[cfg(feature = "historical")]
+                Unit::Historical(unit) => unit.exact_meters_per_unit(),
+                Unit::Imperial(unit) => unit.exact_meters_per_unit(),
+                Unit::Metric(unit) => unit.exact_meters_per_unit(),
+                Unit::Subatomic(unit) => unit.exact_meters_per_unit(),
+                Unit::Traditional(unit) => unit.exact_meters_per_unit(),
+                Unit::Typographic(unit) => unit.exact_meters_per_unit(),
+                Unit::UsSurvey(unit) => unit.exact_meters_per_unit(),
+            };
         }
+        i += 1;
     }
 
-    /// This method is mainly intended for internal use only.
-    pub fn is_imperial(&self) -> bool {
-        match self {
-            Unit::Imperial(_) => true,
-            _ => false,
+    let mut table = [[None; UNIT_COUNT]; UNIT_COUNT];
+    let mut from = 0;
+    while from < UNIT_COUNT {
+        let mut to = 0;
+        while to < UNIT_COUNT {
+            if let (Some(from_factor), Some(to_factor)) = (exact_factors[from], exact_factors[to]) {
+                table[from][to] = reduced_ratio(from_factor, to_factor);
+            }
+            to += 1;
         }
+        from += 1;
     }
+    table
+};
 
-    /// This method is mainly intended for internal use only.
-    pub fn is_metric(&self) -> bool {
-        match self {
-            Unit::Metric(_) => true,
-            _ => false,
+/// Gets the reduced ratio between two exact factors, if it fits into an i128.
+const fn reduced_ratio(
+    (from_numerator, from_denominator): (i128, i128),
+    (to_numerator, to_denominator): (i128, i128),
+) -> Option<(i128, i128)> {
+    let numerator_gcd = gcd(from_numerator, to_numerator);
+    let denominator_gcd = gcd(from_denominator, to_denominator);
+    let numerator = (from_numerator / numerator_gcd).checked_mul(to_denominator / denominator_gcd);
+    let denominator =
+        (from_denominator / denominator_gcd).checked_mul(to_numerator / numerator_gcd);
+    match (numerator, denominator) {
+        (Some(numerator), Some(denominator)) => {
+            let gcd = gcd(numerator, denominator);
+            Some((numerator / gcd, denominator / gcd))
         }
+        _ => None,
     }
+}
 
-    /// This method is mainly intended for internal use only.
-    pub fn system(&self) -> UnitSystem {
-        match self {
-            Unit::Astronomic(_) => UnitSystem::Astronomic,
-            Unit::Imperial(_) => UnitSystem::Imperial,
-            Unit::Metric(_) => UnitSystem::Metric,
+const fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The metric units with an exponent, that is a multiple of 3, in ascending order, as used by
+/// Length::format_engineering().
+const ENGINEERING_UNITS: [MetricUnit; 21] = [
+    MetricUnit::Quectometer,
+    MetricUnit::Rontometer,
+    MetricUnit::Yoctometer,
+    MetricUnit::Zeptometer,
+    MetricUnit::Attometer,
+    MetricUnit::Femtometer,
+    MetricUnit::Picometer,
+    MetricUnit::Nanometer,
+    MetricUnit::Micrometer,
+    MetricUnit::Millimeter,
+    MetricUnit::Meter,
+    MetricUnit::Kilometer,
+    MetricUnit::Megameter,
+    MetricUnit::Gigameter,
+    MetricUnit::Terameter,
+    MetricUnit::Petameter,
+    MetricUnit::Exameter,
+    MetricUnit::Zettameter,
+    MetricUnit::Yottameter,
+    MetricUnit::Ronnameter,
+    MetricUnit::Quettameter,
+];
+
+/// The English names of all units in singular and plural, as used for parsing "5 kilometers".
+const UNIT_NAMES: &[(Unit, &str, &str)] = &[
+    (
+        Unit::Metric(MetricUnit::Quectometer),
+        "quectometer",
+        "quectometers",
+    ),
+    (
+        Unit::Metric(MetricUnit::Rontometer),
+        "rontometer",
+        "rontometers",
+    ),
+    (
+        Unit::Metric(MetricUnit::Yoctometer),
+        "yoctometer",
+        "yoctometers",
+    ),
+    (
+        Unit::Metric(MetricUnit::Zeptometer),
+        "zeptometer",
+        "zeptometers",
+    ),
+    (
+        Unit::Metric(MetricUnit::Attometer),
+        "attometer",
+        "attometers",
+    ),
+    (
+        Unit::Metric(MetricUnit::Femtometer),
+        "femtometer",
+        "femtometers",
+    ),
+    (
+        Unit::Metric(MetricUnit::Picometer),
+        "picometer",
+        "picometers",
+    ),
+    (Unit::Metric(MetricUnit::Angstrom), "angstrom", "angstroms"),
+    (
+        Unit::Metric(MetricUnit::Nanometer),
+        "nanometer",
+        "nanometers",
+    ),
+    (
+        Unit::Metric(MetricUnit::Micrometer),
+        "micrometer",
+        "micrometers",
+    ),
+    (
+        Unit::Metric(MetricUnit::Millimeter),
+        "millimeter",
+        "millimeters",
+    ),
+    (
+        Unit::Metric(MetricUnit::Centimeter),
+        "centimeter",
+        "centimeters",
+    ),
+    (
+        Unit::Metric(MetricUnit::Decimeter),
+        "decimeter",
+        "decimeters",
+    ),
+    (Unit::Metric(MetricUnit::Meter), "meter", "meters"),
+    (
+        Unit::Metric(MetricUnit::Decameter),
+        "decameter",
+        "decameters",
+    ),
+    (
+        Unit::Metric(MetricUnit::Hectometer),
+        "hectometer",
+        "hectometers",
+    ),
+    (
+        Unit::Metric(MetricUnit::Kilometer),
+        "kilometer",
+        "kilometers",
+    ),
+    (
+        Unit::Metric(MetricUnit::Megameter),
+        "megameter",
+        "megameters",
+    ),
+    (
+        Unit::Metric(MetricUnit::Gigameter),
+        "gigameter",
+        "gigameters",
+    ),
+    (
+        Unit::Metric(MetricUnit::Terameter),
+        "terameter",
+        "terameters",
+    ),
+    (
+        Unit::Metric(MetricUnit::Petameter),
+        "petameter",
+        "petameters",
+    ),
+    (Unit::Metric(MetricUnit::Exameter), "exameter", "exameters"),
+    (
+        Unit::Metric(MetricUnit::Zettameter),
+        "zettameter",
+        "zettameters",
+    ),
+    (
+        Unit::Metric(MetricUnit::Yottameter),
+        "yottameter",
+        "yottameters",
+    ),
+    (
+        Unit::Metric(MetricUnit::Ronnameter),
+        "ronnameter",
+        "ronnameters",
+    ),
+    (
+        Unit::Metric(MetricUnit::Quettameter),
+        "quettameter",
+        "quettameters",
+    ),
+    (Unit::Imperial(ImperialUnit::Thou), "thou", "thou"),
+    (Unit::Imperial(ImperialUnit::Inch), "inch", "inches"),
+    (Unit::Imperial(ImperialUnit::Hand), "hand", "hands"),
+    (Unit::Imperial(ImperialUnit::Foot), "foot", "feet"),
+    (Unit::Imperial(ImperialUnit::Yard), "yard", "yards"),
+    (Unit::Imperial(ImperialUnit::Rod), "rod", "rods"),
+    (Unit::Imperial(ImperialUnit::Chain), "chain", "chains"),
+    (Unit::Imperial(ImperialUnit::Furlong), "furlong", "furlongs"),
+    (Unit::Imperial(ImperialUnit::Mile), "mile", "miles"),
+    (Unit::Imperial(ImperialUnit::League), "league", "leagues"),
+    (
+        Unit::Astronomic(AstronomicUnit::AstronomicalUnit),
+        "astronomical unit",
+        "astronomical units",
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Lightsecond),
+        "light-second",
+        "light-seconds",
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Lightminute),
+        "light-minute",
+        "light-minutes",
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Lighthour),
+        "light-hour",
+        "light-hours",
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Lightday),
+        "light-day",
+        "light-days",
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Lightyear),
+        "light-year",
+        "light-years",
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Parsec),
+        "parsec",
+        "parsecs",
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Kiloparsec),
+        "kiloparsec",
+        "kiloparsecs",
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Megaparsec),
+        "megaparsec",
+        "megaparsecs",
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Gigaparsec),
+        "gigaparsec",
+        "gigaparsecs",
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::SolarRadius),
+        "solar radius",
+        "solar radii",
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::EarthRadius),
+        "Earth radius",
+        "Earth radii",
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::LunarDistance),
+        "lunar distance",
+        "lunar distances",
+    ),
+    (Unit::Typographic(TypographicUnit::Point), "point", "points"),
+    (
+        Unit::Typographic(TypographicUnit::DidotPoint),
+        "Didot point",
+        "Didot points",
+    ),
+    (
+        Unit::Typographic(TypographicUnit::Cicero),
+        "cicero",
+        "ciceros",
+    ),
+    (
+        Unit::Typographic(TypographicUnit::ScaledPoint),
+        "scaled point",
+        "scaled points",
+    ),
+    (Unit::Typographic(TypographicUnit::Pica), "pica", "picas"),
+    (Unit::Typographic(TypographicUnit::Pixel), "pixel", "pixels"),
+    (
+        Unit::UsSurvey(UsSurveyUnit::SurveyFoot),
+        "survey foot",
+        "survey feet",
+    ),
+    (
+        Unit::UsSurvey(UsSurveyUnit::SurveyChain),
+        "survey chain",
+        "survey chains",
+    ),
+    (
+        Unit::UsSurvey(UsSurveyUnit::SurveyMile),
+        "survey mile",
+        "survey miles",
+    ),
+    (
+        Unit::Subatomic(SubatomicUnit::PlanckLength),
+        "Planck length",
+        "Planck lengths",
+    ),
+    (
+        Unit::Subatomic(SubatomicUnit::BohrRadius),
+        "Bohr radius",
+        "Bohr radii",
+    ),
+    (Unit::Traditional(TraditionalUnit::Cun), "cun", "cun"),
+    (Unit::Traditional(TraditionalUnit::Chi), "chi", "chi"),
+    (Unit::Traditional(TraditionalUnit::Zhang), "zhang", "zhang"),
+    (Unit::Traditional(TraditionalUnit::Li), "li", "li"),
+    (Unit::Traditional(TraditionalUnit::Shaku), "shaku", "shaku"),
+    (Unit::Traditional(TraditionalUnit::Ken), "ken", "ken"),
+    (Unit::Traditional(TraditionalUnit::Ri), "ri", "ri"),
+    (
+        Unit::Traditional(TraditionalUnit::Arshin),
+        "arshin",
+        "arshins",
+    ),
+    (Unit::Traditional(TraditionalUnit::Verst), "verst", "versts"),
+    (
+        Unit::Traditional(TraditionalUnit::Mil),
+        "Scandinavian mile",
+        "Scandinavian miles",
+    ),
+    #[cfg(feature = "historical")]
+    (Unit::Historical(HistoricalUnit::Cubit), "cubit", "cubits"),
+    #[cfg(feature = "historical")]
+    (Unit::Historical(HistoricalUnit::Smoot), "smoot", "smoots"),
+    #[cfg(feature = "historical")]
+    (
+        Unit::Historical(HistoricalUnit::Fathom),
+        "fathom",
+        "fathoms",
+    ),
+    #[cfg(feature = "historical")]
+    (
+        Unit::Historical(HistoricalUnit::FootballField),
+        "football field",
+        "football fields",
+    ),
+];
+
+const UNIT_SYMBOLS: &[(&str, Unit)] = &[
+    ("au", Unit::Astronomic(AstronomicalUnit)),
+    ("ls", Unit::Astronomic(Lightsecond)),
+    ("lm", Unit::Astronomic(Lightminute)),
+    ("lh", Unit::Astronomic(Lighthour)),
+    ("ld", Unit::Astronomic(Lightday)),
+    ("ly", Unit::Astronomic(Lightyear)),
+    ("pc", Unit::Astronomic(Parsec)),
+    ("kpc", Unit::Astronomic(Kiloparsec)),
+    ("Mpc", Unit::Astronomic(Megaparsec)),
+    ("Gpc", Unit::Astronomic(Gigaparsec)),
+    ("R☉", Unit::Astronomic(SolarRadius)),
+    ("Rsun", Unit::Astronomic(SolarRadius)),
+    ("R⊕", Unit::Astronomic(EarthRadius)),
+    ("Rearth", Unit::Astronomic(EarthRadius)),
+    ("LD", Unit::Astronomic(LunarDistance)),
+    ("th", Unit::Imperial(Thou)),
+    ("mil", Unit::Imperial(Thou)),
+    ("in", Unit::Imperial(Inch)),
+    ("\"", Unit::Imperial(Inch)),
+    ("″", Unit::Imperial(Inch)),
+    ("hh", Unit::Imperial(Hand)),
+    ("ft", Unit::Imperial(Foot)),
+    ("'", Unit::Imperial(Foot)),
+    ("′", Unit::Imperial(Foot)),
+    ("yd", Unit::Imperial(Yard)),
+    ("rd", Unit::Imperial(Rod)),
+    ("ch", Unit::Imperial(Chain)),
+    ("fur", Unit::Imperial(Furlong)),
+    ("mi", Unit::Imperial(Mile)),
+    ("lea", Unit::Imperial(League)),
+    ("qm", Unit::Metric(Quectometer)),
+    ("rm", Unit::Metric(Rontometer)),
+    ("ym", Unit::Metric(Yoctometer)),
+    ("zm", Unit::Metric(Zeptometer)),
+    ("am", Unit::Metric(Attometer)),
+    ("fm", Unit::Metric(Femtometer)),
+    ("fermi", Unit::Metric(Femtometer)),
+    ("pm", Unit::Metric(Picometer)),
+    ("Å", Unit::Metric(Angstrom)),
+    ("\u{212B}", Unit::Metric(Angstrom)),
+    ("angstrom", Unit::Metric(Angstrom)),
+    ("nm", Unit::Metric(Nanometer)),
+    ("µm", Unit::Metric(Micrometer)),
+    ("\u{3BC}m", Unit::Metric(Micrometer)),
+    ("um", Unit::Metric(Micrometer)),
+    ("mcm", Unit::Metric(Micrometer)),
+    ("micron", Unit::Metric(Micrometer)),
+    ("mm", Unit::Metric(Millimeter)),
+    ("cm", Unit::Metric(Centimeter)),
+    ("dm", Unit::Metric(Decimeter)),
+    ("m", Unit::Metric(Meter)),
+    ("dam", Unit::Metric(Decameter)),
+    ("hm", Unit::Metric(Hectometer)),
+    ("km", Unit::Metric(Kilometer)),
+    ("Mm", Unit::Metric(Megameter)),
+    ("Gm", Unit::Metric(Gigameter)),
+    ("Tm", Unit::Metric(Terameter)),
+    ("Pm", Unit::Metric(Petameter)),
+    ("Em", Unit::Metric(Exameter)),
+    ("Zm", Unit::Metric(Zettameter)),
+    ("Ym", Unit::Metric(Yottameter)),
+    ("Rm", Unit::Metric(Ronnameter)),
+    ("Qm", Unit::Metric(Quettameter)),
+    ("lP", Unit::Subatomic(PlanckLength)),
+    ("a0", Unit::Subatomic(BohrRadius)),
+    ("sp", Unit::Typographic(ScaledPoint)),
+    ("px", Unit::Typographic(Pixel)),
+    ("pt", Unit::Typographic(Point)),
+    ("bp", Unit::Typographic(Point)),
+    ("dd", Unit::Typographic(DidotPoint)),
+    ("pica", Unit::Typographic(Pica)),
+    ("cc", Unit::Typographic(Cicero)),
+    ("ftUS", Unit::UsSurvey(SurveyFoot)),
+    ("chUS", Unit::UsSurvey(SurveyChain)),
+    ("miUS", Unit::UsSurvey(SurveyMile)),
+    ("cun", Unit::Traditional(TraditionalUnit::Cun)),
+    ("chi", Unit::Traditional(TraditionalUnit::Chi)),
+    ("zhang", Unit::Traditional(TraditionalUnit::Zhang)),
+    ("li", Unit::Traditional(TraditionalUnit::Li)),
+    ("shaku", Unit::Traditional(TraditionalUnit::Shaku)),
+    ("ken", Unit::Traditional(TraditionalUnit::Ken)),
+    ("ri", Unit::Traditional(TraditionalUnit::Ri)),
+    ("arshin", Unit::Traditional(TraditionalUnit::Arshin)),
+    ("verst", Unit::Traditional(TraditionalUnit::Verst)),
+    ("smil", Unit::Traditional(TraditionalUnit::Mil)),
+    #[cfg(feature = "historical")]
+    ("cubit", Unit::Historical(HistoricalUnit::Cubit)),
+    #[cfg(feature = "historical")]
+    ("smoot", Unit::Historical(HistoricalUnit::Smoot)),
+    #[cfg(feature = "historical")]
+    ("ftm", Unit::Historical(HistoricalUnit::Fathom)),
+    #[cfg(feature = "historical")]
+    ("fbf", Unit::Historical(HistoricalUnit::FootballField)),
+];
+
+/// Compares two byte strings in const contexts, where the == operator is not available.
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
         }
+        index += 1;
     }
+
+    true
 }
 
 impl SiblingUnit for Unit {
     fn smaller_unit(&self) -> Option<Unit> {
         match self {
             Unit::Astronomic(astronomic_unit) => astronomic_unit.smaller_unit(),
+            Unit::Custom(_) => None,
+            #[cfg(feature = "historical")]
+            Unit::Historical(historical_unit) => historical_unit.smaller_unit(),
             Unit::Imperial(imperial_unit) => imperial_unit.smaller_unit(),
             Unit::Metric(metric_unit) => metric_unit.smaller_unit(),
+            Unit::Subatomic(subatomic_unit) => subatomic_unit.smaller_unit(),
+            Unit::Traditional(traditional_unit) => traditional_unit.smaller_unit(),
+            Unit::Typographic(typographic_unit) => typographic_unit.smaller_unit(),
+            Unit::UsSurvey(us_survey_unit) => us_survey_unit.smaller_unit(),
         }
     }
 
     fn greater_unit(&self) -> Option<Unit> {
         match self {
             Unit::Astronomic(astronomic_unit) => astronomic_unit.greater_unit(),
+            Unit::Custom(_) => None,
+            #[cfg(feature = "historical")]
+            Unit::Historical(historical_unit) => historical_unit.greater_unit(),
             Unit::Imperial(imperial_unit) => imperial_unit.greater_unit(),
             Unit::Metric(metric_unit) => metric_unit.greater_unit(),
+            Unit::Subatomic(subatomic_unit) => subatomic_unit.greater_unit(),
+            Unit::Traditional(traditional_unit) => traditional_unit.greater_unit(),
+            Unit::Typographic(typographic_unit) => typographic_unit.greater_unit(),
+            Unit::UsSurvey(us_survey_unit) => us_survey_unit.greater_unit(),
         }
     }
 }
@@ -544,42 +4098,13 @@ impl FromStr for Unit {
     type Err = &'static str;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "au" => Ok(Unit::Astronomic(AstronomicalUnit)),
-            "ls" => Ok(Unit::Astronomic(Lightsecond)),
-            "lm" => Ok(Unit::Astronomic(Lightminute)),
-            "lh" => Ok(Unit::Astronomic(Lighthour)),
-            "ld" => Ok(Unit::Astronomic(Lightday)),
-            "ly" => Ok(Unit::Astronomic(Lightyear)),
-            "pc" => Ok(Unit::Astronomic(Parsec)),
-            "kpc" => Ok(Unit::Astronomic(Kiloparsec)),
-            "Mpc" => Ok(Unit::Astronomic(Megaparsec)),
-            "in" => Ok(Unit::Imperial(Inch)),
-            "ft" => Ok(Unit::Imperial(Foot)),
-            "yd" => Ok(Unit::Imperial(Yard)),
-            "mi" => Ok(Unit::Imperial(Mile)),
-            "ym" => Ok(Unit::Metric(Yoctometer)),
-            "zm" => Ok(Unit::Metric(Zeptometer)),
-            "am" => Ok(Unit::Metric(Attometer)),
-            "fm" => Ok(Unit::Metric(Femtometer)),
-            "pm" => Ok(Unit::Metric(Picometer)),
-            "nm" => Ok(Unit::Metric(Nanometer)),
-            "µm" => Ok(Unit::Metric(Micrometer)),
-            "mm" => Ok(Unit::Metric(Millimeter)),
-            "cm" => Ok(Unit::Metric(Centimeter)),
-            "dm" => Ok(Unit::Metric(Decimeter)),
-            "m" => Ok(Unit::Metric(Meter)),
-            "dam" => Ok(Unit::Metric(Decameter)),
-            "hm" => Ok(Unit::Metric(Hectometer)),
-            "km" => Ok(Unit::Metric(Kilometer)),
-            "Mm" => Ok(Unit::Metric(Megameter)),
-            "Gm" => Ok(Unit::Metric(Gigameter)),
-            "Tm" => Ok(Unit::Metric(Terameter)),
-            "Pm" => Ok(Unit::Metric(Petameter)),
-            "Em" => Ok(Unit::Metric(Exameter)),
-            "Zm" => Ok(Unit::Metric(Zettameter)),
-            "Ym" => Ok(Unit::Metric(Yottameter)),
-            _ => Err("unable to parse string to Unit-enum."),
+        let built_in = Unit::from_symbol(s).or_else(|| Unit::from_name(s));
+        match built_in {
+            Some(unit) if !unit.yields_to_custom_units() => Ok(unit),
+            _ => CustomUnit::find(s)
+                .map(Unit::Custom)
+                .or(built_in)
+                .ok_or("unable to parse string to Unit-enum."),
         }
     }
 }
@@ -596,6 +4121,10 @@ impl From<AstronomicUnit> for Unit {
             AstronomicUnit::Parsec => Unit::Astronomic(AstronomicUnit::Parsec),
             AstronomicUnit::Kiloparsec => Unit::Astronomic(AstronomicUnit::Kiloparsec),
             AstronomicUnit::Megaparsec => Unit::Astronomic(AstronomicUnit::Megaparsec),
+            AstronomicUnit::Gigaparsec => Unit::Astronomic(AstronomicUnit::Gigaparsec),
+            AstronomicUnit::SolarRadius => Unit::Astronomic(AstronomicUnit::SolarRadius),
+            AstronomicUnit::EarthRadius => Unit::Astronomic(AstronomicUnit::EarthRadius),
+            AstronomicUnit::LunarDistance => Unit::Astronomic(AstronomicUnit::LunarDistance),
         }
     }
 }
@@ -603,10 +4132,16 @@ impl From<AstronomicUnit> for Unit {
 impl From<ImperialUnit> for Unit {
     fn from(item: ImperialUnit) -> Self {
         match item {
+            ImperialUnit::Thou => Unit::Imperial(ImperialUnit::Thou),
             ImperialUnit::Inch => Unit::Imperial(ImperialUnit::Inch),
+            ImperialUnit::Hand => Unit::Imperial(ImperialUnit::Hand),
             ImperialUnit::Foot => Unit::Imperial(ImperialUnit::Foot),
             ImperialUnit::Yard => Unit::Imperial(ImperialUnit::Yard),
+            ImperialUnit::Rod => Unit::Imperial(ImperialUnit::Rod),
+            ImperialUnit::Chain => Unit::Imperial(ImperialUnit::Chain),
+            ImperialUnit::Furlong => Unit::Imperial(ImperialUnit::Furlong),
             ImperialUnit::Mile => Unit::Imperial(ImperialUnit::Mile),
+            ImperialUnit::League => Unit::Imperial(ImperialUnit::League),
         }
     }
 }
@@ -621,6 +4156,7 @@ impl From<MetricUnit> for Unit {
             MetricUnit::Attometer => Unit::Metric(MetricUnit::Attometer),
             MetricUnit::Femtometer => Unit::Metric(MetricUnit::Femtometer),
             MetricUnit::Picometer => Unit::Metric(MetricUnit::Picometer),
+            MetricUnit::Angstrom => Unit::Metric(MetricUnit::Angstrom),
             MetricUnit::Nanometer => Unit::Metric(MetricUnit::Nanometer),
             MetricUnit::Micrometer => Unit::Metric(MetricUnit::Micrometer),
             MetricUnit::Millimeter => Unit::Metric(MetricUnit::Millimeter),
@@ -643,15 +4179,201 @@ impl From<MetricUnit> for Unit {
     }
 }
 
-#[derive(PartialEq)]
+impl From<SubatomicUnit> for Unit {
+    fn from(item: SubatomicUnit) -> Self {
+        Unit::Subatomic(item)
+    }
+}
+
+#[cfg(feature = "historical")]
+impl From<HistoricalUnit> for Unit {
+    fn from(item: HistoricalUnit) -> Self {
+        Unit::Historical(item)
+    }
+}
+
+impl From<TraditionalUnit> for Unit {
+    fn from(item: TraditionalUnit) -> Self {
+        Unit::Traditional(item)
+    }
+}
+
+impl From<TypographicUnit> for Unit {
+    fn from(item: TypographicUnit) -> Self {
+        Unit::Typographic(item)
+    }
+}
+
+impl From<UsSurveyUnit> for Unit {
+    fn from(item: UsSurveyUnit) -> Self {
+        Unit::UsSurvey(item)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 pub enum UnitSystem {
     Astronomic,
+    Chinese,
+    Custom,
+    #[cfg(feature = "historical")]
+    Historical,
     Imperial,
+    Japanese,
     Metric,
+    Russian,
+    Scandinavian,
+    Subatomic,
+    Typographic,
+    UsSurvey,
+}
+
+impl UnitSystem {
+    /// Gets all units of this system in ascending order of their size.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Unit, UnitSystem, ImperialUnit::*};
+    ///
+    /// let units = UnitSystem::Imperial.units();
+    ///
+    /// assert_eq!(10, units.len());
+    /// assert_eq!(Unit::Imperial(Thou), units[0]);
+    /// assert_eq!(Unit::Imperial(League), units[9]);
+    /// ```
+    pub fn units(&self) -> Vec<Unit> {
+        Unit::all().filter(|unit| unit.system() == *self).collect()
+    }
+
+    /// Gets a unit of this system, from which normalize() finds the best unit, or None for the
+    /// custom units, that have no order.
+    fn base_unit(&self) -> Option<Unit> {
+        match self {
+            UnitSystem::Astronomic => Some(Unit::Astronomic(AstronomicalUnit)),
+            UnitSystem::Chinese => Some(Unit::Traditional(TraditionalUnit::Chi)),
+            UnitSystem::Custom => None,
+            #[cfg(feature = "historical")]
+            UnitSystem::Historical => Some(Unit::Historical(HistoricalUnit::Cubit)),
+            UnitSystem::Imperial => Some(Unit::Imperial(Foot)),
+            UnitSystem::Japanese => Some(Unit::Traditional(TraditionalUnit::Shaku)),
+            UnitSystem::Metric => Some(Unit::Metric(Meter)),
+            UnitSystem::Russian => Some(Unit::Traditional(TraditionalUnit::Arshin)),
+            UnitSystem::Scandinavian => Some(Unit::Traditional(TraditionalUnit::Mil)),
+            UnitSystem::Subatomic => Some(Unit::Subatomic(BohrRadius)),
+            UnitSystem::Typographic => Some(Unit::Typographic(Point)),
+            UnitSystem::UsSurvey => Some(Unit::UsSurvey(SurveyFoot)),
+        }
+    }
+
+    /// Gets the units of this system, that are commonly used, in ascending order.
+    fn readable_units(&self) -> &'static [Unit] {
+        match self {
+            UnitSystem::Astronomic => &[
+                Unit::Astronomic(AstronomicalUnit),
+                Unit::Astronomic(Lightyear),
+                Unit::Astronomic(Megaparsec),
+            ],
+            UnitSystem::Chinese => &[
+                Unit::Traditional(TraditionalUnit::Cun),
+                Unit::Traditional(TraditionalUnit::Chi),
+                Unit::Traditional(TraditionalUnit::Li),
+            ],
+            UnitSystem::Custom | UnitSystem::Metric => &[
+                Unit::Metric(Nanometer),
+                Unit::Metric(Micrometer),
+                Unit::Metric(Millimeter),
+                Unit::Metric(Centimeter),
+                Unit::Metric(Meter),
+                Unit::Metric(Kilometer),
+            ],
+            UnitSystem::Imperial => &[
+                Unit::Imperial(Inch),
+                Unit::Imperial(Foot),
+                Unit::Imperial(Mile),
+            ],
+            UnitSystem::Japanese => &[
+                Unit::Traditional(TraditionalUnit::Shaku),
+                Unit::Traditional(TraditionalUnit::Ken),
+                Unit::Traditional(TraditionalUnit::Ri),
+            ],
+            UnitSystem::Russian => &[
+                Unit::Traditional(TraditionalUnit::Arshin),
+                Unit::Traditional(TraditionalUnit::Verst),
+            ],
+            UnitSystem::Scandinavian => &[Unit::Traditional(TraditionalUnit::Mil)],
+            #[cfg(feature = "historical")]
+            UnitSystem::Historical => &[
+                Unit::Historical(HistoricalUnit::Cubit),
+                Unit::Historical(HistoricalUnit::Smoot),
+                Unit::Historical(HistoricalUnit::Fathom),
+                Unit::Historical(HistoricalUnit::FootballField),
+            ],
+            UnitSystem::Subatomic => &[Unit::Subatomic(PlanckLength), Unit::Subatomic(BohrRadius)],
+            UnitSystem::Typographic => &[Unit::Typographic(Point)],
+            UnitSystem::UsSurvey => &[Unit::UsSurvey(SurveyFoot), Unit::UsSurvey(SurveyMile)],
+        }
+    }
+}
+
+impl FromStr for UnitSystem {
+    type Err = &'static str;
+
+    /// Parses the name of a unit system, like "metric" or "US survey". The comparison ignores the
+    /// case, whitespace, hyphens and underscores.
+    ///
+    /// # Example
+    /// ```
+    /// use length::UnitSystem;
+    ///
+    /// assert_eq!(Ok(UnitSystem::Imperial), "Imperial".parse());
+    /// assert_eq!(Ok(UnitSystem::UsSurvey), "us-survey".parse());
+    /// assert!("nautical".parse::<UnitSystem>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key: String = s
+            .to_lowercase()
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+            .collect();
+
+        match key.as_str() {
+            "astronomic" => Ok(UnitSystem::Astronomic),
+            "chinese" => Ok(UnitSystem::Chinese),
+            "custom" => Ok(UnitSystem::Custom),
+            "imperial" => Ok(UnitSystem::Imperial),
+            "japanese" => Ok(UnitSystem::Japanese),
+            "metric" => Ok(UnitSystem::Metric),
+            "russian" => Ok(UnitSystem::Russian),
+            "scandinavian" => Ok(UnitSystem::Scandinavian),
+            #[cfg(feature = "historical")]
+            "historical" => Ok(UnitSystem::Historical),
+            "subatomic" => Ok(UnitSystem::Subatomic),
+            "typographic" => Ok(UnitSystem::Typographic),
+            "ussurvey" => Ok(UnitSystem::UsSurvey),
+            _ => Err("unable to parse string to UnitSystem-enum."),
+        }
+    }
 }
 
-trait UnitFactor {
-    fn factor(&self) -> f64;
+impl fmt::Display for UnitSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            UnitSystem::Astronomic => "astronomic",
+            UnitSystem::Chinese => "chinese",
+            UnitSystem::Custom => "custom",
+            UnitSystem::Imperial => "imperial",
+            UnitSystem::Japanese => "japanese",
+            UnitSystem::Metric => "metric",
+            UnitSystem::Russian => "russian",
+            UnitSystem::Scandinavian => "scandinavian",
+            #[cfg(feature = "historical")]
+            UnitSystem::Historical => "historical",
+            UnitSystem::Subatomic => "subatomic",
+            UnitSystem::Typographic => "typographic",
+            UnitSystem::UsSurvey => "us survey",
+        };
+
+        f.pad(name)
+    }
 }
 
 trait SiblingUnit {
@@ -660,30 +4382,125 @@ trait SiblingUnit {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 pub enum AstronomicUnit {
-    AstronomicalUnit,
+    EarthRadius,
     Lightsecond,
+    LunarDistance,
+    SolarRadius,
     Lightminute,
+    AstronomicalUnit,
     Lighthour,
     Lightday,
     Lightyear,
     Parsec,
     Kiloparsec,
     Megaparsec,
+    Gigaparsec,
+}
+
+impl AstronomicUnit {
+    /// Gets the amount of meters in one of this unit. Unlike Unit::meters_per_unit(), it can be
+    /// evaluated in const contexts.
+    ///
+    /// # Example
+    /// ```
+    /// use length::AstronomicUnit::*;
+    ///
+    /// const FACTOR: f64 = Lightsecond.meters_per_unit();
+    ///
+    /// assert_eq!(299_792_458.0, FACTOR);
+    /// ```
+    pub const fn meters_per_unit(&self) -> f64 {
+        match self {
+            AstronomicUnit::EarthRadius => Length::EARTH_RADIUS_TO_METER_FACTOR,
+            AstronomicUnit::Lightsecond => Length::LIGHTSECOND_TO_METER_FACTOR,
+            AstronomicUnit::LunarDistance => Length::LUNAR_DISTANCE_TO_METER_FACTOR,
+            AstronomicUnit::SolarRadius => Length::SOLAR_RADIUS_TO_METER_FACTOR,
+            AstronomicUnit::Lightminute => Length::LIGHTSECOND_TO_METER_FACTOR * 60.0,
+            AstronomicUnit::AstronomicalUnit => Length::ASTRONOMICAL_UNIT_TO_METER_FACTOR,
+            AstronomicUnit::Lighthour => Length::LIGHTSECOND_TO_METER_FACTOR * 3_600.0,
+            AstronomicUnit::Lightday => Length::LIGHTSECOND_TO_METER_FACTOR * 86_400.0,
+            AstronomicUnit::Lightyear => Length::LIGHTYEAR_TO_METER_FACTOR,
+            AstronomicUnit::Parsec => Length::PARSEC_TO_METER_FACTOR,
+            AstronomicUnit::Kiloparsec => Length::PARSEC_TO_METER_FACTOR * 1e3,
+            AstronomicUnit::Megaparsec => Length::PARSEC_TO_METER_FACTOR * 1e6,
+            AstronomicUnit::Gigaparsec => Length::PARSEC_TO_METER_FACTOR * 1e9,
+        }
+    }
+
+    /// Gets the symbol of this unit, like "ly".
+    ///
+    /// # Example
+    /// ```
+    /// use length::AstronomicUnit::*;
+    ///
+    /// assert_eq!("ly", Lightyear.symbol());
+    /// ```
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            EarthRadius => "R⊕",
+            Lightsecond => "ls",
+            LunarDistance => "LD",
+            SolarRadius => "R☉",
+            Lightminute => "lm",
+            AstronomicalUnit => "au",
+            Lighthour => "lh",
+            Lightday => "ld",
+            Lightyear => "ly",
+            Parsec => "pc",
+            Kiloparsec => "kpc",
+            Megaparsec => "Mpc",
+            Gigaparsec => "Gpc",
+        }
+    }
+
+    /// Gets the English name of this unit in singular, like "light-year".
+    ///
+    /// # Example
+    /// ```
+    /// use length::AstronomicUnit::*;
+    ///
+    /// assert_eq!("light-year", Lightyear.name());
+    /// ```
+    pub fn name(&self) -> &'static str {
+        Unit::from(*self)
+            .names()
+            .map_or("", |(singular, _)| singular)
+    }
+
+    /// Gets the English name of this unit in plural, like "light-years".
+    ///
+    /// # Example
+    /// ```
+    /// use length::AstronomicUnit::*;
+    ///
+    /// assert_eq!("light-years", Lightyear.plural_name());
+    /// ```
+    pub fn plural_name(&self) -> &'static str {
+        Unit::from(*self).names().map_or("", |(_, plural)| plural)
+    }
 }
 
-impl UnitFactor for AstronomicUnit {
-    fn factor(&self) -> f64 {
+impl AstronomicUnit {
+    /// Gets the amount of meters in one unit as numerator and denominator, if it is a rational
+    /// number.
+    const fn exact_meters_per_unit(&self) -> Option<(i128, i128)> {
         match self {
-            AstronomicUnit::AstronomicalUnit => Length::ASTRONOMICAL_UNIT_TO_LIGHTYEAR_FACTOR,
-            AstronomicUnit::Lightsecond => Length::LIGHTSECOND_TO_LIGHTYEAR_FACTOR,
-            AstronomicUnit::Lightminute => Length::LIGHTMINUTE_TO_LIGHTYEAR_FACTOR,
-            AstronomicUnit::Lighthour => Length::LIGHTHOUR_TO_LIGHTYEAR_FACTOR,
-            AstronomicUnit::Lightday => Length::LIGHTDAY_TO_LIGHTYEAR_FACTOR,
-            AstronomicUnit::Lightyear => 1.0,
-            AstronomicUnit::Parsec => Length::PARSEC_TO_LIGHTYEAR_FACTOR,
-            AstronomicUnit::Kiloparsec => Length::KILOPARSEC_TO_LIGHTYEAR_FACTOR,
-            AstronomicUnit::Megaparsec => Length::MEGAPARSEC_TO_LIGHTYEAR_FACTOR,
+            AstronomicUnit::EarthRadius => Some((6_378_100, 1)),
+            AstronomicUnit::Lightsecond => Some((299_792_458, 1)),
+            AstronomicUnit::LunarDistance => Some((384_399_000, 1)),
+            AstronomicUnit::SolarRadius => Some((695_700_000, 1)),
+            AstronomicUnit::Lightminute => Some((299_792_458 * 60, 1)),
+            AstronomicUnit::AstronomicalUnit => Some((149_597_870_700, 1)),
+            AstronomicUnit::Lighthour => Some((299_792_458 * 3_600, 1)),
+            AstronomicUnit::Lightday => Some((299_792_458 * 86_400, 1)),
+            AstronomicUnit::Lightyear => Some((9_460_730_472_580_800, 1)),
+            AstronomicUnit::Parsec => None,
+            AstronomicUnit::Kiloparsec => None,
+            AstronomicUnit::Megaparsec => None,
+            AstronomicUnit::Gigaparsec => None,
         }
     }
 }
@@ -691,64 +4508,155 @@ impl UnitFactor for AstronomicUnit {
 impl SiblingUnit for AstronomicUnit {
     fn smaller_unit(&self) -> Option<Unit> {
         match self {
-            AstronomicUnit::AstronomicalUnit => None,
-            AstronomicUnit::Lightsecond => Some(Unit::Astronomic(AstronomicUnit::AstronomicalUnit)),
-            AstronomicUnit::Lightminute => Some(Unit::Astronomic(AstronomicUnit::Lightsecond)),
-            AstronomicUnit::Lighthour => Some(Unit::Astronomic(AstronomicUnit::Lightminute)),
+            AstronomicUnit::EarthRadius => None,
+            AstronomicUnit::Lightsecond => Some(Unit::Astronomic(AstronomicUnit::EarthRadius)),
+            AstronomicUnit::LunarDistance => Some(Unit::Astronomic(AstronomicUnit::Lightsecond)),
+            AstronomicUnit::SolarRadius => Some(Unit::Astronomic(AstronomicUnit::LunarDistance)),
+            AstronomicUnit::Lightminute => Some(Unit::Astronomic(AstronomicUnit::SolarRadius)),
+            AstronomicUnit::AstronomicalUnit => Some(Unit::Astronomic(AstronomicUnit::Lightminute)),
+            AstronomicUnit::Lighthour => Some(Unit::Astronomic(AstronomicUnit::AstronomicalUnit)),
             AstronomicUnit::Lightday => Some(Unit::Astronomic(AstronomicUnit::Lighthour)),
             AstronomicUnit::Lightyear => Some(Unit::Astronomic(AstronomicUnit::Lightday)),
             AstronomicUnit::Parsec => Some(Unit::Astronomic(AstronomicUnit::Lightyear)),
             AstronomicUnit::Kiloparsec => Some(Unit::Astronomic(AstronomicUnit::Parsec)),
             AstronomicUnit::Megaparsec => Some(Unit::Astronomic(AstronomicUnit::Kiloparsec)),
+            AstronomicUnit::Gigaparsec => Some(Unit::Astronomic(AstronomicUnit::Megaparsec)),
         }
     }
 
     fn greater_unit(&self) -> Option<Unit> {
         match self {
-            AstronomicUnit::AstronomicalUnit => Some(Unit::Astronomic(AstronomicUnit::Lightsecond)),
-            AstronomicUnit::Lightsecond => Some(Unit::Astronomic(AstronomicUnit::Lightminute)),
-            AstronomicUnit::Lightminute => Some(Unit::Astronomic(AstronomicUnit::Lighthour)),
+            AstronomicUnit::EarthRadius => Some(Unit::Astronomic(AstronomicUnit::Lightsecond)),
+            AstronomicUnit::Lightsecond => Some(Unit::Astronomic(AstronomicUnit::LunarDistance)),
+            AstronomicUnit::LunarDistance => Some(Unit::Astronomic(AstronomicUnit::SolarRadius)),
+            AstronomicUnit::SolarRadius => Some(Unit::Astronomic(AstronomicUnit::Lightminute)),
+            AstronomicUnit::Lightminute => Some(Unit::Astronomic(AstronomicUnit::AstronomicalUnit)),
+            AstronomicUnit::AstronomicalUnit => Some(Unit::Astronomic(AstronomicUnit::Lighthour)),
             AstronomicUnit::Lighthour => Some(Unit::Astronomic(AstronomicUnit::Lightday)),
             AstronomicUnit::Lightday => Some(Unit::Astronomic(AstronomicUnit::Lightyear)),
             AstronomicUnit::Lightyear => Some(Unit::Astronomic(AstronomicUnit::Parsec)),
             AstronomicUnit::Parsec => Some(Unit::Astronomic(AstronomicUnit::Kiloparsec)),
             AstronomicUnit::Kiloparsec => Some(Unit::Astronomic(AstronomicUnit::Megaparsec)),
-            AstronomicUnit::Megaparsec => None,
+            AstronomicUnit::Megaparsec => Some(Unit::Astronomic(AstronomicUnit::Gigaparsec)),
+            AstronomicUnit::Gigaparsec => None,
         }
     }
 }
 
-impl ToString for AstronomicUnit {
-    fn to_string(&self) -> String {
+impl fmt::Display for AstronomicUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.symbol())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub enum ImperialUnit {
+    Thou,
+    Inch,
+    Hand,
+    Foot,
+    Yard,
+    Rod,
+    Chain,
+    Furlong,
+    Mile,
+    League,
+}
+
+impl ImperialUnit {
+    /// Gets the amount of meters in one of this unit. Unlike Unit::meters_per_unit(), it can be
+    /// evaluated in const contexts.
+    ///
+    /// # Example
+    /// ```
+    /// use length::ImperialUnit::*;
+    ///
+    /// const FACTOR: f64 = Foot.meters_per_unit();
+    ///
+    /// assert_eq!(0.304_8, FACTOR);
+    /// ```
+    pub const fn meters_per_unit(&self) -> f64 {
+        match self {
+            ImperialUnit::Thou => 0.000_025_4,
+            ImperialUnit::Inch => 0.025_4,
+            ImperialUnit::Hand => 0.101_6,
+            ImperialUnit::Foot => 0.304_8,
+            ImperialUnit::Yard => 0.914_4,
+            ImperialUnit::Rod => 5.029_2,
+            ImperialUnit::Chain => 20.116_8,
+            ImperialUnit::Furlong => 201.168,
+            ImperialUnit::Mile => 1_609.344,
+            ImperialUnit::League => 4_828.032,
+        }
+    }
+
+    /// Gets the symbol of this unit, like "ft".
+    ///
+    /// # Example
+    /// ```
+    /// use length::ImperialUnit::*;
+    ///
+    /// assert_eq!("ft", Foot.symbol());
+    /// ```
+    pub fn symbol(&self) -> &'static str {
         match self {
-            AstronomicalUnit => String::from("au"),
-            Lightsecond => String::from("ls"),
-            Lightminute => String::from("lm"),
-            Lighthour => String::from("lh"),
-            Lightday => String::from("ld"),
-            Lightyear => String::from("ly"),
-            Parsec => String::from("pc"),
-            Kiloparsec => String::from("kpc"),
-            Megaparsec => String::from("Mpc"),
+            Thou => "th",
+            Inch => "in",
+            Hand => "hh",
+            Foot => "ft",
+            Yard => "yd",
+            Rod => "rd",
+            Chain => "ch",
+            Furlong => "fur",
+            Mile => "mi",
+            League => "lea",
         }
     }
+
+    /// Gets the English name of this unit in singular, like "foot".
+    ///
+    /// # Example
+    /// ```
+    /// use length::ImperialUnit::*;
+    ///
+    /// assert_eq!("foot", Foot.name());
+    /// ```
+    pub fn name(&self) -> &'static str {
+        Unit::from(*self)
+            .names()
+            .map_or("", |(singular, _)| singular)
+    }
+
+    /// Gets the English name of this unit in plural, like "feet".
+    ///
+    /// # Example
+    /// ```
+    /// use length::ImperialUnit::*;
+    ///
+    /// assert_eq!("feet", Foot.plural_name());
+    /// ```
+    pub fn plural_name(&self) -> &'static str {
+        Unit::from(*self).names().map_or("", |(_, plural)| plural)
+    }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum ImperialUnit {
-    Inch,
-    Foot,
-    Yard,
-    Mile,
-}
-
-impl UnitFactor for ImperialUnit {
-    fn factor(&self) -> f64 {
+impl ImperialUnit {
+    /// Gets the amount of meters in one unit as numerator and denominator, if it is a rational
+    /// number.
+    const fn exact_meters_per_unit(&self) -> Option<(i128, i128)> {
         match self {
-            ImperialUnit::Inch => 1.0,
-            ImperialUnit::Foot => 12.0,
-            ImperialUnit::Yard => 36.0,
-            ImperialUnit::Mile => 63360.0,
+            ImperialUnit::Thou => Some((254, 10_000_000)),
+            ImperialUnit::Inch => Some((254, 10_000)),
+            ImperialUnit::Hand => Some((1_016, 10_000)),
+            ImperialUnit::Foot => Some((3_048, 10_000)),
+            ImperialUnit::Yard => Some((9_144, 10_000)),
+            ImperialUnit::Rod => Some((50_292, 10_000)),
+            ImperialUnit::Chain => Some((201_168, 10_000)),
+            ImperialUnit::Furlong => Some((201_168, 1_000)),
+            ImperialUnit::Mile => Some((1_609_344, 1_000)),
+            ImperialUnit::League => Some((4_828_032, 1_000)),
         }
     }
 }
@@ -756,35 +4664,44 @@ impl UnitFactor for ImperialUnit {
 impl SiblingUnit for ImperialUnit {
     fn smaller_unit(&self) -> Option<Unit> {
         match self {
-            ImperialUnit::Inch => None,
-            ImperialUnit::Foot => Some(Unit::Imperial(ImperialUnit::Inch)),
+            ImperialUnit::Thou => None,
+            ImperialUnit::Inch => Some(Unit::Imperial(ImperialUnit::Thou)),
+            ImperialUnit::Hand => Some(Unit::Imperial(ImperialUnit::Inch)),
+            ImperialUnit::Foot => Some(Unit::Imperial(ImperialUnit::Hand)),
             ImperialUnit::Yard => Some(Unit::Imperial(ImperialUnit::Foot)),
-            ImperialUnit::Mile => Some(Unit::Imperial(ImperialUnit::Yard)),
+            ImperialUnit::Rod => Some(Unit::Imperial(ImperialUnit::Yard)),
+            ImperialUnit::Chain => Some(Unit::Imperial(ImperialUnit::Rod)),
+            ImperialUnit::Furlong => Some(Unit::Imperial(ImperialUnit::Chain)),
+            ImperialUnit::Mile => Some(Unit::Imperial(ImperialUnit::Furlong)),
+            ImperialUnit::League => Some(Unit::Imperial(ImperialUnit::Mile)),
         }
     }
 
     fn greater_unit(&self) -> Option<Unit> {
         match self {
-            ImperialUnit::Inch => Some(Unit::Imperial(ImperialUnit::Foot)),
+            ImperialUnit::Thou => Some(Unit::Imperial(ImperialUnit::Inch)),
+            ImperialUnit::Inch => Some(Unit::Imperial(ImperialUnit::Hand)),
+            ImperialUnit::Hand => Some(Unit::Imperial(ImperialUnit::Foot)),
             ImperialUnit::Foot => Some(Unit::Imperial(ImperialUnit::Yard)),
-            ImperialUnit::Yard => Some(Unit::Imperial(ImperialUnit::Mile)),
-            ImperialUnit::Mile => None,
+            ImperialUnit::Yard => Some(Unit::Imperial(ImperialUnit::Rod)),
+            ImperialUnit::Rod => Some(Unit::Imperial(ImperialUnit::Chain)),
+            ImperialUnit::Chain => Some(Unit::Imperial(ImperialUnit::Furlong)),
+            ImperialUnit::Furlong => Some(Unit::Imperial(ImperialUnit::Mile)),
+            ImperialUnit::Mile => Some(Unit::Imperial(ImperialUnit::League)),
+            ImperialUnit::League => None,
         }
     }
 }
 
-impl ToString for ImperialUnit {
-    fn to_string(&self) -> String {
-        match self {
-            Inch => String::from("in"),
-            Foot => String::from("ft"),
-            Yard => String::from("yd"),
-            Mile => String::from("mi"),
-        }
+impl fmt::Display for ImperialUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.symbol())
     }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 pub enum MetricUnit {
     Quectometer,
     Rontometer,
@@ -793,6 +4710,7 @@ pub enum MetricUnit {
     Attometer,
     Femtometer,
     Picometer,
+    Angstrom,
     Nanometer,
     Micrometer,
     Millimeter,
@@ -810,11 +4728,22 @@ pub enum MetricUnit {
     Zettameter,
     Yottameter,
     Ronnameter,
-    Quettameter
+    Quettameter,
 }
 
-impl UnitFactor for MetricUnit {
-    fn factor(&self) -> f64 {
+impl MetricUnit {
+    /// Gets the amount of meters in one of this unit. Unlike Unit::meters_per_unit(), it can be
+    /// evaluated in const contexts.
+    ///
+    /// # Example
+    /// ```
+    /// use length::MetricUnit::*;
+    ///
+    /// const FACTOR: f64 = Kilometer.meters_per_unit();
+    ///
+    /// assert_eq!(1_000.0, FACTOR);
+    /// ```
+    pub const fn meters_per_unit(&self) -> f64 {
         match self {
             MetricUnit::Quectometer => 0.000_000_000_000_000_000_000_000_000_001,
             MetricUnit::Rontometer => 0.000_000_000_000_000_000_000_000_001,
@@ -823,6 +4752,7 @@ impl UnitFactor for MetricUnit {
             MetricUnit::Attometer => 0.000_000_000_000_000_001,
             MetricUnit::Femtometer => 0.000_000_000_000_001,
             MetricUnit::Picometer => 0.000_000_000_001,
+            MetricUnit::Angstrom => 0.000_000_000_1,
             MetricUnit::Nanometer => 0.000_000_001,
             MetricUnit::Micrometer => 0.000_001,
             MetricUnit::Millimeter => 0.001,
@@ -843,6 +4773,106 @@ impl UnitFactor for MetricUnit {
             MetricUnit::Quettameter => 1_000_000_000_000_000_000_000_000_000_000.0,
         }
     }
+
+    /// Gets the symbol of this unit, like "km".
+    ///
+    /// # Example
+    /// ```
+    /// use length::MetricUnit::*;
+    ///
+    /// assert_eq!("km", Kilometer.symbol());
+    /// ```
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Quectometer => "qm",
+            Rontometer => "rm",
+            Yoctometer => "ym",
+            Zeptometer => "zm",
+            Attometer => "am",
+            Femtometer => "fm",
+            Picometer => "pm",
+            Angstrom => "Å",
+            Nanometer => "nm",
+            Micrometer => "µm",
+            Millimeter => "mm",
+            Centimeter => "cm",
+            Decimeter => "dm",
+            Meter => "m",
+            Decameter => "dam",
+            Hectometer => "hm",
+            Kilometer => "km",
+            Megameter => "Mm",
+            Gigameter => "Gm",
+            Terameter => "Tm",
+            Petameter => "Pm",
+            Exameter => "Em",
+            Zettameter => "Zm",
+            Yottameter => "Ym",
+            Ronnameter => "Rm",
+            Quettameter => "Qm",
+        }
+    }
+
+    /// Gets the English name of this unit in singular, like "kilometer".
+    ///
+    /// # Example
+    /// ```
+    /// use length::MetricUnit::*;
+    ///
+    /// assert_eq!("kilometer", Kilometer.name());
+    /// ```
+    pub fn name(&self) -> &'static str {
+        Unit::from(*self)
+            .names()
+            .map_or("", |(singular, _)| singular)
+    }
+
+    /// Gets the English name of this unit in plural, like "kilometers".
+    ///
+    /// # Example
+    /// ```
+    /// use length::MetricUnit::*;
+    ///
+    /// assert_eq!("kilometers", Kilometer.plural_name());
+    /// ```
+    pub fn plural_name(&self) -> &'static str {
+        Unit::from(*self).names().map_or("", |(_, plural)| plural)
+    }
+}
+
+impl MetricUnit {
+    /// Gets the amount of meters in one unit as numerator and denominator, if it is a rational
+    /// number.
+    const fn exact_meters_per_unit(&self) -> Option<(i128, i128)> {
+        match self {
+            MetricUnit::Quectometer => Some((1, 10_i128.pow(30))),
+            MetricUnit::Rontometer => Some((1, 10_i128.pow(27))),
+            MetricUnit::Yoctometer => Some((1, 10_i128.pow(24))),
+            MetricUnit::Zeptometer => Some((1, 10_i128.pow(21))),
+            MetricUnit::Attometer => Some((1, 10_i128.pow(18))),
+            MetricUnit::Femtometer => Some((1, 10_i128.pow(15))),
+            MetricUnit::Picometer => Some((1, 10_i128.pow(12))),
+            MetricUnit::Angstrom => Some((1, 10_i128.pow(10))),
+            MetricUnit::Nanometer => Some((1, 10_i128.pow(9))),
+            MetricUnit::Micrometer => Some((1, 10_i128.pow(6))),
+            MetricUnit::Millimeter => Some((1, 10_i128.pow(3))),
+            MetricUnit::Centimeter => Some((1, 10_i128.pow(2))),
+            MetricUnit::Decimeter => Some((1, 10_i128.pow(1))),
+            MetricUnit::Meter => Some((10_i128.pow(0), 1)),
+            MetricUnit::Decameter => Some((10_i128.pow(1), 1)),
+            MetricUnit::Hectometer => Some((10_i128.pow(2), 1)),
+            MetricUnit::Kilometer => Some((10_i128.pow(3), 1)),
+            MetricUnit::Megameter => Some((10_i128.pow(6), 1)),
+            MetricUnit::Gigameter => Some((10_i128.pow(9), 1)),
+            MetricUnit::Terameter => Some((10_i128.pow(12), 1)),
+            MetricUnit::Petameter => Some((10_i128.pow(15), 1)),
+            MetricUnit::Exameter => Some((10_i128.pow(18), 1)),
+            MetricUnit::Zettameter => Some((10_i128.pow(21), 1)),
+            MetricUnit::Yottameter => Some((10_i128.pow(24), 1)),
+            MetricUnit::Ronnameter => Some((10_i128.pow(27), 1)),
+            MetricUnit::Quettameter => Some((10_i128.pow(30), 1)),
+        }
+    }
 }
 
 impl SiblingUnit for MetricUnit {
@@ -855,7 +4885,8 @@ impl SiblingUnit for MetricUnit {
             MetricUnit::Attometer => Some(Unit::Metric(MetricUnit::Zeptometer)),
             MetricUnit::Femtometer => Some(Unit::Metric(MetricUnit::Attometer)),
             MetricUnit::Picometer => Some(Unit::Metric(MetricUnit::Femtometer)),
-            MetricUnit::Nanometer => Some(Unit::Metric(MetricUnit::Picometer)),
+            MetricUnit::Angstrom => Some(Unit::Metric(MetricUnit::Picometer)),
+            MetricUnit::Nanometer => Some(Unit::Metric(MetricUnit::Angstrom)),
             MetricUnit::Micrometer => Some(Unit::Metric(MetricUnit::Nanometer)),
             MetricUnit::Millimeter => Some(Unit::Metric(MetricUnit::Micrometer)),
             MetricUnit::Centimeter => Some(Unit::Metric(MetricUnit::Millimeter)),
@@ -871,8 +4902,8 @@ impl SiblingUnit for MetricUnit {
             MetricUnit::Exameter => Some(Unit::Metric(MetricUnit::Petameter)),
             MetricUnit::Zettameter => Some(Unit::Metric(MetricUnit::Exameter)),
             MetricUnit::Yottameter => Some(Unit::Metric(MetricUnit::Zettameter)),
-            MetricUnit::Ronnameter => Some(Unit::Metric(MetricUnit::Ronnameter)),
-            MetricUnit::Quettameter => Some(Unit::Metric(MetricUnit::Quettameter)),
+            MetricUnit::Ronnameter => Some(Unit::Metric(MetricUnit::Yottameter)),
+            MetricUnit::Quettameter => Some(Unit::Metric(MetricUnit::Ronnameter)),
         }
     }
 
@@ -884,7 +4915,8 @@ impl SiblingUnit for MetricUnit {
             MetricUnit::Zeptometer => Some(Unit::Metric(MetricUnit::Attometer)),
             MetricUnit::Attometer => Some(Unit::Metric(MetricUnit::Femtometer)),
             MetricUnit::Femtometer => Some(Unit::Metric(MetricUnit::Picometer)),
-            MetricUnit::Picometer => Some(Unit::Metric(MetricUnit::Nanometer)),
+            MetricUnit::Picometer => Some(Unit::Metric(MetricUnit::Angstrom)),
+            MetricUnit::Angstrom => Some(Unit::Metric(MetricUnit::Nanometer)),
             MetricUnit::Nanometer => Some(Unit::Metric(MetricUnit::Micrometer)),
             MetricUnit::Micrometer => Some(Unit::Metric(MetricUnit::Millimeter)),
             MetricUnit::Millimeter => Some(Unit::Metric(MetricUnit::Centimeter)),
@@ -907,50 +4939,479 @@ impl SiblingUnit for MetricUnit {
     }
 }
 
-impl ToString for MetricUnit {
-    fn to_string(&self) -> String {
+impl fmt::Display for MetricUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.symbol())
+    }
+}
+
+/// The natural units of the length in physics.
+///
+/// The Planck length and the Bohr radius are the CODATA 2022 recommended values.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub enum SubatomicUnit {
+    PlanckLength,
+    BohrRadius,
+}
+
+impl SubatomicUnit {
+    /// Gets the amount of meters in one of this unit. Unlike Unit::meters_per_unit(), it can be
+    /// evaluated in const contexts.
+    ///
+    /// # Example
+    /// ```
+    /// use length::SubatomicUnit::*;
+    ///
+    /// const FACTOR: f64 = PlanckLength.meters_per_unit();
+    ///
+    /// assert_eq!(1.616_255e-35, FACTOR);
+    /// ```
+    pub const fn meters_per_unit(&self) -> f64 {
+        match self {
+            SubatomicUnit::PlanckLength => Length::PLANCK_LENGTH_TO_METER_FACTOR,
+            SubatomicUnit::BohrRadius => Length::BOHR_RADIUS_TO_METER_FACTOR,
+        }
+    }
+
+    /// Gets the symbol of this unit, like "a0".
+    ///
+    /// # Example
+    /// ```
+    /// use length::SubatomicUnit::*;
+    ///
+    /// assert_eq!("a0", BohrRadius.symbol());
+    /// ```
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            PlanckLength => "lP",
+            BohrRadius => "a0",
+        }
+    }
+
+    /// Gets the English name of this unit in singular, like "Bohr radius".
+    ///
+    /// # Example
+    /// ```
+    /// use length::SubatomicUnit::*;
+    ///
+    /// assert_eq!("Bohr radius", BohrRadius.name());
+    /// ```
+    pub fn name(&self) -> &'static str {
+        Unit::from(*self)
+            .names()
+            .map_or("", |(singular, _)| singular)
+    }
+
+    /// Gets the English name of this unit in plural, like "Bohr radii".
+    ///
+    /// # Example
+    /// ```
+    /// use length::SubatomicUnit::*;
+    ///
+    /// assert_eq!("Bohr radii", BohrRadius.plural_name());
+    /// ```
+    pub fn plural_name(&self) -> &'static str {
+        Unit::from(*self).names().map_or("", |(_, plural)| plural)
+    }
+}
+
+impl SubatomicUnit {
+    /// Gets the amount of meters in one unit as numerator and denominator, if it is a rational
+    /// number.
+    const fn exact_meters_per_unit(&self) -> Option<(i128, i128)> {
+        match self {
+            SubatomicUnit::PlanckLength => None,
+            SubatomicUnit::BohrRadius => Some((529_177_210_544, 10_i128.pow(22))),
+        }
+    }
+}
+
+impl SiblingUnit for SubatomicUnit {
+    fn smaller_unit(&self) -> Option<Unit> {
+        match self {
+            SubatomicUnit::PlanckLength => None,
+            SubatomicUnit::BohrRadius => Some(Unit::Subatomic(SubatomicUnit::PlanckLength)),
+        }
+    }
+
+    fn greater_unit(&self) -> Option<Unit> {
+        match self {
+            SubatomicUnit::PlanckLength => Some(Unit::Subatomic(SubatomicUnit::BohrRadius)),
+            SubatomicUnit::BohrRadius => None,
+        }
+    }
+}
+
+impl fmt::Display for SubatomicUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.symbol())
+    }
+}
+
+/// The units, that are used in typography.
+///
+/// The point is the DTP (PostScript) point of 1/72 inch and the pica has 12 of them. The
+/// pixel is the CSS reference pixel of 1/96 inch, for other resolutions see
+/// Length::to_pixels(...). The scaled point is the smallest unit of TeX (1/65536 of a
+/// TeX point of 1/72.27 inch) and the Didot point and the cicero (12 Didot points) are
+/// the traditional European units.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub enum TypographicUnit {
+    ScaledPoint,
+    Pixel,
+    Point,
+    DidotPoint,
+    Pica,
+    Cicero,
+}
+
+impl TypographicUnit {
+    /// Gets the amount of meters in one of this unit. Unlike Unit::meters_per_unit(), it can be
+    /// evaluated in const contexts.
+    ///
+    /// # Example
+    /// ```
+    /// use length::TypographicUnit::*;
+    ///
+    /// const FACTOR: f64 = Pica.meters_per_unit();
+    ///
+    /// assert_eq!(0.025_4 / 6.0, FACTOR);
+    /// ```
+    pub const fn meters_per_unit(&self) -> f64 {
+        match self {
+            TypographicUnit::ScaledPoint => 0.025_4 / (72.27 * 65_536.0),
+            TypographicUnit::Pixel => 0.025_4 / Length::DEFAULT_DPI,
+            TypographicUnit::Point => Length::POINT_TO_METER_FACTOR,
+            TypographicUnit::DidotPoint => Length::DIDOT_POINT_TO_METER_FACTOR,
+            TypographicUnit::Pica => 0.025_4 / 6.0,
+            TypographicUnit::Cicero => Length::DIDOT_POINT_TO_METER_FACTOR * 12.0,
+        }
+    }
+
+    /// Gets the symbol of this unit, like "pt".
+    ///
+    /// # Example
+    /// ```
+    /// use length::TypographicUnit::*;
+    ///
+    /// assert_eq!("pt", Point.symbol());
+    /// ```
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            ScaledPoint => "sp",
+            Pixel => "px",
+            Point => "pt",
+            DidotPoint => "dd",
+            Pica => "pica",
+            Cicero => "cc",
+        }
+    }
+
+    /// Gets the English name of this unit in singular, like "point".
+    ///
+    /// # Example
+    /// ```
+    /// use length::TypographicUnit::*;
+    ///
+    /// assert_eq!("point", Point.name());
+    /// ```
+    pub fn name(&self) -> &'static str {
+        Unit::from(*self)
+            .names()
+            .map_or("", |(singular, _)| singular)
+    }
+
+    /// Gets the English name of this unit in plural, like "points".
+    ///
+    /// # Example
+    /// ```
+    /// use length::TypographicUnit::*;
+    ///
+    /// assert_eq!("points", Point.plural_name());
+    /// ```
+    pub fn plural_name(&self) -> &'static str {
+        Unit::from(*self).names().map_or("", |(_, plural)| plural)
+    }
+}
+
+impl TypographicUnit {
+    /// Gets the amount of meters in one unit as numerator and denominator, if it is a rational
+    /// number.
+    const fn exact_meters_per_unit(&self) -> Option<(i128, i128)> {
+        match self {
+            TypographicUnit::ScaledPoint => Some((25_400, 10_000 * 7_227 * 65_536)),
+            TypographicUnit::Pixel => Some((254, 960_000)),
+            TypographicUnit::Point => Some((254, 720_000)),
+            TypographicUnit::DidotPoint => Some((376_065, 1_000_000_000)),
+            TypographicUnit::Pica => Some((254, 60_000)),
+            TypographicUnit::Cicero => Some((376_065 * 12, 1_000_000_000)),
+        }
+    }
+}
+
+impl SiblingUnit for TypographicUnit {
+    fn smaller_unit(&self) -> Option<Unit> {
+        match self {
+            TypographicUnit::ScaledPoint => None,
+            TypographicUnit::Pixel => Some(Unit::Typographic(TypographicUnit::ScaledPoint)),
+            TypographicUnit::Point => Some(Unit::Typographic(TypographicUnit::Pixel)),
+            TypographicUnit::DidotPoint => Some(Unit::Typographic(TypographicUnit::Point)),
+            TypographicUnit::Pica => Some(Unit::Typographic(TypographicUnit::DidotPoint)),
+            TypographicUnit::Cicero => Some(Unit::Typographic(TypographicUnit::Pica)),
+        }
+    }
+
+    fn greater_unit(&self) -> Option<Unit> {
+        match self {
+            TypographicUnit::ScaledPoint => Some(Unit::Typographic(TypographicUnit::Pixel)),
+            TypographicUnit::Pixel => Some(Unit::Typographic(TypographicUnit::Point)),
+            TypographicUnit::Point => Some(Unit::Typographic(TypographicUnit::DidotPoint)),
+            TypographicUnit::DidotPoint => Some(Unit::Typographic(TypographicUnit::Pica)),
+            TypographicUnit::Pica => Some(Unit::Typographic(TypographicUnit::Cicero)),
+            TypographicUnit::Cicero => None,
+        }
+    }
+}
+
+impl fmt::Display for TypographicUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.symbol())
+    }
+}
+
+/// The units of the US survey system.
+///
+/// The US survey foot is defined as 1200/3937 meter and differs from the international
+/// foot by about 2 parts per million. It is still found in legacy US geodetic and land
+/// survey data, the chain and the mile of this system are based on it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub enum UsSurveyUnit {
+    SurveyFoot,
+    SurveyChain,
+    SurveyMile,
+}
+
+impl UsSurveyUnit {
+    /// Gets the amount of meters in one of this unit. Unlike Unit::meters_per_unit(), it can be
+    /// evaluated in const contexts.
+    ///
+    /// # Example
+    /// ```
+    /// use length::UsSurveyUnit::*;
+    ///
+    /// const FACTOR: f64 = SurveyFoot.meters_per_unit();
+    ///
+    /// assert_eq!(1_200.0 / 3_937.0, FACTOR);
+    /// ```
+    pub const fn meters_per_unit(&self) -> f64 {
+        match self {
+            UsSurveyUnit::SurveyFoot => 1_200.0 / 3_937.0,
+            UsSurveyUnit::SurveyChain => 79_200.0 / 3_937.0,
+            UsSurveyUnit::SurveyMile => 6_336_000.0 / 3_937.0,
+        }
+    }
+
+    /// Gets the symbol of this unit, like "miUS".
+    ///
+    /// # Example
+    /// ```
+    /// use length::UsSurveyUnit::*;
+    ///
+    /// assert_eq!("miUS", SurveyMile.symbol());
+    /// ```
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            SurveyFoot => "ftUS",
+            SurveyChain => "chUS",
+            SurveyMile => "miUS",
+        }
+    }
+
+    /// Gets the English name of this unit in singular, like "survey mile".
+    ///
+    /// # Example
+    /// ```
+    /// use length::UsSurveyUnit::*;
+    ///
+    /// assert_eq!("survey mile", SurveyMile.name());
+    /// ```
+    pub fn name(&self) -> &'static str {
+        Unit::from(*self)
+            .names()
+            .map_or("", |(singular, _)| singular)
+    }
+
+    /// Gets the English name of this unit in plural, like "survey miles".
+    ///
+    /// # Example
+    /// ```
+    /// use length::UsSurveyUnit::*;
+    ///
+    /// assert_eq!("survey miles", SurveyMile.plural_name());
+    /// ```
+    pub fn plural_name(&self) -> &'static str {
+        Unit::from(*self).names().map_or("", |(_, plural)| plural)
+    }
+}
+
+impl UsSurveyUnit {
+    /// Gets the amount of meters in one unit as numerator and denominator, if it is a rational
+    /// number.
+    const fn exact_meters_per_unit(&self) -> Option<(i128, i128)> {
+        match self {
+            UsSurveyUnit::SurveyFoot => Some((1_200, 3_937)),
+            UsSurveyUnit::SurveyChain => Some((79_200, 3_937)),
+            UsSurveyUnit::SurveyMile => Some((6_336_000, 3_937)),
+        }
+    }
+}
+
+impl SiblingUnit for UsSurveyUnit {
+    fn smaller_unit(&self) -> Option<Unit> {
+        match self {
+            UsSurveyUnit::SurveyFoot => None,
+            UsSurveyUnit::SurveyChain => Some(Unit::UsSurvey(UsSurveyUnit::SurveyFoot)),
+            UsSurveyUnit::SurveyMile => Some(Unit::UsSurvey(UsSurveyUnit::SurveyChain)),
+        }
+    }
+
+    fn greater_unit(&self) -> Option<Unit> {
         match self {
-            Quectometer => String::from("qm"),
-            Rontometer => String::from("rm"),
-            Yoctometer => String::from("ym"),
-            Zeptometer => String::from("zm"),
-            Attometer => String::from("am"),
-            Femtometer => String::from("fm"),
-            Picometer => String::from("pm"),
-            Nanometer => String::from("nm"),
-            Micrometer => String::from("µm"),
-            Millimeter => String::from("mm"),
-            Centimeter => String::from("cm"),
-            Decimeter => String::from("dm"),
-            Meter => String::from("m"),
-            Decameter => String::from("dam"),
-            Hectometer => String::from("hm"),
-            Kilometer => String::from("km"),
-            Megameter => String::from("Mm"),
-            Gigameter => String::from("Gm"),
-            Terameter => String::from("Tm"),
-            Petameter => String::from("Pm"),
-            Exameter => String::from("Em"),
-            Zettameter => String::from("Zm"),
-            Yottameter => String::from("Ym"),
-            Ronnameter => String::from("Rm"),
-            Quettameter => String::from("Qm"),
+            UsSurveyUnit::SurveyFoot => Some(Unit::UsSurvey(UsSurveyUnit::SurveyChain)),
+            UsSurveyUnit::SurveyChain => Some(Unit::UsSurvey(UsSurveyUnit::SurveyMile)),
+            UsSurveyUnit::SurveyMile => None,
         }
     }
 }
 
+impl fmt::Display for UsSurveyUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.symbol())
+    }
+}
+
 impl Hash for Unit {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.to_string().hash(state);
     }
 }
 
-impl ToString for Unit {
-    fn to_string(&self) -> String {
+impl Ord for Unit {
+    /// Orders the units by their size in meters, also across unit systems. Units of the same size
+    /// are ordered by their code, so only equal units compare as equal.
+    ///
+    /// As the size of a custom unit changes, when its symbol is registered again, the order of
+    /// custom units may change, too.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.meters_per_unit()
+            .total_cmp(&other.meters_per_unit())
+            .then_with(|| self.code().cmp(&other.code()))
+    }
+}
+
+impl PartialOrd for Unit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AstronomicUnit {
+    /// Orders the units by their size in meters.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.meters_per_unit().total_cmp(&other.meters_per_unit())
+    }
+}
+
+impl PartialOrd for AstronomicUnit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ImperialUnit {
+    /// Orders the units by their size in meters.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.meters_per_unit().total_cmp(&other.meters_per_unit())
+    }
+}
+
+impl PartialOrd for ImperialUnit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MetricUnit {
+    /// Orders the units by their size in meters.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.meters_per_unit().total_cmp(&other.meters_per_unit())
+    }
+}
+
+impl PartialOrd for MetricUnit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SubatomicUnit {
+    /// Orders the units by their size in meters.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.meters_per_unit().total_cmp(&other.meters_per_unit())
+    }
+}
+
+impl PartialOrd for SubatomicUnit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TypographicUnit {
+    /// Orders the units by their size in meters.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.meters_per_unit().total_cmp(&other.meters_per_unit())
+    }
+}
+
+impl PartialOrd for TypographicUnit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UsSurveyUnit {
+    /// Orders the units by their size in meters.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.meters_per_unit().total_cmp(&other.meters_per_unit())
+    }
+}
+
+impl PartialOrd for UsSurveyUnit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Unit::Astronomic(astronomic_unit) => astronomic_unit.to_string(),
-            Unit::Imperial(imperial_unit) => imperial_unit.to_string(),
-            Unit::Metric(metric_unit) => metric_unit.to_string(),
+            Unit::Astronomic(astronomic_unit) => astronomic_unit.fmt(f),
+            Unit::Custom(custom_unit) => custom_unit.fmt(f),
+            Unit::Imperial(imperial_unit) => imperial_unit.fmt(f),
+            Unit::Metric(metric_unit) => metric_unit.fmt(f),
+            Unit::Subatomic(subatomic_unit) => subatomic_unit.fmt(f),
+            Unit::Traditional(traditional_unit) => traditional_unit.fmt(f),
+            #[cfg(feature = "historical")]
+            Unit::Historical(historical_unit) => historical_unit.fmt(f),
+            Unit::Typographic(typographic_unit) => typographic_unit.fmt(f),
+            Unit::UsSurvey(us_survey_unit) => us_survey_unit.fmt(f),
         }
     }
 }
@@ -0,0 +1,237 @@
+//! Parsing of length literals at compile time, for the length! macro.
+//!
+//! Only the plain form "<number> <unit>" is supported here, because the parser has to be a const
+//! fn. The unit may be a symbol or an English name, custom units are not known at compile time.
+
+use crate::{Length, Unit, UNIT_NAMES};
+
+/// The exactly representable powers of ten, 10^22 being the greatest one.
+const POWERS_OF_TEN: [f64; 23] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+];
+
+/// The greatest integer, up to which every integer is exactly representable as f64.
+const MAX_EXACT_INTEGER: u64 = 1 << 53;
+
+/// Creates a Length from a string literal like "2.5 km", that is parsed at compile time.
+///
+/// An unknown unit or a malformed number fails the build. The literal has to be of the form
+/// "<number> <unit>", the number may have a sign and an exponent, but at most 15 significant
+/// digits. Compound lengths, fractions and custom units need Length::parse(...) at runtime.
+///
+/// # Example
+/// ```
+/// use length::{length, Length, Unit, ImperialUnit::*, MetricUnit::*};
+///
+/// const MAX_RANGE: Length = length!("2.5 km");
+///
+/// assert_eq!(2.5, MAX_RANGE.value);
+/// assert_eq!(Unit::Metric(Kilometer), MAX_RANGE.unit);
+/// assert_eq!(Unit::Imperial(Foot), length!("-3 feet").unit);
+/// ```
+///
+/// ```compile_fail
+/// let length = length::length!("2.5 kmx");
+/// ```
+#[macro_export]
+macro_rules! length {
+    ($string:literal) => {
+        const {
+            $crate::Length::const_parse($string)
+                .expect(concat!("invalid length literal: ", $string))
+        }
+    };
+}
+
+impl Length {
+    /// Parses a string of the form "<number> <unit>" in const contexts, which is what the
+    /// length! macro is built on.
+    ///
+    /// Returns None for everything else, that Length::parse(...) accepts, like compound lengths,
+    /// fractions and custom units, and for numbers with more than 15 significant digits or with
+    /// a decimal exponent beyond ±22, that can not be converted exactly in a const fn.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, AstronomicUnit::*};
+    ///
+    /// const DISTANCE: Option<Length> = Length::const_parse("4.2 light years");
+    ///
+    /// assert_eq!(4.2, DISTANCE.unwrap().value);
+    /// assert_eq!(Unit::Astronomic(Lightyear), DISTANCE.unwrap().unit);
+    /// assert!(Length::const_parse("6 ft 2 in").is_none());
+    /// ```
+    pub const fn const_parse(string: &str) -> Option<Self> {
+        let bytes = string.trim_ascii().as_bytes();
+        let mut index = 0;
+
+        let negative = !bytes.is_empty() && bytes[0] == b'-';
+        if !bytes.is_empty() && (bytes[0] == b'-' || bytes[0] == b'+') {
+            index += 1;
+        }
+
+        let mut mantissa: u64 = 0;
+        let mut exponent: i32 = 0;
+        let integer_start = index;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            mantissa = match push_digit(mantissa, bytes[index]) {
+                Some(mantissa) => mantissa,
+                None => return None,
+            };
+            index += 1;
+        }
+        if index == integer_start {
+            return None;
+        }
+
+        if index + 1 < bytes.len() && bytes[index] == b'.' && bytes[index + 1].is_ascii_digit() {
+            index += 1;
+            while index < bytes.len() && bytes[index].is_ascii_digit() {
+                mantissa = match push_digit(mantissa, bytes[index]) {
+                    Some(mantissa) => mantissa,
+                    None => return None,
+                };
+                exponent -= 1;
+                index += 1;
+            }
+        }
+
+        if let Some((explicit_exponent, end)) = scan_exponent(bytes, index) {
+            exponent += explicit_exponent;
+            index = end;
+        }
+
+        while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+            index += 1;
+        }
+        let (_, unit_bytes) = bytes.split_at(index);
+        if unit_bytes.is_empty() || unit_bytes[0].is_ascii_digit() || is_number_char(unit_bytes[0])
+        {
+            return None;
+        }
+        let unit = match unit_from_literal(unit_bytes) {
+            Some(unit) => unit,
+            None => return None,
+        };
+
+        let magnitude = if mantissa == 0 {
+            0.0
+        } else if exponent < 0 && exponent >= -22 {
+            mantissa as f64 / POWERS_OF_TEN[-exponent as usize]
+        } else if exponent >= 0 && exponent <= 22 {
+            mantissa as f64 * POWERS_OF_TEN[exponent as usize]
+        } else {
+            return None;
+        };
+
+        Some(Length::const_new(
+            if negative { -magnitude } else { magnitude },
+            unit,
+        ))
+    }
+}
+
+/// Appends a decimal digit to the mantissa, as long as it stays exactly representable.
+const fn push_digit(mantissa: u64, digit: u8) -> Option<u64> {
+    let mantissa = mantissa * 10 + (digit - b'0') as u64;
+    if mantissa > MAX_EXACT_INTEGER {
+        None
+    } else {
+        Some(mantissa)
+    }
+}
+
+/// Scans an exponent like "e3" or "E-6" at the given index and returns it with its end.
+const fn scan_exponent(bytes: &[u8], start: usize) -> Option<(i32, usize)> {
+    if start >= bytes.len() || (bytes[start] != b'e' && bytes[start] != b'E') {
+        return None;
+    }
+    let mut index = start + 1;
+    let negative = index < bytes.len() && bytes[index] == b'-';
+    if index < bytes.len() && (bytes[index] == b'-' || bytes[index] == b'+') {
+        index += 1;
+    }
+
+    let digits_start = index;
+    let mut exponent: i32 = 0;
+    while index < bytes.len() && bytes[index].is_ascii_digit() {
+        exponent = exponent
+            .saturating_mul(10)
+            .saturating_add((bytes[index] - b'0') as i32);
+        index += 1;
+    }
+    if index == digits_start {
+        return None;
+    }
+
+    Some((if negative { -exponent } else { exponent }, index))
+}
+
+/// Checks, if the byte is a part of a number, that can not start a unit symbol.
+const fn is_number_char(byte: u8) -> bool {
+    byte == b'.' || byte == b',' || byte == b'+' || byte == b'-'
+}
+
+/// Gets the unit with the given symbol or singular or plural name.
+const fn unit_from_literal(unit: &[u8]) -> Option<Unit> {
+    if let Ok(symbol) = std::str::from_utf8(unit) {
+        if let Some(unit) = Unit::from_symbol(symbol) {
+            return Some(unit);
+        }
+    }
+
+    let mut index = 0;
+    while index < UNIT_NAMES.len() {
+        let (unit_of_name, singular, plural) = UNIT_NAMES[index];
+        if name_matches(unit, singular.as_bytes()) || name_matches(unit, plural.as_bytes()) {
+            return Some(unit_of_name);
+        }
+        index += 1;
+    }
+
+    None
+}
+
+/// Compares a unit name like Unit::from_name(...) does, ignoring the ASCII case, whitespace and
+/// hyphens and accepting "metre" for "meter".
+const fn name_matches(input: &[u8], name: &[u8]) -> bool {
+    let (mut i, mut j) = (0, 0);
+    loop {
+        while i < input.len() && (input[i].is_ascii_whitespace() || input[i] == b'-') {
+            i += 1;
+        }
+        while j < name.len() && (name[j].is_ascii_whitespace() || name[j] == b'-') {
+            j += 1;
+        }
+        if i == input.len() || j == name.len() {
+            return i == input.len() && j == name.len();
+        }
+
+        if starts_with_ignore_case(name, j, b"meter") && starts_with_ignore_case(input, i, b"metre")
+        {
+            i += 5;
+            j += 5;
+        } else if input[i].eq_ignore_ascii_case(&name[j]) {
+            i += 1;
+            j += 1;
+        } else {
+            return false;
+        }
+    }
+}
+
+const fn starts_with_ignore_case(bytes: &[u8], start: usize, prefix: &[u8]) -> bool {
+    if bytes.len() - start < prefix.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < prefix.len() {
+        if !bytes[start + index].eq_ignore_ascii_case(&prefix[index]) {
+            return false;
+        }
+        index += 1;
+    }
+
+    true
+}
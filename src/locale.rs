@@ -0,0 +1,724 @@
+//! Localized unit names, like "2,5 Kilometer" in German or "3 pouces" in French.
+//!
+//! Only the full names and the decimal separator are localized, the unit symbols are the same in
+//! every locale.
+
+use crate::{
+    AstronomicUnit, ImperialUnit, Length, LengthParser, MetricUnit, ParseLengthError,
+    SubatomicUnit, TraditionalUnit, TypographicUnit, Unit, UsSurveyUnit,
+};
+
+#[cfg(feature = "historical")]
+use crate::HistoricalUnit;
+
+/// A language, in which lengths are formatted and parsed with full unit names.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Locale {
+    English,
+    German,
+    French,
+    Spanish,
+}
+
+impl Locale {
+    /// Gets the singular name of the unit in this language, custom units have their symbol.
+    ///
+    /// # Example
+    /// ```
+    /// use length::locale::Locale;
+    /// use length::{Unit, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// assert_eq!("Zoll", Locale::German.unit_name(Unit::Imperial(Inch)));
+    /// assert_eq!("kilomètre", Locale::French.unit_name(Unit::Metric(Kilometer)));
+    /// assert_eq!("milla", Locale::Spanish.unit_name(Unit::Imperial(Mile)));
+    /// ```
+    pub fn unit_name(&self, unit: Unit) -> String {
+        match self.names(unit) {
+            Some((singular, _)) => singular.to_string(),
+            None => unit.name(),
+        }
+    }
+
+    /// Gets the plural name of the unit in this language, custom units have their symbol.
+    ///
+    /// # Example
+    /// ```
+    /// use length::locale::Locale;
+    /// use length::{Unit, ImperialUnit::*};
+    ///
+    /// assert_eq!("Meilen", Locale::German.unit_plural_name(Unit::Imperial(Mile)));
+    /// assert_eq!("pouces", Locale::French.unit_plural_name(Unit::Imperial(Inch)));
+    /// ```
+    pub fn unit_plural_name(&self, unit: Unit) -> String {
+        match self.names(unit) {
+            Some((_, plural)) => plural.to_string(),
+            None => unit.plural_name(),
+        }
+    }
+
+    /// Gets the singular and plural name of a built-in unit, None for English and custom units.
+    fn names(&self, unit: Unit) -> Option<(&'static str, &'static str)> {
+        let language = match self {
+            Locale::English => return None,
+            Locale::German => 0,
+            Locale::French => 1,
+            Locale::Spanish => 2,
+        };
+
+        LOCALIZED_NAMES
+            .iter()
+            .find(|(unit_of_names, _)| *unit_of_names == unit)
+            .map(|(_, names)| names[language])
+    }
+
+    /// Checks, if the singular name is used for the value, which is the case for 1 and -1, but
+    /// in French for all values below 2.
+    fn is_singular(&self, value: f64) -> bool {
+        match self {
+            Locale::French => value.abs() < 2.0,
+            _ => value.abs() == 1.0,
+        }
+    }
+
+    fn decimal_comma(&self) -> bool {
+        *self != Locale::English
+    }
+}
+
+impl Length {
+    /// Formats the length with the full unit name and the decimal separator of the locale.
+    ///
+    /// # Example
+    /// ```
+    /// use length::locale::Locale;
+    /// use length::{Length, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// assert_eq!("2,5 Kilometer", Length::new_value_unit(2.5, Kilometer).format_localized(Locale::German));
+    /// assert_eq!("1,5 pouce", Length::new_value_unit(1.5, Inch).format_localized(Locale::French));
+    /// assert_eq!("3 millas", Length::new_value_unit(3, Mile).format_localized(Locale::Spanish));
+    /// assert_eq!("1 foot", Length::new_value_unit(1, Foot).format_localized(Locale::English));
+    /// ```
+    pub fn format_localized(&self, locale: Locale) -> String {
+        let mut value = self.value.to_string();
+        if locale.decimal_comma() {
+            value = value.replace('.', ",");
+        }
+        let name = if locale.is_singular(self.value) {
+            locale.unit_name(self.unit)
+        } else {
+            locale.unit_plural_name(self.unit)
+        };
+
+        format!("{} {}", value, name)
+    }
+
+    /// Parses a length with a full unit name in the language of the locale, ignoring its case, or
+    /// with a unit symbol. The decimal separator is a comma in all locales but English.
+    ///
+    /// # Example
+    /// ```
+    /// use length::locale::Locale;
+    /// use length::{Length, Unit, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// let length = Length::parse_localized("2,5 Kilometer", Locale::German).unwrap();
+    ///
+    /// assert_eq!(2.5, length.value);
+    /// assert_eq!(Unit::Metric(Kilometer), length.unit);
+    /// assert_eq!(Unit::Imperial(Inch), Length::parse_localized("12 pouces", Locale::French).unwrap().unit);
+    /// assert_eq!(Unit::Imperial(Mile), Length::parse_localized("3 mi", Locale::Spanish).unwrap().unit);
+    /// ```
+    pub fn parse_localized(string: &str, locale: Locale) -> Result<Length, ParseLengthError> {
+        let parser = LengthParser::new().decimal_comma(locale.decimal_comma());
+        let trimmed = string.trim_end();
+
+        let longest_name = LOCALIZED_NAMES
+            .iter()
+            .filter_map(|(unit, _)| locale.names(*unit).map(|names| (*unit, names)))
+            .flat_map(|(unit, (singular, plural))| [(unit, singular), (unit, plural)])
+            .filter_map(|(unit, name)| Some((unit, name_start(trimmed, name)?)))
+            .min_by_key(|(_, start)| *start);
+
+        match longest_name {
+            Some((unit, start)) => parser.parse(&format!("{} {}", &trimmed[..start], unit.name())),
+            None => parser.parse(string),
+        }
+    }
+}
+
+/// Gets the start of the name at the end of the string, ignoring the case, if the name follows
+/// a whitespace or a digit.
+fn name_start(string: &str, name: &str) -> Option<usize> {
+    let mut chars = string.char_indices().rev();
+    let mut start = string.len();
+    for name_char in name.chars().rev() {
+        let (index, c) = chars.next()?;
+        if !c.to_lowercase().eq(name_char.to_lowercase()) {
+            return None;
+        }
+        start = index;
+    }
+
+    chars
+        .next()
+        .is_some_and(|(_, c)| c.is_whitespace() || c.is_ascii_digit())
+        .then_some(start)
+}
+
+/// The singular and plural names of all built-in units in German, French and Spanish.
+const LOCALIZED_NAMES: &[(Unit, [(&str, &str); 3])] = &[
+    (
+        Unit::Astronomic(AstronomicUnit::EarthRadius),
+        [
+            ("Erdradius", "Erdradien"),
+            ("rayon terrestre", "rayons terrestres"),
+            ("radio terrestre", "radios terrestres"),
+        ],
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Lightsecond),
+        [
+            ("Lichtsekunde", "Lichtsekunden"),
+            ("seconde-lumière", "secondes-lumière"),
+            ("segundo luz", "segundos luz"),
+        ],
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::LunarDistance),
+        [
+            ("Monddistanz", "Monddistanzen"),
+            ("distance lunaire", "distances lunaires"),
+            ("distancia lunar", "distancias lunares"),
+        ],
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::SolarRadius),
+        [
+            ("Sonnenradius", "Sonnenradien"),
+            ("rayon solaire", "rayons solaires"),
+            ("radio solar", "radios solares"),
+        ],
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Lightminute),
+        [
+            ("Lichtminute", "Lichtminuten"),
+            ("minute-lumière", "minutes-lumière"),
+            ("minuto luz", "minutos luz"),
+        ],
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::AstronomicalUnit),
+        [
+            ("Astronomische Einheit", "Astronomische Einheiten"),
+            ("unité astronomique", "unités astronomiques"),
+            ("unidad astronómica", "unidades astronómicas"),
+        ],
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Lighthour),
+        [
+            ("Lichtstunde", "Lichtstunden"),
+            ("heure-lumière", "heures-lumière"),
+            ("hora luz", "horas luz"),
+        ],
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Lightday),
+        [
+            ("Lichttag", "Lichttage"),
+            ("jour-lumière", "jours-lumière"),
+            ("día luz", "días luz"),
+        ],
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Lightyear),
+        [
+            ("Lichtjahr", "Lichtjahre"),
+            ("année-lumière", "années-lumière"),
+            ("año luz", "años luz"),
+        ],
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Parsec),
+        [
+            ("Parsec", "Parsec"),
+            ("parsec", "parsecs"),
+            ("pársec", "pársecs"),
+        ],
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Kiloparsec),
+        [
+            ("Kiloparsec", "Kiloparsec"),
+            ("kiloparsec", "kiloparsecs"),
+            ("kilopársec", "kilopársecs"),
+        ],
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Megaparsec),
+        [
+            ("Megaparsec", "Megaparsec"),
+            ("mégaparsec", "mégaparsecs"),
+            ("megapársec", "megapársecs"),
+        ],
+    ),
+    (
+        Unit::Astronomic(AstronomicUnit::Gigaparsec),
+        [
+            ("Gigaparsec", "Gigaparsec"),
+            ("gigaparsec", "gigaparsecs"),
+            ("gigapársec", "gigapársecs"),
+        ],
+    ),
+    (
+        Unit::Imperial(ImperialUnit::Thou),
+        [("Thou", "Thou"), ("thou", "thous"), ("thou", "thous")],
+    ),
+    (
+        Unit::Imperial(ImperialUnit::Inch),
+        [
+            ("Zoll", "Zoll"),
+            ("pouce", "pouces"),
+            ("pulgada", "pulgadas"),
+        ],
+    ),
+    (
+        Unit::Imperial(ImperialUnit::Hand),
+        [("Hand", "Hand"), ("paume", "paumes"), ("palmo", "palmos")],
+    ),
+    (
+        Unit::Imperial(ImperialUnit::Foot),
+        [("Fuß", "Fuß"), ("pied", "pieds"), ("pie", "pies")],
+    ),
+    (
+        Unit::Imperial(ImperialUnit::Yard),
+        [("Yard", "Yards"), ("yard", "yards"), ("yarda", "yardas")],
+    ),
+    (
+        Unit::Imperial(ImperialUnit::Rod),
+        [
+            ("Rute", "Ruten"),
+            ("perche", "perches"),
+            ("pértiga", "pértigas"),
+        ],
+    ),
+    (
+        Unit::Imperial(ImperialUnit::Chain),
+        [
+            ("Chain", "Chains"),
+            ("chaîne", "chaînes"),
+            ("cadena", "cadenas"),
+        ],
+    ),
+    (
+        Unit::Imperial(ImperialUnit::Furlong),
+        [
+            ("Furlong", "Furlongs"),
+            ("furlong", "furlongs"),
+            ("estadio", "estadios"),
+        ],
+    ),
+    (
+        Unit::Imperial(ImperialUnit::Mile),
+        [
+            ("Meile", "Meilen"),
+            ("mille", "milles"),
+            ("milla", "millas"),
+        ],
+    ),
+    (
+        Unit::Imperial(ImperialUnit::League),
+        [
+            ("League", "Leagues"),
+            ("lieue", "lieues"),
+            ("legua", "leguas"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Quectometer),
+        [
+            ("Quektometer", "Quektometer"),
+            ("quectomètre", "quectomètres"),
+            ("quectómetro", "quectómetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Rontometer),
+        [
+            ("Rontometer", "Rontometer"),
+            ("rontomètre", "rontomètres"),
+            ("rontómetro", "rontómetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Yoctometer),
+        [
+            ("Yoktometer", "Yoktometer"),
+            ("yoctomètre", "yoctomètres"),
+            ("yoctómetro", "yoctómetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Zeptometer),
+        [
+            ("Zeptometer", "Zeptometer"),
+            ("zeptomètre", "zeptomètres"),
+            ("zeptómetro", "zeptómetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Attometer),
+        [
+            ("Attometer", "Attometer"),
+            ("attomètre", "attomètres"),
+            ("attómetro", "attómetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Femtometer),
+        [
+            ("Femtometer", "Femtometer"),
+            ("femtomètre", "femtomètres"),
+            ("femtómetro", "femtómetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Picometer),
+        [
+            ("Pikometer", "Pikometer"),
+            ("picomètre", "picomètres"),
+            ("picómetro", "picómetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Angstrom),
+        [
+            ("Ångström", "Ångström"),
+            ("ångström", "ångströms"),
+            ("ángstrom", "ángstroms"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Nanometer),
+        [
+            ("Nanometer", "Nanometer"),
+            ("nanomètre", "nanomètres"),
+            ("nanómetro", "nanómetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Micrometer),
+        [
+            ("Mikrometer", "Mikrometer"),
+            ("micromètre", "micromètres"),
+            ("micrómetro", "micrómetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Millimeter),
+        [
+            ("Millimeter", "Millimeter"),
+            ("millimètre", "millimètres"),
+            ("milímetro", "milímetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Centimeter),
+        [
+            ("Zentimeter", "Zentimeter"),
+            ("centimètre", "centimètres"),
+            ("centímetro", "centímetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Decimeter),
+        [
+            ("Dezimeter", "Dezimeter"),
+            ("décimètre", "décimètres"),
+            ("decímetro", "decímetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Meter),
+        [("Meter", "Meter"), ("mètre", "mètres"), ("metro", "metros")],
+    ),
+    (
+        Unit::Metric(MetricUnit::Decameter),
+        [
+            ("Dekameter", "Dekameter"),
+            ("décamètre", "décamètres"),
+            ("decámetro", "decámetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Hectometer),
+        [
+            ("Hektometer", "Hektometer"),
+            ("hectomètre", "hectomètres"),
+            ("hectómetro", "hectómetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Kilometer),
+        [
+            ("Kilometer", "Kilometer"),
+            ("kilomètre", "kilomètres"),
+            ("kilómetro", "kilómetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Megameter),
+        [
+            ("Megameter", "Megameter"),
+            ("mégamètre", "mégamètres"),
+            ("megámetro", "megámetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Gigameter),
+        [
+            ("Gigameter", "Gigameter"),
+            ("gigamètre", "gigamètres"),
+            ("gigámetro", "gigámetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Terameter),
+        [
+            ("Terameter", "Terameter"),
+            ("téramètre", "téramètres"),
+            ("terámetro", "terámetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Petameter),
+        [
+            ("Petameter", "Petameter"),
+            ("pétamètre", "pétamètres"),
+            ("petámetro", "petámetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Exameter),
+        [
+            ("Exameter", "Exameter"),
+            ("examètre", "examètres"),
+            ("exámetro", "exámetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Zettameter),
+        [
+            ("Zettameter", "Zettameter"),
+            ("zettamètre", "zettamètres"),
+            ("zettámetro", "zettámetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Yottameter),
+        [
+            ("Yottameter", "Yottameter"),
+            ("yottamètre", "yottamètres"),
+            ("yottámetro", "yottámetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Ronnameter),
+        [
+            ("Ronnameter", "Ronnameter"),
+            ("ronnamètre", "ronnamètres"),
+            ("ronnámetro", "ronnámetros"),
+        ],
+    ),
+    (
+        Unit::Metric(MetricUnit::Quettameter),
+        [
+            ("Quettameter", "Quettameter"),
+            ("quettamètre", "quettamètres"),
+            ("quettámetro", "quettámetros"),
+        ],
+    ),
+    (
+        Unit::Subatomic(SubatomicUnit::PlanckLength),
+        [
+            ("Planck-Länge", "Planck-Längen"),
+            ("longueur de Planck", "longueurs de Planck"),
+            ("longitud de Planck", "longitudes de Planck"),
+        ],
+    ),
+    (
+        Unit::Subatomic(SubatomicUnit::BohrRadius),
+        [
+            ("Bohrscher Radius", "Bohrsche Radien"),
+            ("rayon de Bohr", "rayons de Bohr"),
+            ("radio de Bohr", "radios de Bohr"),
+        ],
+    ),
+    (
+        Unit::Typographic(TypographicUnit::ScaledPoint),
+        [
+            ("skalierter Punkt", "skalierte Punkte"),
+            ("point échelonné", "points échelonnés"),
+            ("punto escalado", "puntos escalados"),
+        ],
+    ),
+    (
+        Unit::Typographic(TypographicUnit::Pixel),
+        [
+            ("Pixel", "Pixel"),
+            ("pixel", "pixels"),
+            ("píxel", "píxeles"),
+        ],
+    ),
+    (
+        Unit::Typographic(TypographicUnit::Point),
+        [
+            ("Punkt", "Punkte"),
+            ("point", "points"),
+            ("punto", "puntos"),
+        ],
+    ),
+    (
+        Unit::Typographic(TypographicUnit::DidotPoint),
+        [
+            ("Didot-Punkt", "Didot-Punkte"),
+            ("point Didot", "points Didot"),
+            ("punto Didot", "puntos Didot"),
+        ],
+    ),
+    (
+        Unit::Typographic(TypographicUnit::Pica),
+        [("Pica", "Pica"), ("pica", "picas"), ("pica", "picas")],
+    ),
+    (
+        Unit::Typographic(TypographicUnit::Cicero),
+        [
+            ("Cicero", "Cicero"),
+            ("cicéro", "cicéros"),
+            ("cícero", "cíceros"),
+        ],
+    ),
+    (
+        Unit::UsSurvey(UsSurveyUnit::SurveyFoot),
+        [
+            ("US-Survey-Fuß", "US-Survey-Fuß"),
+            ("pied d'arpentage américain", "pieds d'arpentage américains"),
+            (
+                "pie de agrimensura estadounidense",
+                "pies de agrimensura estadounidenses",
+            ),
+        ],
+    ),
+    (
+        Unit::UsSurvey(UsSurveyUnit::SurveyChain),
+        [
+            ("US-Survey-Chain", "US-Survey-Chains"),
+            (
+                "chaîne d'arpentage américaine",
+                "chaînes d'arpentage américaines",
+            ),
+            (
+                "cadena de agrimensura estadounidense",
+                "cadenas de agrimensura estadounidenses",
+            ),
+        ],
+    ),
+    (
+        Unit::UsSurvey(UsSurveyUnit::SurveyMile),
+        [
+            ("US-Survey-Meile", "US-Survey-Meilen"),
+            (
+                "mille d'arpentage américain",
+                "milles d'arpentage américains",
+            ),
+            (
+                "milla de agrimensura estadounidense",
+                "millas de agrimensura estadounidenses",
+            ),
+        ],
+    ),
+    (
+        Unit::Traditional(TraditionalUnit::Cun),
+        [("Cun", "Cun"), ("cun", "cun"), ("cun", "cun")],
+    ),
+    (
+        Unit::Traditional(TraditionalUnit::Chi),
+        [("Chi", "Chi"), ("chi", "chi"), ("chi", "chi")],
+    ),
+    (
+        Unit::Traditional(TraditionalUnit::Zhang),
+        [("Zhang", "Zhang"), ("zhang", "zhang"), ("zhang", "zhang")],
+    ),
+    (
+        Unit::Traditional(TraditionalUnit::Li),
+        [("Li", "Li"), ("li", "li"), ("li", "li")],
+    ),
+    (
+        Unit::Traditional(TraditionalUnit::Shaku),
+        [("Shaku", "Shaku"), ("shaku", "shaku"), ("shaku", "shaku")],
+    ),
+    (
+        Unit::Traditional(TraditionalUnit::Ken),
+        [("Ken", "Ken"), ("ken", "ken"), ("ken", "ken")],
+    ),
+    (
+        Unit::Traditional(TraditionalUnit::Ri),
+        [("Ri", "Ri"), ("ri", "ri"), ("ri", "ri")],
+    ),
+    (
+        Unit::Traditional(TraditionalUnit::Arshin),
+        [
+            ("Arschin", "Arschin"),
+            ("archine", "archines"),
+            ("arshín", "arshines"),
+        ],
+    ),
+    (
+        Unit::Traditional(TraditionalUnit::Verst),
+        [
+            ("Werst", "Werst"),
+            ("verste", "verstes"),
+            ("versta", "verstas"),
+        ],
+    ),
+    (
+        Unit::Traditional(TraditionalUnit::Mil),
+        [
+            ("Skandinavische Meile", "Skandinavische Meilen"),
+            ("mille scandinave", "milles scandinaves"),
+            ("milla escandinava", "millas escandinavas"),
+        ],
+    ),
+    #[cfg(feature = "historical")]
+    (
+        Unit::Historical(HistoricalUnit::Cubit),
+        [("Elle", "Ellen"), ("coudée", "coudées"), ("codo", "codos")],
+    ),
+    #[cfg(feature = "historical")]
+    (
+        Unit::Historical(HistoricalUnit::Smoot),
+        [
+            ("Smoot", "Smoots"),
+            ("smoot", "smoots"),
+            ("smoot", "smoots"),
+        ],
+    ),
+    #[cfg(feature = "historical")]
+    (
+        Unit::Historical(HistoricalUnit::Fathom),
+        [
+            ("Faden", "Faden"),
+            ("brasse", "brasses"),
+            ("braza", "brazas"),
+        ],
+    ),
+    #[cfg(feature = "historical")]
+    (
+        Unit::Historical(HistoricalUnit::FootballField),
+        [
+            ("Footballfeld", "Footballfelder"),
+            ("terrain de football", "terrains de football"),
+            ("campo de fútbol", "campos de fútbol"),
+        ],
+    ),
+];
@@ -0,0 +1,40 @@
+//! Conversions between Length and the length type of the measurements crate (feature `measurements`).
+
+use ::measurements::Length as MeasurementsLength;
+
+use crate::{Length, MetricUnit::Meter};
+
+impl From<Length> for MeasurementsLength {
+    /// Converts the length into a measurements length, that is stored in meters.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    /// use measurements::Length as MeasurementsLength;
+    ///
+    /// let length: MeasurementsLength = Length::new_string("1 mi").unwrap().into();
+    ///
+    /// assert!((length.as_feet() - 5_280.0).abs() < 1e-9);
+    /// ```
+    fn from(length: Length) -> MeasurementsLength {
+        MeasurementsLength::from_meters(length.convert(Meter).value)
+    }
+}
+
+impl From<MeasurementsLength> for Length {
+    /// Converts the measurements length into a Length in meters.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    /// use measurements::Length as MeasurementsLength;
+    ///
+    /// let length = Length::from(MeasurementsLength::from_kilometers(2.5));
+    ///
+    /// assert_eq!(2_500.0, length.value);
+    /// assert_eq!(Unit::Metric(Meter), length.unit);
+    /// ```
+    fn from(length: MeasurementsLength) -> Length {
+        Length::new_value_unit(length.as_meters(), Meter)
+    }
+}
@@ -0,0 +1,115 @@
+//! UniFFI bindings for Swift and Kotlin (feature `uniffi`).
+//!
+//! The bindings are generated from the compiled library with `uniffi-bindgen`,
+//! e.g. `uniffi-bindgen generate --library target/release/liblength.so --language kotlin`.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{Length, Unit};
+
+/// The errors, that can be raised by the bindings.
+#[derive(Debug, uniffi::Error)]
+pub enum MobileLengthError {
+    UnparsableLength { string: String },
+    UnknownUnit { unit: String },
+}
+
+impl fmt::Display for MobileLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MobileLengthError::UnparsableLength { string } => {
+                write!(f, "unable to parse length: {}", string)
+            }
+            MobileLengthError::UnknownUnit { unit } => write!(f, "unknown unit: {}", unit),
+        }
+    }
+}
+
+impl std::error::Error for MobileLengthError {}
+
+fn parse_unit(unit: &str) -> Result<Unit, MobileLengthError> {
+    unit.parse::<Unit>()
+        .map_err(|_| MobileLengthError::UnknownUnit {
+            unit: unit.to_string(),
+        })
+}
+
+/// A length, that is exposed to Swift and Kotlin.
+#[derive(uniffi::Object)]
+pub struct MobileLength {
+    inner: Length,
+}
+
+impl MobileLength {
+    fn wrap(inner: Length) -> Arc<Self> {
+        Arc::new(MobileLength { inner })
+    }
+}
+
+#[uniffi::export]
+impl MobileLength {
+    /// Creates a new length with the given value and unit symbol.
+    #[uniffi::constructor]
+    pub fn new(value: f64, unit: String) -> Result<Arc<Self>, MobileLengthError> {
+        Ok(MobileLength::wrap(Length::new_value_unit(
+            value,
+            parse_unit(&unit)?,
+        )))
+    }
+
+    /// Parses a string like "5 km" into a length.
+    #[uniffi::constructor]
+    pub fn parse(string: String) -> Result<Arc<Self>, MobileLengthError> {
+        Length::new_string(string.as_str())
+            .map(MobileLength::wrap)
+            .ok_or(MobileLengthError::UnparsableLength { string })
+    }
+
+    pub fn value(&self) -> f64 {
+        self.inner.value
+    }
+
+    pub fn unit(&self) -> String {
+        self.inner.unit.to_string()
+    }
+
+    /// Converts the length into the unit with the given symbol.
+    pub fn to(&self, unit: String) -> Result<Arc<Self>, MobileLengthError> {
+        Ok(MobileLength::wrap(self.inner.to(parse_unit(&unit)?)))
+    }
+
+    pub fn normalize(&self) -> Arc<Self> {
+        MobileLength::wrap(self.inner.normalize())
+    }
+
+    pub fn add(&self, other: Arc<MobileLength>) -> Arc<Self> {
+        MobileLength::wrap(self.inner.add(other.inner.clone()))
+    }
+
+    pub fn subtract(&self, other: Arc<MobileLength>) -> Arc<Self> {
+        MobileLength::wrap(self.inner.subtract(other.inner.clone()))
+    }
+
+    pub fn multiply_by(&self, factor: f64) -> Arc<Self> {
+        MobileLength::wrap(self.inner.multiply_by(factor))
+    }
+
+    pub fn divide_by(&self, factor: f64) -> Arc<Self> {
+        MobileLength::wrap(self.inner.divide_by(factor))
+    }
+
+    /// Formats the length, optionally with a fixed number of decimal places.
+    pub fn format(&self, precision: Option<u32>) -> String {
+        let mut output = String::new();
+        let options = crate::DisplayOptions {
+            precision: precision.map(|precision| precision as usize),
+            ..crate::DisplayOptions::default()
+        };
+        self.inner
+            .format_into(&mut output, options)
+            .expect("writing into a String never fails");
+
+        output
+    }
+}
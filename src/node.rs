@@ -0,0 +1,80 @@
+//! Node.js bindings via napi-rs (feature `node`).
+//!
+//! To build the native addon, compile the crate as a `cdylib`, e.g. with
+//! `cargo rustc --release --features node --crate-type cdylib`.
+
+use napi::{Error, Result};
+use napi_derive::napi;
+
+use crate::{DisplayOptions, Length, Unit};
+
+fn parse_unit(unit: &str) -> Result<Unit> {
+    unit.parse::<Unit>()
+        .map_err(|_| Error::from_reason(format!("unknown unit: {}", unit)))
+}
+
+/// A length, that is exposed to JavaScript.
+#[napi(js_name = "JsLength")]
+pub struct JsLength {
+    inner: Length,
+}
+
+#[napi]
+impl JsLength {
+    /// Creates a new length with the given value and unit symbol.
+    #[napi(constructor)]
+    pub fn new(value: f64, unit: String) -> Result<Self> {
+        Ok(JsLength {
+            inner: Length::new_value_unit(value, parse_unit(&unit)?),
+        })
+    }
+
+    /// Parses a string like "5 km" into a length.
+    #[napi(factory)]
+    pub fn parse(string: String) -> Result<Self> {
+        Length::new_string(string.as_str())
+            .map(|inner| JsLength { inner })
+            .ok_or_else(|| Error::from_reason(format!("unable to parse length: {}", string)))
+    }
+
+    #[napi(getter)]
+    pub fn value(&self) -> f64 {
+        self.inner.value
+    }
+
+    #[napi(getter)]
+    pub fn unit(&self) -> String {
+        self.inner.unit.to_string()
+    }
+
+    /// Converts the length into the unit with the given symbol.
+    #[napi]
+    pub fn to(&self, unit: String) -> Result<JsLength> {
+        Ok(JsLength {
+            inner: self.inner.to(parse_unit(&unit)?),
+        })
+    }
+
+    #[napi]
+    pub fn normalize(&self) -> JsLength {
+        JsLength {
+            inner: self.inner.normalize(),
+        }
+    }
+
+    /// Formats the length, optionally with a fixed number of decimal places.
+    #[napi]
+    pub fn format(&self, precision: Option<u32>) -> String {
+        let options = DisplayOptions {
+            precision: precision.map(|precision| precision as usize),
+            ..DisplayOptions::default()
+        };
+
+        self.inner.format_with(&options)
+    }
+
+    #[napi(js_name = "toString")]
+    pub fn to_js_string(&self) -> String {
+        self.inner.to_string()
+    }
+}
@@ -0,0 +1,102 @@
+//! A global observer of conversions, for logging or auditing them.
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use crate::Unit;
+
+type Observer = Arc<dyn Fn(&Conversion) + Send + Sync>;
+
+static OBSERVER: RwLock<Option<Observer>> = RwLock::new(None);
+
+/// Whether an observer is set, so conversions do not need to lock OBSERVER without one.
+static OBSERVED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Whether the observer is running on this thread, so its own conversions are not observed.
+    static OBSERVING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A conversion, that the observer is called with.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Conversion {
+    pub from: Unit,
+    pub to: Unit,
+    /// The value in the unit from.
+    pub value: f64,
+    /// The value in the unit to.
+    pub converted_value: f64,
+}
+
+/// Sets the observer, that is called with every explicit conversion by Length::to(...),
+/// Length::to_by_ref(...), Length::checked_to(...) and Length::to_exact(...), from all threads,
+/// replacing the previous one.
+///
+/// Conversions into the same unit and conversions within other operations, like comparing or
+/// adding lengths in different units, are not observed, and neither are the conversions of the
+/// observer itself.
+///
+/// # Example
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use length::{Length, Unit, ImperialUnit::*, MetricUnit::*};
+///
+/// let log = Arc::new(Mutex::new(Vec::new()));
+/// let observer_log = Arc::clone(&log);
+/// length::set_conversion_observer(move |conversion| {
+///     observer_log.lock().unwrap().push(*conversion);
+/// });
+///
+/// Length::new_value_unit(3, Furlong).to(Meter);
+/// length::clear_conversion_observer();
+///
+/// let log = log.lock().unwrap();
+/// assert_eq!(Unit::Imperial(Furlong), log[0].from);
+/// assert_eq!(Unit::Metric(Meter), log[0].to);
+/// assert_eq!(3.0, log[0].value);
+/// assert_eq!(603.504, log[0].converted_value);
+/// ```
+pub fn set_conversion_observer<F: Fn(&Conversion) + Send + Sync + 'static>(observer: F) {
+    *OBSERVER.write().unwrap() = Some(Arc::new(observer));
+    OBSERVED.store(true, Ordering::Release);
+}
+
+/// Removes the observer, that set_conversion_observer(...) has set.
+pub fn clear_conversion_observer() {
+    OBSERVED.store(false, Ordering::Release);
+    *OBSERVER.write().unwrap() = None;
+}
+
+/// Calls the observer, if one is set.
+pub(crate) fn observe(from: Unit, to: Unit, value: f64, converted_value: f64) {
+    if !OBSERVED.load(Ordering::Acquire) {
+        return;
+    }
+
+    if OBSERVING.get() {
+        return;
+    }
+
+    // The lock is released before the call, so the observer may set another observer.
+    let observer = OBSERVER.read().unwrap().clone();
+    if let Some(observer) = observer {
+        let _guard = ObservingGuard(OBSERVING.replace(true));
+        observer(&Conversion {
+            from,
+            to,
+            value,
+            converted_value,
+        });
+    }
+}
+
+/// Restores, whether the observer is running on this thread, when it is dropped, also if the
+/// observer panics.
+struct ObservingGuard(bool);
+
+impl Drop for ObservingGuard {
+    fn drop(&mut self) {
+        OBSERVING.set(self.0);
+    }
+}
@@ -0,0 +1,75 @@
+//! Parallel bulk conversions and sums for large datasets with rayon (feature `rayon`).
+
+use rayon::prelude::*;
+
+use crate::{Length, MetricUnit::Meter, Scalar, Unit};
+
+impl<T: Scalar + Send + Sync> Length<T> {
+    /// Converts values from one unit into another in parallel, like convert_slice(...).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, MetricUnit::*};
+    ///
+    /// let points: Vec<f64> = (0..100_000).map(f64::from).collect();
+    /// let meters = Length::par_convert_slice(&points, Millimeter, Meter);
+    ///
+    /// assert_eq!(99.999, meters[99_999]);
+    /// ```
+    pub fn par_convert_slice<F: Into<Unit>, D: Into<Unit>>(values: &[T], from: F, to: D) -> Vec<T> {
+        let (from_factor, to_factor) = Self::factors(&from.into(), &to.into());
+
+        values
+            .par_iter()
+            .map(|&value| value * from_factor / to_factor)
+            .collect()
+    }
+
+    /// Converts values from one unit into another in place and in parallel, like
+    /// convert_slice_in_place(...).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// let mut altitudes = vec![1_000.0, 2_500.0];
+    /// Length::par_convert_slice_in_place(&mut altitudes, Foot, Meter);
+    ///
+    /// assert_eq!(vec![304.8, 762.0], altitudes);
+    /// ```
+    pub fn par_convert_slice_in_place<F: Into<Unit>, D: Into<Unit>>(
+        values: &mut [T],
+        from: F,
+        to: D,
+    ) {
+        let (from_factor, to_factor) = Self::factors(&from.into(), &to.into());
+
+        values
+            .par_iter_mut()
+            .for_each(|value| *value = *value * from_factor / to_factor);
+    }
+
+    /// Sums the lengths in parallel into the unit of the first length, like Sum does. An empty
+    /// slice sums up to 0 m.
+    ///
+    /// As the lengths are added in another order, the result may differ from the sequential sum
+    /// in the last digits.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let segments = vec![Length::new_value_unit(250.0, Meter); 4_000];
+    /// let total = Length::par_sum(&segments);
+    ///
+    /// assert_eq!(1_000_000.0, total.value);
+    /// assert_eq!(Unit::Metric(Meter), total.unit);
+    /// ```
+    pub fn par_sum(lengths: &[Length<T>]) -> Length<T> {
+        lengths
+            .par_iter()
+            .cloned()
+            .reduce_with(|total, length| total + length)
+            .unwrap_or_else(|| Length::from_scalar(T::zero(), Meter))
+    }
+}
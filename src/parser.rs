@@ -0,0 +1,222 @@
+use std::ops::Range;
+
+use crate::{ImperialUnit, Length, ParseLengthError, TraditionalUnit, Unit};
+
+/// A configurable parser for lengths with locale specific number formats.
+///
+/// The default parser behaves like Length::parse(...), so it expects a decimal point and no
+/// thousands separators, and "mil" is a thou.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct LengthParser {
+    decimal_comma: bool,
+    thousands_separator: Option<char>,
+    lenient: bool,
+    scandinavian_mil: bool,
+}
+
+impl LengthParser {
+    /// Gets a new LengthParser struct with the default number format.
+    pub fn new() -> Self {
+        LengthParser::default()
+    }
+
+    /// Sets, if the decimal separator is a comma (like in German) instead of a point.
+    pub fn decimal_comma(mut self, decimal_comma: bool) -> Self {
+        self.decimal_comma = decimal_comma;
+        self
+    }
+
+    /// Sets the character, that groups the digits of the integer part by three, like ',' in
+    /// "1,234.5" or '.' in "1.234,5". It has to differ from the decimal separator.
+    pub fn thousands_separator(mut self, thousands_separator: char) -> Self {
+        self.thousands_separator = Some(thousands_separator);
+        self
+    }
+
+    /// Sets, if unit symbols are accepted regardless of their case, like in
+    /// Length::new_string_lenient(...).
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Sets, if the symbol "mil" is the Scandinavian mil of 10 km instead of the thou (a thousandth
+    /// of an inch), like in Swedish and Norwegian texts. Only the bare symbol is affected, "th"
+    /// and "thou" are still parsed as thou.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{LengthParser, Unit, ImperialUnit::*, TraditionalUnit::*};
+    ///
+    /// let swedish = LengthParser::new().decimal_comma(true).scandinavian_mil(true);
+    ///
+    /// assert_eq!(Unit::Traditional(Mil), swedish.parse("1,5 mil").unwrap().unit);
+    /// assert_eq!(Unit::Imperial(Thou), swedish.parse("1,5 thou").unwrap().unit);
+    /// assert_eq!(Unit::Imperial(Thou), LengthParser::new().parse("1.5 mil").unwrap().unit);
+    /// ```
+    pub fn scandinavian_mil(mut self, scandinavian_mil: bool) -> Self {
+        self.scandinavian_mil = scandinavian_mil;
+        self
+    }
+
+    /// Parses a string into a Length with the configured number format.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{LengthParser, Unit, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// let german = LengthParser::new().decimal_comma(true).thousands_separator('.');
+    /// let length = german.parse("1.234,5 km").unwrap();
+    ///
+    /// assert_eq!(1_234.5, length.value);
+    /// assert_eq!(Unit::Metric(Kilometer), length.unit);
+    ///
+    /// let english = LengthParser::new().thousands_separator(',');
+    ///
+    /// assert_eq!(1_234.5, english.parse("1,234.5 mi").unwrap().value);
+    /// assert!(english.parse("12,34.5 mi").is_err());
+    ///
+    /// let lenient = english.lenient(true);
+    ///
+    /// assert_eq!(Unit::Imperial(Mile), lenient.parse("1,234.5 MI").unwrap().unit);
+    /// ```
+    pub fn parse(&self, string: &str) -> Result<Length, ParseLengthError> {
+        let mut length = self.parse_number(string)?;
+        if self.scandinavian_mil && length.unit == Unit::Imperial(ImperialUnit::Thou) {
+            let unit = string[self.number_span(string).end..].trim();
+            let is_mil = if self.lenient {
+                unit.eq_ignore_ascii_case("mil")
+            } else {
+                unit == "mil"
+            };
+            if is_mil {
+                length.unit = Unit::Traditional(TraditionalUnit::Mil);
+            }
+        }
+
+        Ok(length)
+    }
+
+    /// Parses a string into a Length with the configured number format, without the
+    /// disambiguation of "mil".
+    fn parse_number(&self, string: &str) -> Result<Length, ParseLengthError> {
+        let number_span = self.number_span(string);
+        let number = &string[number_span.clone()];
+        if number.is_empty() {
+            return Length::parse_with(string, self.lenient);
+        }
+
+        let canonical_number = match self.canonical_number(number) {
+            Some(canonical_number) => canonical_number,
+            None => {
+                return Err(ParseLengthError::InvalidNumber {
+                    number: String::from(number),
+                    span: number_span,
+                })
+            }
+        };
+
+        if canonical_number == number {
+            return Length::parse_with(string, self.lenient);
+        }
+
+        let canonical = format!(
+            "{}{}{}",
+            &string[..number_span.start],
+            canonical_number,
+            &string[number_span.end..]
+        );
+        let removed = number.len() - canonical_number.len();
+
+        match Length::parse_with(&canonical, self.lenient) {
+            Ok(mut length) => {
+                length.original_string = String::from(string);
+                Ok(length)
+            }
+            Err(ParseLengthError::MissingUnit { .. }) => {
+                Err(ParseLengthError::MissingUnit { span: number_span })
+            }
+            Err(ParseLengthError::UnknownUnit { unit, span }) => {
+                Err(ParseLengthError::UnknownUnit {
+                    unit,
+                    span: span.start + removed..span.end + removed,
+                })
+            }
+            Err(ParseLengthError::SpacedUnit { unit, span }) => Err(ParseLengthError::SpacedUnit {
+                unit,
+                span: span.start + removed..span.end + removed,
+            }),
+            Err(ParseLengthError::MalformedFormat { .. }) => {
+                Err(ParseLengthError::MalformedFormat {
+                    input: String::from(string),
+                })
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        if self.decimal_comma {
+            ','
+        } else {
+            '.'
+        }
+    }
+
+    /// Gets the span of the leading sign, digits and separators, without trailing separators.
+    fn number_span(&self, string: &str) -> Range<usize> {
+        let start = string.len() - string.trim_start().len();
+        let mut end = start;
+        for (index, c) in string[start..].char_indices() {
+            let is_sign = index == 0 && (c == '+' || c == '-');
+            let is_number =
+                c.is_ascii_digit() || ".,".contains(c) || Some(c) == self.thousands_separator;
+            if !(is_sign || is_number) {
+                break;
+            }
+            end = start + index + c.len_utf8();
+        }
+
+        let trimmed = string[start..end].trim_end_matches(|c| Some(c) == self.thousands_separator);
+
+        start..start + trimmed.len()
+    }
+
+    /// Gets the number with a decimal point and without thousands separators, or None, if the
+    /// number does not match the configured format.
+    fn canonical_number(&self, number: &str) -> Option<String> {
+        let (sign, unsigned) = match number.strip_prefix(['+', '-']) {
+            Some(unsigned) => (&number[..1], unsigned),
+            None => ("", number),
+        };
+        let decimal_separator = self.decimal_separator();
+        let (integer, fraction) = match unsigned.split_once(decimal_separator) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let digits: String = match self.thousands_separator {
+            Some(separator) if separator != decimal_separator && integer.contains(separator) => {
+                let groups: Vec<&str> = integer.split(separator).collect();
+                let valid_groups = (1..=3).contains(&groups[0].len())
+                    && groups[1..].iter().all(|group| group.len() == 3);
+                if !valid_groups {
+                    return None;
+                }
+                groups.concat()
+            }
+            _ => String::from(integer),
+        };
+
+        let all_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+        if !all_digits(&digits) {
+            return None;
+        }
+
+        match fraction {
+            Some(fraction) if all_digits(fraction) => Some(format!("{sign}{digits}.{fraction}")),
+            Some(_) => None,
+            None => Some(format!("{sign}{digits}")),
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Python bindings via PyO3 (feature `python`), with the classes Length and Unit.
+//!
+//! To build the extension module, compile the crate as a `cdylib`, e.g. with
+//! `maturin build --release --features python,pyo3/extension-module` after adding
+//! `crate-type = ["cdylib", "rlib"]`.
+//!
+//! ```python
+//! from length import Length, Unit
+//!
+//! Length("5 km").to("mi")         # Length('3.1068559611866697 mi')
+//! Length(5, "ft").to(Unit("in"))  # Length('60 in')
+//! ```
+
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::{Length, Unit};
+
+/// A unit like "km", that is exposed to Python.
+#[pyclass(name = "Unit", module = "length", frozen)]
+#[derive(Clone)]
+pub struct PyUnit {
+    inner: Unit,
+}
+
+#[pymethods]
+impl PyUnit {
+    /// Creates the unit with the given symbol or name, like "km" or "miles".
+    #[new]
+    fn new(unit: &str) -> PyResult<Self> {
+        unit.parse::<Unit>()
+            .map(|inner| PyUnit { inner })
+            .map_err(|_| PyValueError::new_err(format!("unknown unit: {}", unit)))
+    }
+
+    /// Gets all units, ordered by their size.
+    #[staticmethod]
+    fn all() -> Vec<PyUnit> {
+        Unit::all().map(|inner| PyUnit { inner }).collect()
+    }
+
+    #[getter]
+    fn symbol(&self) -> String {
+        self.inner.symbol()
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name()
+    }
+
+    #[getter]
+    fn plural_name(&self) -> String {
+        self.inner.plural_name()
+    }
+
+    #[getter]
+    fn code(&self) -> u16 {
+        self.inner.code()
+    }
+
+    #[getter]
+    fn meters_per_unit(&self) -> f64 {
+        self.inner.meters_per_unit()
+    }
+
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        extract_unit(other).is_ok_and(|unit| unit == self.inner)
+    }
+
+    fn __hash__(&self) -> u64 {
+        self.inner.code().into()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.symbol()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Unit('{}')", self.inner.symbol())
+    }
+}
+
+/// A length, that is exposed to Python.
+#[pyclass(name = "Length", module = "length", frozen)]
+pub struct PyLength {
+    inner: Length,
+}
+
+#[pymethods]
+impl PyLength {
+    /// Parses a string like "5 km", or creates a length with the given value and unit, like
+    /// Length(5, "km").
+    #[new]
+    #[pyo3(signature = (value, unit = None))]
+    fn new(value: &Bound<'_, PyAny>, unit: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let inner = match unit {
+            Some(unit) => Length::new_value_unit(value.extract::<f64>()?, extract_unit(unit)?),
+            None => Length::parse(&value.extract::<String>()?)
+                .map_err(|error| PyValueError::new_err(error.to_string()))?,
+        };
+
+        Ok(PyLength { inner })
+    }
+
+    #[getter]
+    fn value(&self) -> f64 {
+        self.inner.value
+    }
+
+    #[getter]
+    fn unit(&self) -> PyUnit {
+        PyUnit {
+            inner: self.inner.unit,
+        }
+    }
+
+    /// Converts the length into a unit, that is given as Unit or as symbol or name.
+    fn to(&self, unit: &Bound<'_, PyAny>) -> PyResult<PyLength> {
+        Ok(PyLength {
+            inner: self.inner.to(extract_unit(unit)?),
+        })
+    }
+
+    fn normalize(&self) -> PyLength {
+        PyLength {
+            inner: self.inner.normalize(),
+        }
+    }
+
+    fn __add__(&self, other: &Bound<'_, PyLength>) -> PyLength {
+        PyLength {
+            inner: self.inner.add(other.get().inner.clone()),
+        }
+    }
+
+    fn __sub__(&self, other: &Bound<'_, PyLength>) -> PyLength {
+        PyLength {
+            inner: self.inner.subtract(other.get().inner.clone()),
+        }
+    }
+
+    fn __mul__(&self, factor: f64) -> PyLength {
+        PyLength {
+            inner: self.inner.multiply_by(factor),
+        }
+    }
+
+    fn __rmul__(&self, factor: f64) -> PyLength {
+        self.__mul__(factor)
+    }
+
+    fn __truediv__(&self, factor: f64) -> PyLength {
+        PyLength {
+            inner: self.inner.divide_by(factor),
+        }
+    }
+
+    fn __neg__(&self) -> PyLength {
+        PyLength {
+            inner: -self.inner.clone(),
+        }
+    }
+
+    /// Compares the physical magnitudes, like Length does in Rust: a NaN length is equal to
+    /// another NaN length, but unordered to all other lengths, so only != is true for them.
+    fn __richcmp__(&self, other: &Bound<'_, PyLength>, op: CompareOp) -> bool {
+        match self.inner.partial_cmp(&other.get().inner) {
+            Some(ordering) => op.matches(ordering),
+            None => matches!(op, CompareOp::Ne),
+        }
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Length('{}')", self.inner)
+    }
+}
+
+/// Gets a unit, that is given as Unit or as symbol or name.
+fn extract_unit(unit: &Bound<'_, PyAny>) -> PyResult<Unit> {
+    if let Ok(unit) = unit.cast::<PyUnit>() {
+        return Ok(unit.get().inner);
+    }
+    match unit.extract::<String>() {
+        Ok(symbol) => PyUnit::new(&symbol).map(|unit| unit.inner),
+        Err(_) => Err(PyTypeError::new_err("the unit has to be a Unit or a str")),
+    }
+}
+
+/// The Python module length.
+#[pymodule]
+#[pyo3(name = "length")]
+pub fn python_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyLength>()?;
+    module.add_class::<PyUnit>()?;
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use crate::scanner::Scanner;
+use crate::{Length, Unit};
+
+const RANGE_SEPARATORS: [&str; 3] = ["-", "–", "to"];
+const TOLERANCE_SEPARATORS: [&str; 2] = ["±", "+/-"];
+
+/// A closed interval between two lengths.
+#[derive(Clone)]
+pub struct LengthRange {
+    pub start: Length,
+    pub end: Length,
+}
+
+impl LengthRange {
+    /// Gets a new LengthRange struct with the given bounds.
+    pub fn new(start: Length, end: Length) -> Self {
+        LengthRange { start, end }
+    }
+
+    /// Gets a new Option<LengthRange>, that represents a range by a string.
+    ///
+    /// Supported are ranges like "5-10 km", "3 to 7 ft" or "2 m - 300 cm" and tolerances
+    /// around a nominal value like "10 ± 2 mm" or "10 mm +/- 0.5 mm". If the unit is only
+    /// written once, it applies to both values.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{LengthRange, Unit, MetricUnit::*};
+    ///
+    /// let range = LengthRange::new_string("5-10 km").unwrap();
+    ///
+    /// assert_eq!(5.0, range.start.value);
+    /// assert_eq!(10.0, range.end.value);
+    /// assert_eq!(Unit::Metric(Kilometer), range.start.unit);
+    ///
+    /// let tolerance = LengthRange::new_string("10 ± 2 mm").unwrap();
+    ///
+    /// assert_eq!(8.0, tolerance.start.value);
+    /// assert_eq!(12.0, tolerance.end.value);
+    /// ```
+    pub fn new_string<S: Into<String>>(string: S) -> Option<Self> {
+        let real_string: String = string.into();
+
+        if let Some((first, second)) =
+            LengthRange::parse_pair(real_string.as_str(), &RANGE_SEPARATORS)
+        {
+            return Some(LengthRange::new(first, second));
+        }
+
+        let (nominal, tolerance) =
+            LengthRange::parse_pair(real_string.as_str(), &TOLERANCE_SEPARATORS)?;
+
+        Some(LengthRange::around(&nominal, &tolerance))
+    }
+
+    /// Gets a new Option<LengthRange> from a tolerance string like "±2 mm" around the given nominal length.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, LengthRange, Unit, MetricUnit::*};
+    ///
+    /// let nominal = Length::new_string("5 cm").unwrap();
+    /// let range = LengthRange::new_tolerance_string("±2 mm", &nominal).unwrap();
+    ///
+    /// assert_eq!(4.8, range.start.value);
+    /// assert_eq!(5.2, range.end.value);
+    /// assert_eq!(Unit::Metric(Centimeter), range.end.unit);
+    /// ```
+    pub fn new_tolerance_string<S: Into<String>>(string: S, nominal: &Length) -> Option<Self> {
+        let real_string: String = string.into();
+        let mut scanner = Scanner::new(real_string.as_str());
+        scanner.skip_whitespace();
+        if !LengthRange::separator(&mut scanner, &TOLERANCE_SEPARATORS) {
+            return None;
+        }
+        let (value, unit) = LengthRange::value_unit(&mut scanner)?;
+
+        Some(LengthRange::around(
+            nominal,
+            &Length::new_value_unit(value, unit),
+        ))
+    }
+
+    /// Gets the range from nominal - tolerance to nominal + tolerance, in the unit of the nominal length.
+    pub fn around(nominal: &Length, tolerance: &Length) -> Self {
+        LengthRange::new(
+            nominal.subtract(tolerance.clone()),
+            nominal.add(tolerance.clone()),
+        )
+    }
+
+    /// Checks, if the given length lies within this range (bounds included).
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, LengthRange};
+    ///
+    /// let range = LengthRange::new_string("3 to 7 ft").unwrap();
+    ///
+    /// assert!(range.contains(&Length::new_string("2 m").unwrap()));
+    /// assert!(!range.contains(&Length::new_string("3 m").unwrap()));
+    /// ```
+    pub fn contains(&self, length: &Length) -> bool {
+        let value = length.convert(self.start.unit).value;
+        let end = self.end.convert(self.start.unit).value;
+
+        self.start.value <= value && value <= end
+    }
+
+    /// Gets the distance between start and end, in the unit of the start.
+    pub fn span(&self) -> Length {
+        self.end
+            .convert(self.start.unit)
+            .subtract(self.start.clone())
+    }
+
+    /// Parses two lengths, that are divided by one of the separators, like "5 km - 10 km" or
+    /// "5-10 km". If the unit is only written once, it applies to both values.
+    fn parse_pair(string: &str, separators: &[&str]) -> Option<(Length, Length)> {
+        let mut scanner = Scanner::new(string);
+        scanner.skip_whitespace();
+        let first_span = scanner.number()?;
+        let first_value: f64 = string[first_span].parse().ok()?;
+        scanner.skip_whitespace();
+
+        // The first unit may run into the separator like in "5kmto10km", so it is shortened until
+        // the rest of the string matches.
+        let mut with_unit = scanner;
+        if let Some(unit_span) = with_unit.word(is_range_unit_start, is_range_unit_rest) {
+            let unit_ends = string[unit_span.clone()]
+                .char_indices()
+                .rev()
+                .map(|(index, c)| unit_span.start + index + c.len_utf8());
+            for unit_end in unit_ends {
+                let mut rest = Scanner::new(&string[unit_end..]);
+                if let Some(second) = LengthRange::second_length(&mut rest, separators) {
+                    let first_unit = string[unit_span.start..unit_end].parse::<Unit>().ok()?;
+                    return Some((Length::new_value_unit(first_value, first_unit), second));
+                }
+            }
+        }
+
+        let second = LengthRange::second_length(&mut scanner, separators)?;
+        let first = Length::new_value_unit(first_value, second.unit);
+
+        Some((first, second))
+    }
+
+    /// Parses the separator and the second length up to the end of the string.
+    fn second_length(scanner: &mut Scanner, separators: &[&str]) -> Option<Length> {
+        scanner.skip_whitespace();
+        if !LengthRange::separator(scanner, separators) {
+            return None;
+        }
+        let (value, unit) = LengthRange::value_unit(scanner)?;
+
+        Some(Length::new_value_unit(value, unit))
+    }
+
+    fn separator(scanner: &mut Scanner, separators: &[&str]) -> bool {
+        separators.iter().any(|separator| scanner.eat(separator))
+    }
+
+    /// Parses a number and a unit up to the end of the string.
+    fn value_unit(scanner: &mut Scanner) -> Option<(f64, Unit)> {
+        scanner.skip_whitespace();
+        let value_span = scanner.number()?;
+        scanner.skip_whitespace();
+        let unit_span = scanner.word(is_range_unit_start, is_range_unit_rest)?;
+        if !scanner.finish() {
+            return None;
+        }
+
+        Some((
+            scanner.slice(value_span).parse().ok()?,
+            scanner.slice(unit_span).parse().ok()?,
+        ))
+    }
+}
+
+/// Checks, if the character can start a unit symbol in a range, that may not contain the
+/// separators of a range.
+fn is_range_unit_start(c: char) -> bool {
+    is_range_unit_rest(c) && !c.is_ascii_digit() && !".,+".contains(c)
+}
+
+fn is_range_unit_rest(c: char) -> bool {
+    !c.is_whitespace() && !"-–±/".contains(c)
+}
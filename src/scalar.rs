@@ -0,0 +1,24 @@
+//! The numeric types, that can be used as the value of a Length.
+
+use num_traits::{FromPrimitive, Num, ToPrimitive};
+
+/// A numeric type, that a Length can store its value in.
+///
+/// It is implemented for f64 (the default), f32 and, with the feature `decimal`,
+/// rust_decimal::Decimal. Integer types are not supported, because the conversion factors between
+/// most units are fractional and would be truncated, e.g. 1 ft would be 0 m. BigDecimal is not
+/// supported, because it is not Copy.
+///
+/// ```compile_fail
+/// use length::{Length, MetricUnit::*};
+///
+/// let length = Length::<i32>::from_scalar(1, Meter);
+/// ```
+pub trait Scalar: Copy + Num + PartialOrd + FromPrimitive + ToPrimitive {}
+
+impl Scalar for f32 {}
+
+impl Scalar for f64 {}
+
+#[cfg(feature = "decimal")]
+impl Scalar for rust_decimal::Decimal {}
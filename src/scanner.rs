@@ -0,0 +1,159 @@
+//! A small cursor over a string, that the hand-written parsers are built on.
+//!
+//! All positions are byte offsets into the scanned string. Methods, that fail to match, leave the
+//! position unchanged, so alternatives can be tried on a copy of the scanner.
+
+use std::ops::Range;
+
+#[derive(Clone, Copy)]
+pub(crate) struct Scanner<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Scanner { input, position: 0 }
+    }
+
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
+
+    pub(crate) fn slice(&self, range: Range<usize>) -> &'a str {
+        &self.input[range]
+    }
+
+    pub(crate) fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    /// Skips whitespace and returns, if there was any.
+    pub(crate) fn skip_whitespace(&mut self) -> bool {
+        let start = self.position;
+        while self.eat_char_if(char::is_whitespace).is_some() {}
+
+        self.position > start
+    }
+
+    /// Skips trailing whitespace and checks, if the whole string was scanned.
+    pub(crate) fn finish(&mut self) -> bool {
+        self.skip_whitespace();
+
+        self.position == self.input.len()
+    }
+
+    pub(crate) fn eat(&mut self, expected: &str) -> bool {
+        if self.input[self.position..].starts_with(expected) {
+            self.position += expected.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn eat_char_if<F: Fn(char) -> bool>(&mut self, predicate: F) -> Option<char> {
+        let c = self.peek().filter(|&c| predicate(c))?;
+        self.position += c.len_utf8();
+
+        Some(c)
+    }
+
+    /// Scans an optional sign: [+\-]?
+    pub(crate) fn sign(&mut self) -> Option<char> {
+        self.eat_char_if(|c| c == '+' || c == '-')
+    }
+
+    /// Scans [0-9]+
+    pub(crate) fn digits(&mut self) -> Option<Range<usize>> {
+        let start = self.position;
+        while self.eat_char_if(|c| c.is_ascii_digit()).is_some() {}
+
+        if self.position > start {
+            Some(start..self.position)
+        } else {
+            None
+        }
+    }
+
+    /// Scans an unsigned decimal number: [0-9]+(\.[0-9]+)?
+    pub(crate) fn number(&mut self) -> Option<Range<usize>> {
+        let start = self.position;
+        self.digits()?;
+
+        let mut fraction = *self;
+        if fraction.eat(".") && fraction.digits().is_some() {
+            *self = fraction;
+        }
+
+        Some(start..self.position)
+    }
+
+    /// Scans an exponent: [eE][+\-]?[0-9]+
+    #[cfg(not(feature = "regex"))]
+    pub(crate) fn exponent(&mut self) -> Option<Range<usize>> {
+        let start = self.position;
+        let mut exponent = *self;
+        exponent.eat_char_if(|c| c == 'e' || c == 'E')?;
+        exponent.sign();
+        exponent.digits()?;
+        *self = exponent;
+
+        Some(start..self.position)
+    }
+
+    /// Scans a word, that starts with a character accepted by is_start and continues with the
+    /// characters accepted by is_rest.
+    pub(crate) fn word<S, R>(&mut self, is_start: S, is_rest: R) -> Option<Range<usize>>
+    where
+        S: Fn(char) -> bool,
+        R: Fn(char) -> bool,
+    {
+        let start = self.position;
+        self.eat_char_if(is_start)?;
+        while self.eat_char_if(&is_rest).is_some() {}
+
+        Some(start..self.position)
+    }
+
+    /// Scans whitespace separated words like "light years". The first word starts with a character
+    /// accepted by is_first_start, all further words with a character accepted by is_start.
+    pub(crate) fn words<F, S>(&mut self, is_first_start: F, is_start: S) -> Option<Range<usize>>
+    where
+        F: Fn(char) -> bool,
+        S: Fn(char) -> bool,
+    {
+        let is_rest = |c: char| !c.is_whitespace();
+        let first = self.word(is_first_start, is_rest)?;
+        let mut end = first.end;
+        loop {
+            let mut next = *self;
+            if !next.skip_whitespace() {
+                break;
+            }
+            match next.word(&is_start, is_rest) {
+                Some(word) => {
+                    end = word.end;
+                    *self = next;
+                }
+                None => break,
+            }
+        }
+
+        Some(first.start..end)
+    }
+}
+
+/// Checks, if the character can start a unit symbol, so it is neither whitespace nor a part of a
+/// number.
+pub(crate) fn is_unit_start(c: char) -> bool {
+    !c.is_whitespace() && !c.is_ascii_digit() && !".,+-".contains(c)
+}
+
+/// Checks, if the whole string is a decimal number with an optional sign: [+\-]?[0-9]+(\.[0-9]+)?
+pub(crate) fn is_number(string: &str) -> bool {
+    let mut scanner = Scanner::new(string);
+    scanner.sign();
+
+    scanner.number().is_some() && scanner.position() == string.len()
+}
@@ -0,0 +1,55 @@
+//! Fast bulk conversions of f64 values (feature `simd`).
+//!
+//! Unlike Length::convert_slice(...), every value is multiplied by one precomputed factor instead
+//! of being multiplied and divided. A division has a far lower throughput than a multiplication,
+//! so this is about three to four times faster for large slices (see the conversion benchmark),
+//! and the plain loops are vectorized by the compiler on stable Rust. But the results may differ
+//! from Length::convert_slice(...) and Length::to(...) in the last bit, which is why this is
+//! opt-in.
+
+use crate::{Length, Unit};
+
+/// Converts f64 values from one unit into another, like Length::convert_slice(...), but faster
+/// for large slices.
+///
+/// # Example
+/// ```
+/// use length::{ImperialUnit::*, MetricUnit::*};
+///
+/// let values: Vec<f64> = (0..1_000).map(f64::from).collect();
+/// let meters = length::simd::convert_slice(&values, Foot, Meter);
+///
+/// assert!((meters[999] - 304.495_2).abs() < 1e-12);
+/// ```
+pub fn convert_slice<F: Into<Unit>, D: Into<Unit>>(values: &[f64], from: F, to: D) -> Vec<f64> {
+    let factor = factor(from.into(), to.into());
+
+    values.iter().map(|value| value * factor).collect()
+}
+
+/// Converts f64 values from one unit into another in place, like
+/// Length::convert_slice_in_place(...), but faster for large slices.
+///
+/// # Example
+/// ```
+/// use length::MetricUnit::*;
+///
+/// let mut points = [1_250.0, -80.0, 3.0];
+/// length::simd::convert_slice_in_place(&mut points, Millimeter, Meter);
+///
+/// assert!((points[0] - 1.25).abs() < 1e-15);
+/// ```
+pub fn convert_slice_in_place<F: Into<Unit>, D: Into<Unit>>(values: &mut [f64], from: F, to: D) {
+    let factor = factor(from.into(), to.into());
+
+    for value in values.iter_mut() {
+        *value *= factor;
+    }
+}
+
+/// Gets the factor, that a value is multiplied by to convert it from one unit into another.
+fn factor(from: Unit, to: Unit) -> f64 {
+    let (from_factor, to_factor) = Length::<f64>::factors(&from, &to);
+
+    from_factor / to_factor
+}
@@ -0,0 +1,199 @@
+//! Database column types for Length with sqlx (feature `sqlx`) and Diesel (feature `diesel`).
+//!
+//! A Length is stored in a text column as "<value> <unit>", like "2.5 km", so the unit is kept.
+//! Wrapped in Meters, it is stored in a double precision column as its value in meters, which
+//! can be compared and summed up by the database.
+
+use crate::{Length, MetricUnit::Meter};
+
+/// A length, that is stored in a double precision column in meters and read back in meters.
+///
+/// # Example
+/// ```
+/// use length::sql::Meters;
+/// use length::{Length, Unit, MetricUnit::*};
+///
+/// let column = Meters::from(Length::new_string("2.5 km").unwrap());
+///
+/// assert_eq!(2_500.0, column.0.value);
+/// assert_eq!(Unit::Metric(Meter), column.0.unit);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Double)
+)]
+pub struct Meters(pub Length);
+
+impl From<Length> for Meters {
+    fn from(length: Length) -> Meters {
+        Meters(length.convert(Meter))
+    }
+}
+
+impl From<Meters> for Length {
+    fn from(meters: Meters) -> Length {
+        meters.0
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_types {
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::{Database, Decode, Encode, Type};
+
+    use super::Meters;
+    use crate::{Length, MetricUnit::Meter};
+
+    impl<DB: Database> Type<DB> for Length
+    where
+        String: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <String as Type<DB>>::type_info()
+        }
+
+        fn compatible(type_info: &DB::TypeInfo) -> bool {
+            <String as Type<DB>>::compatible(type_info)
+        }
+    }
+
+    impl<'q, DB: Database> Encode<'q, DB> for Length
+    where
+        String: Encode<'q, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buffer: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            self.to_string().encode(buffer)
+        }
+    }
+
+    impl<'r, DB: Database> Decode<'r, DB> for Length
+    where
+        String: Decode<'r, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Length, BoxDynError> {
+            Ok(Length::parse(&String::decode(value)?)?)
+        }
+    }
+
+    impl<DB: Database> Type<DB> for Meters
+    where
+        f64: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <f64 as Type<DB>>::type_info()
+        }
+
+        fn compatible(type_info: &DB::TypeInfo) -> bool {
+            <f64 as Type<DB>>::compatible(type_info)
+        }
+    }
+
+    impl<'q, DB: Database> Encode<'q, DB> for Meters
+    where
+        f64: Encode<'q, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buffer: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            self.0.convert(Meter).value.encode(buffer)
+        }
+    }
+
+    impl<'r, DB: Database> Decode<'r, DB> for Meters
+    where
+        f64: Decode<'r, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Meters, BoxDynError> {
+            Ok(Meters(Length::new_value_unit(f64::decode(value)?, Meter)))
+        }
+    }
+}
+
+#[cfg(feature = "diesel")]
+mod diesel_types {
+    use diesel::backend::Backend;
+    use diesel::deserialize::{self, FromSql};
+    use diesel::sql_types::{Double, Text};
+
+    use super::Meters;
+    use crate::{Length, MetricUnit::Meter};
+
+    impl<DB: Backend> FromSql<Text, DB> for Length
+    where
+        String: FromSql<Text, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Length> {
+            Ok(Length::parse(&String::from_sql(bytes)?)?)
+        }
+    }
+
+    impl<DB: Backend> FromSql<Double, DB> for Meters
+    where
+        f64: FromSql<Double, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Meters> {
+            Ok(Meters(Length::new_value_unit(f64::from_sql(bytes)?, Meter)))
+        }
+    }
+
+    /// Implements ToSql for a backend, that writes the values into a byte buffer.
+    #[cfg(any(feature = "diesel-postgres", feature = "diesel-mysql"))]
+    macro_rules! impl_to_sql_as_bytes {
+        ($backend:ty) => {
+            impl ToSql<Text, $backend> for Length {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, $backend>) -> serialize::Result {
+                    let text = self.to_string();
+                    <String as ToSql<Text, $backend>>::to_sql(&text, &mut out.reborrow())
+                }
+            }
+
+            impl ToSql<Double, $backend> for Meters {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, $backend>) -> serialize::Result {
+                    let meters = self.0.convert(Meter).value;
+                    <f64 as ToSql<Double, $backend>>::to_sql(&meters, &mut out.reborrow())
+                }
+            }
+        };
+    }
+
+    #[cfg(any(feature = "diesel-postgres", feature = "diesel-mysql"))]
+    use diesel::serialize::{self, Output, ToSql};
+
+    #[cfg(feature = "diesel-postgres")]
+    impl_to_sql_as_bytes!(diesel::pg::Pg);
+
+    #[cfg(feature = "diesel-mysql")]
+    impl_to_sql_as_bytes!(diesel::mysql::Mysql);
+
+    /// SQLite binds owned values instead of bytes.
+    #[cfg(feature = "diesel-sqlite")]
+    mod sqlite {
+        use diesel::serialize::{self, IsNull, Output, ToSql};
+        use diesel::sql_types::{Double, Text};
+        use diesel::sqlite::Sqlite;
+
+        use crate::sql::Meters;
+        use crate::{Length, MetricUnit::Meter};
+
+        impl ToSql<Text, Sqlite> for Length {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+                out.set_value(self.to_string());
+                Ok(IsNull::No)
+            }
+        }
+
+        impl ToSql<Double, Sqlite> for Meters {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+                out.set_value(self.0.convert(Meter).value);
+                Ok(IsNull::No)
+            }
+        }
+    }
+}
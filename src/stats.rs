@@ -0,0 +1,110 @@
+//! Aggregations over slices of lengths with possibly different units.
+//!
+//! All results are returned in the unit of the first length of the slice, or None for an empty
+//! slice.
+
+use std::cmp::Ordering;
+
+use crate::{Length, Scalar};
+
+/// Gets the arithmetic mean of the lengths.
+///
+/// # Example
+/// ```
+/// use length::{stats, Length, Unit, MetricUnit::*};
+///
+/// let lengths = [
+///     Length::new_string("1 m").unwrap(),
+///     Length::new_string("150 cm").unwrap(),
+///     Length::new_string("3500 mm").unwrap(),
+/// ];
+/// let mean = stats::mean(&lengths).unwrap();
+///
+/// assert_eq!(2.0, mean.value);
+/// assert_eq!(Unit::Metric(Meter), mean.unit);
+/// ```
+pub fn mean<T: Scalar>(lengths: &[Length<T>]) -> Option<Length<T>> {
+    if lengths.is_empty() {
+        return None;
+    }
+
+    let total: Length<T> = lengths.iter().sum();
+
+    Some(total.divide_by(T::from_usize(lengths.len())?))
+}
+
+/// Gets the median of the lengths. For an even number of lengths, it is the midpoint of both
+/// middle lengths.
+///
+/// # Example
+/// ```
+/// use length::{stats, Length};
+///
+/// let lengths = [
+///     Length::new_string("1 km").unwrap(),
+///     Length::new_string("3 m").unwrap(),
+///     Length::new_string("2 cm").unwrap(),
+/// ];
+///
+/// assert_eq!(0.003, stats::median(&lengths).unwrap().value);
+/// ```
+pub fn median<T: Scalar>(lengths: &[Length<T>]) -> Option<Length<T>> {
+    let unit = lengths.first()?.unit;
+    let mut sorted: Vec<&Length<T>> = lengths.iter().collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    let middle = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        Some(sorted[middle].convert(unit))
+    } else {
+        Some(sorted[middle - 1].convert(unit).midpoint(sorted[middle]))
+    }
+}
+
+/// Gets the shortest of the lengths in the unit of the first length.
+///
+/// # Example
+/// ```
+/// use length::{stats, Length, Unit, ImperialUnit::*};
+///
+/// let lengths = [
+///     Length::new_string("1 ft").unwrap(),
+///     Length::new_string("25 cm").unwrap(),
+/// ];
+/// let shortest = stats::min(&lengths).unwrap();
+///
+/// assert!((shortest.value - 0.25 / 0.3048).abs() < 1e-12);
+/// assert_eq!(Unit::Imperial(Foot), shortest.unit);
+/// ```
+pub fn min<T: Scalar>(lengths: &[Length<T>]) -> Option<Length<T>> {
+    let first = lengths.first()?;
+    let shortest = lengths[1..].iter().fold(first.clone(), |shortest, length| {
+        shortest.min(length.clone())
+    });
+
+    Some(shortest.convert(first.unit))
+}
+
+/// Gets the longest of the lengths in the unit of the first length.
+///
+/// # Example
+/// ```
+/// use length::{stats, Length, Unit, ImperialUnit::*};
+///
+/// let lengths = [
+///     Length::new_string("1 ft").unwrap(),
+///     Length::new_string("61 cm").unwrap(),
+/// ];
+/// let longest = stats::max(&lengths).unwrap();
+///
+/// assert!((longest.value - 0.61 / 0.3048).abs() < 1e-12);
+/// assert_eq!(Unit::Imperial(Foot), longest.unit);
+/// ```
+pub fn max<T: Scalar>(lengths: &[Length<T>]) -> Option<Length<T>> {
+    let first = lengths.first()?;
+    let longest = lengths[1..]
+        .iter()
+        .fold(first.clone(), |longest, length| longest.max(length.clone()));
+
+    Some(longest.convert(first.unit))
+}
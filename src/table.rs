@@ -0,0 +1,159 @@
+//! Conversion tables between units, like a cm and in cheat sheet.
+
+use crate::{Length, Unit};
+
+/// The number of significant digits of the values in a table.
+const SIGNIFICANT_DIGITS: i32 = 6;
+
+/// The output format of generate(...).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TableFormat {
+    /// A GitHub flavored Markdown table with right-aligned values.
+    Markdown,
+    /// Comma separated values with a header row, quoted where needed.
+    Csv,
+    /// A plain text table with ASCII borders and right-aligned values.
+    Ascii,
+}
+
+/// Generates a table, that has a row and a column for each unit, with the value of one unit of
+/// the row in the unit of the column. The values are rounded to six significant digits, very
+/// great and very small ones are written with an exponent.
+///
+/// # Example
+/// ```
+/// use length::table::{self, TableFormat};
+/// use length::{Unit, ImperialUnit::*, MetricUnit::*};
+///
+/// let units = [Unit::Metric(Centimeter), Unit::Imperial(Inch)];
+///
+/// assert_eq!(
+///     "|  | cm | in |\n\
+///      |---|---:|---:|\n\
+///      | 1 cm | 1 | 0.393701 |\n\
+///      | 1 in | 2.54 | 1 |\n",
+///     table::generate(&units, TableFormat::Markdown)
+/// );
+/// ```
+pub fn generate(units: &[Unit], format: TableFormat) -> String {
+    let mut rows = Vec::with_capacity(units.len() + 1);
+    rows.push(
+        std::iter::once(String::new())
+            .chain(units.iter().map(Unit::symbol))
+            .collect::<Vec<_>>(),
+    );
+    for from in units {
+        let mut row = vec![format!("1 {}", from.symbol())];
+        row.extend(
+            units
+                .iter()
+                .map(|to| format_value(Length::new_value_unit(1.0, *from).convert(*to).value)),
+        );
+        rows.push(row);
+    }
+
+    match format {
+        TableFormat::Markdown => markdown(&rows),
+        TableFormat::Csv => csv(&rows),
+        TableFormat::Ascii => ascii(&rows),
+    }
+}
+
+fn markdown(rows: &[Vec<String>]) -> String {
+    let mut table = String::new();
+    for (index, row) in rows.iter().enumerate() {
+        table.push_str("| ");
+        table.push_str(&row.join(" | "));
+        table.push_str(" |\n");
+        if index == 0 {
+            table.push_str("|---");
+            table.push_str(&"|---:".repeat(row.len() - 1));
+            table.push_str("|\n");
+        }
+    }
+
+    table
+}
+
+fn csv(rows: &[Vec<String>]) -> String {
+    let mut table = String::new();
+    for row in rows {
+        let fields: Vec<String> = row
+            .iter()
+            .map(|field| {
+                if field.contains([',', '"', '\n']) {
+                    format!("\"{}\"", field.replace('"', "\"\""))
+                } else {
+                    field.clone()
+                }
+            })
+            .collect();
+        table.push_str(&fields.join(","));
+        table.push('\n');
+    }
+
+    table
+}
+
+fn ascii(rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let border: String = widths
+        .iter()
+        .map(|width| format!("+{}", "-".repeat(width + 2)))
+        .collect::<String>()
+        + "+\n";
+
+    let mut table = border.clone();
+    for (index, row) in rows.iter().enumerate() {
+        for (column, field) in row.iter().enumerate() {
+            if column == 0 {
+                table.push_str(&format!("| {:<1$} ", field, widths[column]));
+            } else {
+                table.push_str(&format!("| {:>1$} ", field, widths[column]));
+            }
+        }
+        table.push_str("|\n");
+        if index == 0 {
+            table.push_str(&border);
+        }
+    }
+    table.push_str(&border);
+
+    table
+}
+
+/// Formats the value with six significant digits without trailing zeros, and with an exponent
+/// below 0.0001 and from 1e9 on.
+fn format_value(value: f64) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return value.to_string();
+    }
+
+    let exponent = value.abs().log10().floor() as i32;
+    if (-4..9).contains(&exponent) {
+        let decimals = (SIGNIFICANT_DIGITS - 1 - exponent).max(0) as usize;
+        trim_zeros(&format!("{:.*}", decimals, value)).to_string()
+    } else {
+        let formatted = format!("{:.*e}", (SIGNIFICANT_DIGITS - 1) as usize, value);
+        let (mantissa, exponent) = formatted
+            .split_once('e')
+            .expect("formatted with an exponent");
+        format!("{}e{}", trim_zeros(mantissa), exponent)
+    }
+}
+
+/// Removes the trailing zeros of the decimal places and a trailing decimal point.
+fn trim_zeros(number: &str) -> &str {
+    if number.contains('.') {
+        number.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        number
+    }
+}
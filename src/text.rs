@@ -0,0 +1,228 @@
+//! Lengths in free text, like "the bridge is 1.2 km long and 15 m wide".
+
+use std::ops::Range;
+
+use crate::{DisplayOptions, Length, Unit, UnitSystem};
+
+/// The greatest number of whitespace separated tokens, that a length in a text may consist of,
+/// like "1 m 75 cm" or "3 1/2 in".
+const MAX_TOKENS: usize = 6;
+
+/// Options for convert_all_with(...).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvertOptions {
+    precision: usize,
+    units: Vec<Unit>,
+}
+
+impl ConvertOptions {
+    /// Gets new options with two decimal places and the commonly used units of the target
+    /// system.
+    pub fn new() -> Self {
+        ConvertOptions::default()
+    }
+
+    /// Sets the number of decimal places of the converted values.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the units, into which the lengths are converted, instead of the commonly used units
+    /// of the target system. Lengths in one of these units are left unchanged.
+    pub fn units(mut self, units: &[Unit]) -> Self {
+        self.units = units.to_vec();
+        self.units.sort();
+        self.units.dedup();
+        self
+    }
+
+    /// Checks, if a length in the unit is left unchanged.
+    fn is_allowed(&self, unit: Unit, target_system: UnitSystem) -> bool {
+        if self.units.is_empty() {
+            unit.system() == target_system
+        } else {
+            self.units.contains(&unit)
+        }
+    }
+
+    /// Gets the unit, into which a length is converted, like Unit::best_for(...).
+    fn target_unit(&self, length: &Length, target_system: UnitSystem) -> Unit {
+        let value_in_meters = length.convert(Unit::Metric(crate::MetricUnit::Meter)).value;
+        match self.units.first() {
+            None => Unit::best_for(value_in_meters, target_system),
+            Some(smallest) => *self
+                .units
+                .iter()
+                .rev()
+                .find(|unit| value_in_meters.abs() / unit.meters_per_unit() >= 1.0)
+                .unwrap_or(smallest),
+        }
+    }
+}
+
+impl Default for ConvertOptions {
+    fn default() -> Self {
+        ConvertOptions {
+            precision: 2,
+            units: Vec::new(),
+        }
+    }
+}
+
+/// Converts all lengths in a text into the commonly used units of the target system, with two
+/// decimal places. The rest of the text and the lengths, that are already in the target system,
+/// are left unchanged.
+///
+/// # Example
+/// ```
+/// use length::{text, UnitSystem};
+///
+/// let recipe = "Roll the dough to 1/4 in and cut it into 3 in squares.";
+///
+/// assert_eq!(
+///     "Roll the dough to 6.35 mm and cut it into 7.62 cm squares.",
+///     text::convert_all(recipe, UnitSystem::Metric)
+/// );
+/// ```
+pub fn convert_all(text: &str, target_system: UnitSystem) -> String {
+    convert_all_with(text, target_system, &ConvertOptions::default())
+}
+
+/// Converts all lengths in a text like convert_all(...), with the given precision and units.
+///
+/// # Example
+/// ```
+/// use length::text::{self, ConvertOptions};
+/// use length::{Unit, UnitSystem, MetricUnit::*};
+///
+/// let spec = "Width: 24 in, height: 6 ft 2 in, cable: 30 ft";
+/// let options = ConvertOptions::new()
+///     .precision(1)
+///     .units(&[Unit::Metric(Centimeter), Unit::Metric(Meter)]);
+///
+/// assert_eq!(
+///     "Width: 61.0 cm, height: 1.9 m, cable: 9.1 m",
+///     text::convert_all_with(spec, UnitSystem::Metric, &options)
+/// );
+/// ```
+pub fn convert_all_with(text: &str, target_system: UnitSystem, options: &ConvertOptions) -> String {
+    let display_options = DisplayOptions {
+        precision: Some(options.precision),
+        ..DisplayOptions::default()
+    };
+
+    let mut converted = String::with_capacity(text.len());
+    let mut position = 0;
+    for (length, span) in Length::find_all(text) {
+        if options.is_allowed(length.unit, target_system) {
+            continue;
+        }
+        converted.push_str(&text[position..span.start]);
+        length
+            .convert(options.target_unit(&length, target_system))
+            .format_into(&mut converted, display_options)
+            .expect("writing into a String never fails");
+        position = span.end;
+    }
+    converted.push_str(&text[position..]);
+
+    converted
+}
+
+impl Length {
+    /// Finds all lengths in a text and returns them with their byte spans.
+    ///
+    /// Every form, that Length::parse(...) accepts, is found, like "1.2 km", "6 ft 2 in", "3 1/2
+    /// in" or 5'10". Where several forms start at the same number, the longest one is taken.
+    /// Punctuation right after a length is not a part of it. As the text is not understood, a
+    /// number followed by a word, that is also a unit, is always a length, like "5 in" in "5 in
+    /// total".
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    ///
+    /// let text = "The bridge is 1.2 km long and 15 m wide.";
+    /// let lengths = Length::find_all(text);
+    ///
+    /// assert_eq!(2, lengths.len());
+    /// assert_eq!(1.2, lengths[0].0.value);
+    /// assert_eq!(Unit::Metric(Kilometer), lengths[0].0.unit);
+    /// assert_eq!("1.2 km", &text[lengths[0].1.clone()]);
+    /// assert_eq!("15 m", &text[lengths[1].1.clone()]);
+    /// ```
+    pub fn find_all(text: &str) -> Vec<(Length, Range<usize>)> {
+        let mut lengths = Vec::new();
+        let mut position = 0;
+        while position < text.len() {
+            let next = &text[position..];
+            let c = next.chars().next().expect("position is before the end");
+            if Length::starts_number(text, position) {
+                if let Some((length, end)) = Length::longest_length_at(text, position) {
+                    lengths.push((length, position..end));
+                    position = end;
+                    continue;
+                }
+            }
+            position += c.len_utf8();
+        }
+
+        lengths
+    }
+
+    /// Checks, if a number starts at the position, that is not a part of a word or of another
+    /// number.
+    fn starts_number(text: &str, position: usize) -> bool {
+        let mut chars = text[position..].chars();
+        let starts_with_digit = match chars.next() {
+            Some('+') | Some('-') => chars.next().is_some_and(|c| c.is_ascii_digit()),
+            Some(c) => c.is_ascii_digit(),
+            None => false,
+        };
+        let after_boundary = text[..position]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric() && c != '.' && c != ',');
+
+        starts_with_digit && after_boundary
+    }
+
+    /// Gets the longest length, that starts at the position, with its end.
+    fn longest_length_at(text: &str, start: usize) -> Option<(Length, usize)> {
+        let mut token_ends = Vec::with_capacity(MAX_TOKENS);
+        let mut in_token = false;
+        for (index, c) in text[start..].char_indices() {
+            if c.is_whitespace() {
+                if in_token {
+                    token_ends.push(start + index);
+                    if token_ends.len() == MAX_TOKENS {
+                        break;
+                    }
+                }
+                in_token = false;
+            } else {
+                in_token = true;
+            }
+        }
+        if in_token && token_ends.len() < MAX_TOKENS {
+            token_ends.push(text.len());
+        }
+
+        token_ends.iter().rev().find_map(|&token_end| {
+            // Punctuation like a full stop or a closing bracket is stripped one by one, as long
+            // as the rest can not be parsed.
+            let mut end = token_end;
+            loop {
+                if let Ok(length) = Length::parse(&text[start..end]) {
+                    return Some((length, end));
+                }
+                let last = text[start..end].chars().next_back()?;
+                if last.is_alphanumeric() || last.is_whitespace() {
+                    return None;
+                }
+                end -= last.len_utf8();
+            }
+        })
+    }
+}
@@ -0,0 +1,194 @@
+//! Traditional units of China, Japan, Russia and Scandinavia.
+//!
+//! The Chinese units are the market units (shìzhì) of 1929, based on the chi of 1/3 meter. The
+//! Japanese units are the shakkanhō units as defined in 1891, based on the shaku of 10/33 meter.
+//! The Russian units are the units of 1835, based on the arshin of 28 inches. The Scandinavian
+//! mil is the metric mile of 10 kilometers, that is still used in Sweden and Norway.
+//!
+//! The Scandinavian mil is written as "mil", but as "mil" is parsed as a thou (a thousandth of
+//! an inch) by default, its symbol in this crate is "smil", so a formatted length can be parsed
+//! again. "mil" is parsed as Scandinavian mil with LengthParser::scandinavian_mil(true).
+//!
+//! A length in one of these units is normalized within the units of its own country, so 3000
+//! chi become 2 li, but never ri or versts.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::{SiblingUnit, Unit};
+
+/// The traditional units of China, Japan, Russia and Scandinavia.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub enum TraditionalUnit {
+    /// The Chinese cun (寸) of 1/10 chi.
+    Cun,
+    /// The Chinese chi (尺) of 1/3 meter.
+    Chi,
+    /// The Chinese zhang (丈) of 10 chi.
+    Zhang,
+    /// The Chinese li (里) of 1500 chi, that is 500 meters.
+    Li,
+    /// The Japanese shaku (尺) of 10/33 meter.
+    Shaku,
+    /// The Japanese ken (間) of 6 shaku.
+    Ken,
+    /// The Japanese ri (里) of 12960 shaku.
+    Ri,
+    /// The Russian arshin (аршин) of 28 inches.
+    Arshin,
+    /// The Russian verst (верста) of 1500 arshins.
+    Verst,
+    /// The Scandinavian mil of 10 kilometers.
+    Mil,
+}
+
+impl TraditionalUnit {
+    /// Gets the amount of meters in one of this unit. Unlike Unit::meters_per_unit(), it can be
+    /// evaluated in const contexts.
+    ///
+    /// # Example
+    /// ```
+    /// use length::TraditionalUnit::*;
+    ///
+    /// const FACTOR: f64 = Li.meters_per_unit();
+    ///
+    /// assert_eq!(500.0, FACTOR);
+    /// ```
+    pub const fn meters_per_unit(&self) -> f64 {
+        match self {
+            TraditionalUnit::Cun => 1.0 / 30.0,
+            TraditionalUnit::Chi => 1.0 / 3.0,
+            TraditionalUnit::Zhang => 10.0 / 3.0,
+            TraditionalUnit::Li => 500.0,
+            TraditionalUnit::Shaku => 10.0 / 33.0,
+            TraditionalUnit::Ken => 20.0 / 11.0,
+            TraditionalUnit::Ri => 43_200.0 / 11.0,
+            TraditionalUnit::Arshin => 0.711_2,
+            TraditionalUnit::Verst => 1_066.8,
+            TraditionalUnit::Mil => 10_000.0,
+        }
+    }
+
+    /// Gets the symbol of this unit, which is its romanized name, like "shaku", or "smil" for the
+    /// Scandinavian mil.
+    ///
+    /// # Example
+    /// ```
+    /// use length::TraditionalUnit::*;
+    ///
+    /// assert_eq!("shaku", Shaku.symbol());
+    /// assert_eq!("verst", Verst.symbol());
+    /// assert_eq!("smil", Mil.symbol());
+    /// ```
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TraditionalUnit::Cun => "cun",
+            TraditionalUnit::Chi => "chi",
+            TraditionalUnit::Zhang => "zhang",
+            TraditionalUnit::Li => "li",
+            TraditionalUnit::Shaku => "shaku",
+            TraditionalUnit::Ken => "ken",
+            TraditionalUnit::Ri => "ri",
+            TraditionalUnit::Arshin => "arshin",
+            TraditionalUnit::Verst => "verst",
+            TraditionalUnit::Mil => "smil",
+        }
+    }
+
+    /// Gets the English name of this unit in singular, like "arshin".
+    ///
+    /// # Example
+    /// ```
+    /// use length::TraditionalUnit::*;
+    ///
+    /// assert_eq!("arshin", Arshin.name());
+    /// ```
+    pub fn name(&self) -> &'static str {
+        Unit::from(*self)
+            .names()
+            .map_or("", |(singular, _)| singular)
+    }
+
+    /// Gets the English name of this unit in plural, like "arshins". The Chinese and Japanese
+    /// names have no plural form.
+    ///
+    /// # Example
+    /// ```
+    /// use length::TraditionalUnit::*;
+    ///
+    /// assert_eq!("arshins", Arshin.plural_name());
+    /// assert_eq!("li", Li.plural_name());
+    /// ```
+    pub fn plural_name(&self) -> &'static str {
+        Unit::from(*self).names().map_or("", |(_, plural)| plural)
+    }
+
+    /// Gets the amount of meters in one unit as numerator and denominator.
+    pub(crate) const fn exact_meters_per_unit(&self) -> Option<(i128, i128)> {
+        match self {
+            TraditionalUnit::Cun => Some((1, 30)),
+            TraditionalUnit::Chi => Some((1, 3)),
+            TraditionalUnit::Zhang => Some((10, 3)),
+            TraditionalUnit::Li => Some((500, 1)),
+            TraditionalUnit::Shaku => Some((10, 33)),
+            TraditionalUnit::Ken => Some((20, 11)),
+            TraditionalUnit::Ri => Some((43_200, 11)),
+            TraditionalUnit::Arshin => Some((7_112, 10_000)),
+            TraditionalUnit::Verst => Some((10_668, 10)),
+            TraditionalUnit::Mil => Some((10_000, 1)),
+        }
+    }
+}
+
+impl SiblingUnit for TraditionalUnit {
+    fn smaller_unit(&self) -> Option<Unit> {
+        match self {
+            TraditionalUnit::Cun => None,
+            TraditionalUnit::Chi => Some(Unit::Traditional(TraditionalUnit::Cun)),
+            TraditionalUnit::Zhang => Some(Unit::Traditional(TraditionalUnit::Chi)),
+            TraditionalUnit::Li => Some(Unit::Traditional(TraditionalUnit::Zhang)),
+            TraditionalUnit::Shaku => None,
+            TraditionalUnit::Ken => Some(Unit::Traditional(TraditionalUnit::Shaku)),
+            TraditionalUnit::Ri => Some(Unit::Traditional(TraditionalUnit::Ken)),
+            TraditionalUnit::Arshin => None,
+            TraditionalUnit::Verst => Some(Unit::Traditional(TraditionalUnit::Arshin)),
+            TraditionalUnit::Mil => None,
+        }
+    }
+
+    fn greater_unit(&self) -> Option<Unit> {
+        match self {
+            TraditionalUnit::Cun => Some(Unit::Traditional(TraditionalUnit::Chi)),
+            TraditionalUnit::Chi => Some(Unit::Traditional(TraditionalUnit::Zhang)),
+            TraditionalUnit::Zhang => Some(Unit::Traditional(TraditionalUnit::Li)),
+            TraditionalUnit::Li => None,
+            TraditionalUnit::Shaku => Some(Unit::Traditional(TraditionalUnit::Ken)),
+            TraditionalUnit::Ken => Some(Unit::Traditional(TraditionalUnit::Ri)),
+            TraditionalUnit::Ri => None,
+            TraditionalUnit::Arshin => Some(Unit::Traditional(TraditionalUnit::Verst)),
+            TraditionalUnit::Verst => None,
+            TraditionalUnit::Mil => None,
+        }
+    }
+}
+
+impl fmt::Display for TraditionalUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.symbol())
+    }
+}
+
+impl Ord for TraditionalUnit {
+    /// Orders the units by their size in meters.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.meters_per_unit().total_cmp(&other.meters_per_unit())
+    }
+}
+
+impl PartialOrd for TraditionalUnit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,41 @@
+//! Conversions between Length and the length quantity of the uom crate (feature `uom`).
+
+use ::uom::si::f64::Length as UomLength;
+use ::uom::si::length::meter;
+
+use crate::{Length, MetricUnit::Meter};
+
+impl From<Length> for UomLength {
+    /// Converts the length into a uom length, that is stored in meters.
+    ///
+    /// # Example
+    /// ```
+    /// use length::Length;
+    /// use uom::si::{f64::Length as UomLength, length::foot};
+    ///
+    /// let length: UomLength = Length::new_string("1 mi").unwrap().into();
+    ///
+    /// assert!((length.get::<foot>() - 5_280.0).abs() < 1e-9);
+    /// ```
+    fn from(length: Length) -> UomLength {
+        UomLength::new::<meter>(length.convert(Meter).value)
+    }
+}
+
+impl From<UomLength> for Length {
+    /// Converts the uom length into a Length in meters.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Unit, MetricUnit::*};
+    /// use uom::si::{f64::Length as UomLength, length::kilometer};
+    ///
+    /// let length = Length::from(UomLength::new::<kilometer>(2.5));
+    ///
+    /// assert_eq!(2_500.0, length.value);
+    /// assert_eq!(Unit::Metric(Meter), length.unit);
+    /// ```
+    fn from(length: UomLength) -> Length {
+        Length::new_value_unit(length.get::<meter>(), Meter)
+    }
+}
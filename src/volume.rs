@@ -0,0 +1,211 @@
+use std::fmt;
+use std::ops::Mul;
+
+use crate::{
+    Area, AstronomicUnit, ImperialUnit, Length, MetricUnit, SubatomicUnit, TraditionalUnit,
+    TypographicUnit, Unit, UsSurveyUnit,
+};
+
+/// The unit of a volume, that is either the cube of a length unit or a unit of capacity.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub enum VolumeUnit {
+    Cubic(Unit),
+    Milliliter,
+    Liter,
+    UsGallon,
+    ImperialGallon,
+}
+
+impl VolumeUnit {
+    /// Gets the amount of cubic meters in one of this unit.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{VolumeUnit, MetricUnit::*};
+    ///
+    /// assert_eq!(0.001, VolumeUnit::Liter.cubic_meters_per_unit());
+    /// assert_eq!(1.0, VolumeUnit::from(Meter).cubic_meters_per_unit());
+    /// ```
+    pub fn cubic_meters_per_unit(&self) -> f64 {
+        match self {
+            VolumeUnit::Cubic(unit) => unit.meters_per_unit().powi(3),
+            VolumeUnit::Milliliter => 0.000_001,
+            VolumeUnit::Liter => 0.001,
+            VolumeUnit::UsGallon => 0.003_785_411_784,
+            VolumeUnit::ImperialGallon => 0.004_546_09,
+        }
+    }
+}
+
+impl fmt::Display for VolumeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VolumeUnit::Cubic(unit) => write!(f, "{}³", unit),
+            VolumeUnit::Milliliter => write!(f, "ml"),
+            VolumeUnit::Liter => write!(f, "l"),
+            VolumeUnit::UsGallon => write!(f, "gal"),
+            VolumeUnit::ImperialGallon => write!(f, "imp gal"),
+        }
+    }
+}
+
+impl From<Unit> for VolumeUnit {
+    fn from(unit: Unit) -> Self {
+        VolumeUnit::Cubic(unit)
+    }
+}
+
+impl From<AstronomicUnit> for VolumeUnit {
+    fn from(unit: AstronomicUnit) -> Self {
+        VolumeUnit::Cubic(unit.into())
+    }
+}
+
+impl From<ImperialUnit> for VolumeUnit {
+    fn from(unit: ImperialUnit) -> Self {
+        VolumeUnit::Cubic(unit.into())
+    }
+}
+
+impl From<MetricUnit> for VolumeUnit {
+    fn from(unit: MetricUnit) -> Self {
+        VolumeUnit::Cubic(unit.into())
+    }
+}
+
+impl From<SubatomicUnit> for VolumeUnit {
+    fn from(unit: SubatomicUnit) -> Self {
+        VolumeUnit::Cubic(unit.into())
+    }
+}
+
+#[cfg(feature = "historical")]
+impl From<crate::HistoricalUnit> for VolumeUnit {
+    fn from(unit: crate::HistoricalUnit) -> Self {
+        VolumeUnit::Cubic(unit.into())
+    }
+}
+
+impl From<TraditionalUnit> for VolumeUnit {
+    fn from(unit: TraditionalUnit) -> Self {
+        VolumeUnit::Cubic(unit.into())
+    }
+}
+
+impl From<TypographicUnit> for VolumeUnit {
+    fn from(unit: TypographicUnit) -> Self {
+        VolumeUnit::Cubic(unit.into())
+    }
+}
+
+impl From<UsSurveyUnit> for VolumeUnit {
+    fn from(unit: UsSurveyUnit) -> Self {
+        VolumeUnit::Cubic(unit.into())
+    }
+}
+
+/// A volume, that is represented by a value in a volume unit.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct Volume {
+    pub unit: VolumeUnit,
+    pub value: f64,
+}
+
+impl Volume {
+    /// Gets a new Volume struct with the given value and unit.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Volume, VolumeUnit, MetricUnit::*};
+    ///
+    /// let tank = Volume::new_value_unit(200, VolumeUnit::Liter);
+    /// let box_volume = Volume::new_value_unit(1.5, Meter);
+    ///
+    /// assert_eq!(200.0, tank.value);
+    /// assert_eq!(VolumeUnit::Cubic(Meter.into()), box_volume.unit);
+    /// ```
+    pub fn new_value_unit<T: Into<f64>, U: Into<VolumeUnit>>(value: T, unit: U) -> Self {
+        Volume {
+            unit: unit.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Gets the volume of a box with the given sides, in the cube of the unit of the first side.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, Volume, VolumeUnit, MetricUnit::*};
+    ///
+    /// let width = Length::new_string("2m").unwrap();
+    /// let depth = Length::new_string("50cm").unwrap();
+    /// let height = Length::new_string("1500mm").unwrap();
+    /// let volume = Volume::from_lengths(&width, &depth, &height);
+    ///
+    /// assert_eq!(1.5, volume.value);
+    /// assert_eq!(VolumeUnit::Cubic(Meter.into()), volume.unit);
+    /// ```
+    pub fn from_lengths(a: &Length, b: &Length, c: &Length) -> Self {
+        Area::from_lengths(a, b) * c.clone()
+    }
+
+    /// Converts this volume into the given unit and returns a new Volume-struct.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Volume, VolumeUnit, MetricUnit::*};
+    ///
+    /// let one_cubic_meter = Volume::new_value_unit(1, Meter);
+    ///
+    /// assert_eq!(1_000.0, one_cubic_meter.to(VolumeUnit::Liter).value);
+    /// assert_eq!(1_000_000.0, one_cubic_meter.to(Centimeter).value);
+    /// ```
+    pub fn to<T: Into<VolumeUnit>>(&self, destination_unit: T) -> Self {
+        let destination_unit = destination_unit.into();
+        let value = match (self.unit, destination_unit) {
+            (VolumeUnit::Cubic(from), VolumeUnit::Cubic(to)) => {
+                let factor = Length::new_value_unit(1, from).convert(to).value;
+                self.value * factor * factor * factor
+            }
+            _ if self.unit == destination_unit => self.value,
+            _ => {
+                self.value * self.unit.cubic_meters_per_unit()
+                    / destination_unit.cubic_meters_per_unit()
+            }
+        };
+
+        Volume {
+            unit: destination_unit,
+            value,
+        }
+    }
+}
+
+impl Default for Volume {
+    fn default() -> Volume {
+        Volume::new_value_unit(0, MetricUnit::Meter)
+    }
+}
+
+impl fmt::Display for Volume {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.value, self.unit)
+    }
+}
+
+impl Mul<Length> for Area {
+    type Output = Volume;
+
+    /// Gets the volume of a prism with this base area and the given height, in the cube of the
+    /// unit of the area.
+    fn mul(self, height: Length) -> Volume {
+        Volume {
+            unit: VolumeUnit::Cubic(self.unit),
+            value: self.value * height.convert(self.unit).value,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! WebAssembly bindings via wasm-bindgen (feature `wasm`).
+//!
+//! To build the module for the browser, compile the crate as a `cdylib` for wasm32, e.g. with
+//! `wasm-pack build --target web --features wasm` after adding `crate-type = ["cdylib", "rlib"]`,
+//! or with `cargo rustc --release --target wasm32-unknown-unknown --features wasm --crate-type
+//! cdylib`. The default hand-written parser does not need the regex crate.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{Length, Unit};
+
+fn parse_unit(unit: &str) -> Result<Unit, JsError> {
+    unit.parse::<Unit>()
+        .map_err(|_| JsError::new(&format!("unknown unit: {}", unit)))
+}
+
+/// A length, that is exposed to JavaScript.
+#[wasm_bindgen(js_name = "JsLength")]
+pub struct JsLength {
+    inner: Length,
+}
+
+#[wasm_bindgen(js_class = "JsLength")]
+impl JsLength {
+    /// Creates a new length with the given value and unit symbol.
+    #[wasm_bindgen(constructor)]
+    pub fn new(value: f64, unit: &str) -> Result<JsLength, JsError> {
+        Ok(JsLength {
+            inner: Length::new_value_unit(value, parse_unit(unit)?),
+        })
+    }
+
+    /// Parses a string like "5 km" into a length.
+    pub fn parse(string: &str) -> Result<JsLength, JsError> {
+        Length::parse(string)
+            .map(|inner| JsLength { inner })
+            .map_err(|error| JsError::new(&error.to_string()))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.inner.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn unit(&self) -> String {
+        self.inner.unit.to_string()
+    }
+
+    /// Converts the length into the unit with the given symbol or name.
+    pub fn convert(&self, unit: &str) -> Result<JsLength, JsError> {
+        Ok(JsLength {
+            inner: self.inner.to(parse_unit(unit)?),
+        })
+    }
+
+    pub fn normalize(&self) -> JsLength {
+        JsLength {
+            inner: self.inner.normalize(),
+        }
+    }
+
+    #[wasm_bindgen(js_name = "toString")]
+    pub fn to_js_string(&self) -> String {
+        self.inner.to_string()
+    }
+}
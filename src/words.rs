@@ -0,0 +1,206 @@
+//! Lengths spelled out in English words, like "three and a half inches", for screen readers and
+//! text-to-speech.
+
+use crate::Length;
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// The names of the powers of a thousand, in the short scale.
+const SCALES: [&str; 5] = ["", "thousand", "million", "billion", "trillion"];
+
+/// The greatest value, that is spelled out with its digits, greater values are spelled out in
+/// scientific notation.
+const MAX_DECIMAL: f64 = 1e15;
+
+/// The smallest value, that is spelled out with its digits, smaller values are spelled out in
+/// scientific notation.
+const MIN_DECIMAL: f64 = 1e-6;
+
+impl Length {
+    /// Spells the length out in English words, with the full unit name, like "five kilometers"
+    /// or "three and a half inches".
+    ///
+    /// Halves and quarters are spelled out as such, other fractions digit by digit, like "two
+    /// point seven five meters". Values from 10^15 and below 10^-6 are spelled out in scientific
+    /// notation.
+    ///
+    /// # Example
+    /// ```
+    /// use length::{Length, AstronomicUnit::*, ImperialUnit::*, MetricUnit::*};
+    ///
+    /// assert_eq!("five kilometers", Length::new_value_unit(5, Kilometer).to_words());
+    /// assert_eq!("three and a half inches", Length::new_value_unit(3.5, Inch).to_words());
+    /// assert_eq!("one foot", Length::new_value_unit(1, Foot).to_words());
+    /// assert_eq!("half an inch", Length::new_value_unit(0.5, Inch).to_words());
+    /// assert_eq!(
+    ///     "minus one point two light-years",
+    ///     Length::new_value_unit(-1.2, Lightyear).to_words()
+    /// );
+    /// ```
+    pub fn to_words(&self) -> String {
+        let sign = if self.value.is_sign_negative() && self.value != 0.0 {
+            "minus "
+        } else {
+            ""
+        };
+        let magnitude = self.value.abs();
+        let singular = self.unit.name();
+        let plural = self.unit.plural_name();
+
+        if magnitude.is_nan() {
+            return format!("not a number of {}", plural);
+        }
+        if magnitude.is_infinite() {
+            return format!("{}infinity {}", sign, plural);
+        }
+        if magnitude != 0.0 && !(MIN_DECIMAL..MAX_DECIMAL).contains(&magnitude) {
+            return format!("{}{} {}", sign, scientific_words(magnitude), plural);
+        }
+
+        let digits = magnitude.to_string();
+        let (whole, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+        let whole: u64 = whole.parse().expect("the whole part consists of digits");
+        let unit = if magnitude == 1.0 { &singular } else { &plural };
+
+        match (whole, fraction) {
+            (0, "5") => format!("{}half {} {}", sign, article(&singular), singular),
+            (0, "25") => format!("{}a quarter of {} {}", sign, article(&singular), singular),
+            (0, "75") => format!(
+                "{}three quarters of {} {}",
+                sign,
+                article(&singular),
+                singular
+            ),
+            (_, "5") => format!("{}{} and a half {}", sign, integer_words(whole), unit),
+            (_, "25") => format!("{}{} and a quarter {}", sign, integer_words(whole), unit),
+            (_, "75") => format!(
+                "{}{} and three quarters {}",
+                sign,
+                integer_words(whole),
+                unit
+            ),
+            (_, "") => format!("{}{} {}", sign, integer_words(whole), unit),
+            (_, _) => format!(
+                "{}{} point {} {}",
+                sign,
+                integer_words(whole),
+                digit_words(fraction),
+                unit
+            ),
+        }
+    }
+}
+
+/// Spells out an integer, like "one hundred twenty-three thousand four hundred fifty-six".
+fn integer_words(integer: u64) -> String {
+    if integer == 0 {
+        return ONES[0].to_string();
+    }
+
+    let mut groups = Vec::new();
+    let mut rest = integer;
+    let mut scale = 0;
+    while rest > 0 {
+        let group = rest % 1_000;
+        if group > 0 {
+            let words = group_words(group);
+            groups.push(match SCALES[scale] {
+                "" => words,
+                name => format!("{} {}", words, name),
+            });
+        }
+        rest /= 1_000;
+        scale += 1;
+    }
+    groups.reverse();
+
+    groups.join(" ")
+}
+
+/// Spells out an integer below 1000, like "four hundred fifty-six".
+fn group_words(group: u64) -> String {
+    let hundreds = (group / 100) as usize;
+    let rest = (group % 100) as usize;
+
+    let rest_words = match rest {
+        0 => String::new(),
+        1..=19 => ONES[rest].to_string(),
+        _ if rest.is_multiple_of(10) => TENS[rest / 10].to_string(),
+        _ => format!("{}-{}", TENS[rest / 10], ONES[rest % 10]),
+    };
+
+    match (hundreds, rest) {
+        (0, _) => rest_words,
+        (_, 0) => format!("{} hundred", ONES[hundreds]),
+        _ => format!("{} hundred {}", ONES[hundreds], rest_words),
+    }
+}
+
+/// Spells out digits one by one, like "seven five".
+fn digit_words(digits: &str) -> String {
+    digits
+        .chars()
+        .filter_map(|digit| digit.to_digit(10))
+        .map(|digit| ONES[digit as usize])
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Spells out a positive value in scientific notation, like "one point five times ten to the
+/// power of twenty-one".
+fn scientific_words(magnitude: f64) -> String {
+    let notation = format!("{:e}", magnitude);
+    let (mantissa, exponent) = notation
+        .split_once('e')
+        .expect("the scientific notation has an exponent");
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let exponent: i32 = exponent.parse().expect("the exponent is an integer");
+
+    let first_digit: usize = whole.parse().expect("the mantissa starts with a digit");
+
+    let mut words = ONES[first_digit].to_string();
+    if !fraction.is_empty() {
+        words.push_str(" point ");
+        words.push_str(&digit_words(fraction));
+    }
+    words.push_str(" times ten to the power of ");
+    if exponent < 0 {
+        words.push_str("minus ");
+    }
+    words.push_str(&integer_words(u64::from(exponent.unsigned_abs())));
+
+    words
+}
+
+/// Gets the indefinite article for a unit name, "an" before a vowel, like "an inch".
+fn article(name: &str) -> &'static str {
+    match name.chars().next() {
+        Some('a' | 'e' | 'i' | 'o' | 'A' | 'E' | 'I' | 'O') => "an",
+        _ => "a",
+    }
+}
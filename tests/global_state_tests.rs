@@ -0,0 +1,82 @@
+//! Tests of settings, that apply to the whole process, like the global default unit and the
+//! conversion observer. They run in
+//! their own test binary, so they do not affect the tests in integration_tests.rs, that run in
+//! parallel, and they are serialized by a lock.
+
+use std::sync::Mutex;
+
+use length::{ImperialUnit::*, Length, MetricUnit::*, Unit};
+
+static GLOBAL_STATE: Mutex<()> = Mutex::new(());
+
+#[test]
+fn test_global_default_unit() {
+    use length::config;
+
+    let _lock = GLOBAL_STATE
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+
+    config::set_default_unit(Foot);
+    let new = Length::new();
+    let default = Length::default();
+    let scoped = config::with_default_unit(Kilometer, Length::new);
+    let other_thread = std::thread::spawn(|| Length::new().unit).join().unwrap();
+    config::reset_default_unit();
+
+    assert_eq!(new.unit, Unit::Imperial(Foot));
+    assert_eq!(new.value, 0.0);
+    assert_eq!(default.unit, Unit::Imperial(Foot));
+    assert_eq!(scoped.unit, Unit::Metric(Kilometer));
+    assert_eq!(other_thread, Unit::Imperial(Foot));
+    assert_eq!(Length::new().unit, Unit::Metric(Meter));
+}
+
+#[test]
+fn test_conversion_observer() {
+    use std::sync::{Arc, Mutex};
+
+    let _lock = GLOBAL_STATE
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let observer_log = Arc::clone(&log);
+    length::set_conversion_observer(move |conversion| {
+        // Conversions of the observer itself are not observed again.
+        let in_meters = Length::new_value_unit(conversion.converted_value, conversion.to).to(Meter);
+        observer_log
+            .lock()
+            .unwrap()
+            .push((*conversion, in_meters.value));
+    });
+
+    let rods = Length::new_value_unit(123.25, Rod);
+    let chains = rods.to(Chain);
+    let mut in_place = rods.clone();
+    in_place.to_by_ref(Meter);
+    let _ = rods.to(Rod);
+    let _ = rods.checked_to(Yard);
+    assert!(rods > chains.clone() - Length::new_value_unit(1, Chain));
+    assert_eq!(rods, chains);
+    let _ = rods.clone() + Length::new_value_unit(1, Foot);
+    let _ = length::stats::median(&[rods.clone(), in_place.clone()]);
+    length::clear_conversion_observer();
+    let _ = rods.to(Yard);
+
+    let log = log.lock().unwrap();
+    assert_eq!(3, log.len());
+    assert_eq!(
+        length::Conversion {
+            from: Unit::Imperial(Rod),
+            to: Unit::Imperial(Chain),
+            value: 123.25,
+            converted_value: chains.value,
+        },
+        log[0].0
+    );
+    assert_eq!(Unit::Metric(Meter), log[1].0.to);
+    assert_eq!(in_place.value, log[1].0.converted_value);
+    assert_eq!(in_place.value, log[1].1);
+    assert_eq!(Unit::Imperial(Yard), log[2].0.to);
+}
@@ -1,6 +1,28 @@
 extern crate length;
 
-use length::{AstronomicUnit::*, ImperialUnit::*, Length, MetricUnit::*, Unit};
+use std::f64::consts::PI;
+use std::time::Duration;
+
+use length::geometry::{Circle, Rectangle, Square};
+use length::{geo, stats};
+use length::{
+    length, Area, AstronomicUnit::*, CustomUnit, DisplayOptions, ExactLength, ImperialUnit::*,
+    Length, LengthError, LengthParser, LengthRange, MetricUnit::*, ParseLengthError,
+    SubatomicUnit::*, TypographicUnit::*, Unit, UnitSystem, UsSurveyUnit::*, Validation, Volume,
+    VolumeUnit,
+};
+
+/// Asserts, that a converted value is within a relative tolerance of 1e-12 of the expected value.
+fn assert_approx(actual: f64, expected: f64) {
+    let tolerance = expected.abs() * 1e-12;
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "{} is not within {} of {}",
+        actual,
+        tolerance,
+        expected
+    );
+}
 
 #[test]
 fn test_new() {
@@ -359,15 +381,15 @@ fn test_au_to_x() {
 
     let au_to_ly = au.to(Unit::Astronomic(Lightyear));
     assert_eq!(au_to_ly.unit, Unit::Astronomic(Lightyear));
-    assert_eq!(au_to_ly.value, 0.000_015_812_507_409_820_66);
+    assert_approx(au_to_ly.value, 0.000_015_812_507_409_820_66);
 
     let au_to_au = au.to(Unit::Astronomic(AstronomicalUnit));
     assert_eq!(au_to_au.unit, Unit::Astronomic(AstronomicalUnit));
-    assert_eq!(au_to_au.value, 1.0);
+    assert_approx(au_to_au.value, 1.0);
 
     let au_to_pc = au.to(Unit::Astronomic(Parsec));
     assert_eq!(au_to_pc.unit, Unit::Astronomic(Parsec));
-    assert_eq!(au_to_pc.value, 0.000_004_848_136_811_095_361);
+    assert_approx(au_to_pc.value, 0.000_004_848_136_811_095_361);
 }
 
 #[test]
@@ -376,31 +398,31 @@ fn test_ly_to_x() {
 
     let ly_to_ld = ly.to(Unit::Astronomic(Lightday));
     assert_eq!(ly_to_ld.unit, Unit::Astronomic(Lightday));
-    assert_eq!(ly_to_ld.value, 365.25);
+    assert_approx(ly_to_ld.value, 365.25);
 
     let ly_to_lh = ly.to(Unit::Astronomic(Lighthour));
     assert_eq!(ly_to_lh.unit, Unit::Astronomic(Lighthour));
-    assert_eq!(ly_to_lh.value, 365.25 * 24.0);
+    assert_approx(ly_to_lh.value, 365.25 * 24.0);
 
     let ly_to_lm = ly.to(Unit::Astronomic(Lightminute));
     assert_eq!(ly_to_lm.unit, Unit::Astronomic(Lightminute));
-    assert_eq!(ly_to_lm.value, 365.25 * 24.0 * 60.0);
+    assert_approx(ly_to_lm.value, 365.25 * 24.0 * 60.0);
 
     let ly_to_ls = ly.to(Unit::Astronomic(Lightsecond));
     assert_eq!(ly_to_ls.unit, Unit::Astronomic(Lightsecond));
-    assert_eq!(ly_to_ls.value, 31_557_600.000_000_004); // 365.25 * 24.0 * 60.0 * 60.0
+    assert_approx(ly_to_ls.value, 31_557_600.000_000_004); // 365.25 * 24.0 * 60.0 * 60.0
 
     let ly_to_au = ly.to(Unit::Astronomic(AstronomicalUnit));
     assert_eq!(ly_to_au.unit, Unit::Astronomic(AstronomicalUnit));
-    assert_eq!(ly_to_au.value, 63_241.077_084_266_275);
+    assert_approx(ly_to_au.value, 63_241.077_084_266_275);
 
     let ly_to_ly = ly.to(Unit::Astronomic(Lightyear));
     assert_eq!(ly_to_ly.unit, Unit::Astronomic(Lightyear));
-    assert_eq!(ly_to_ly.value, 1.0);
+    assert_approx(ly_to_ly.value, 1.0);
 
     let ly_to_pc = ly.to(Unit::Astronomic(Parsec));
     assert_eq!(ly_to_pc.unit, Unit::Astronomic(Parsec));
-    assert_eq!(ly_to_pc.value, 0.306_601_393_785_550_57);
+    assert_approx(ly_to_pc.value, 0.306_601_393_785_550_57);
 }
 
 #[test]
@@ -409,15 +431,15 @@ fn test_pc_to_x() {
 
     let pc_to_au = pc.to(Unit::Astronomic(AstronomicalUnit));
     assert_eq!(pc_to_au.unit, Unit::Astronomic(AstronomicalUnit));
-    assert_eq!(pc_to_au.value, 206_264.806_247_096_36);
+    assert_approx(pc_to_au.value, 206_264.806_247_096_36);
 
     let pc_to_ly = pc.to(Unit::Astronomic(Lightyear));
     assert_eq!(pc_to_ly.unit, Unit::Astronomic(Lightyear));
-    assert_eq!(pc_to_ly.value, 3.261_563_777_167_433_7);
+    assert_approx(pc_to_ly.value, 3.261_563_777_167_433_7);
 
     let pc_to_pc = pc.to(Unit::Astronomic(Parsec));
     assert_eq!(pc_to_pc.unit, Unit::Astronomic(Parsec));
-    assert_eq!(pc_to_pc.value, 1.0);
+    assert_approx(pc_to_pc.value, 1.0);
 }
 
 #[test]
@@ -439,11 +461,11 @@ fn test_metric_to_astronomic() {
 
     let km_to_au = km.to(Unit::Astronomic(AstronomicalUnit));
     assert_eq!(km_to_au.unit, Unit::Astronomic(AstronomicalUnit));
-    assert_eq!(km_to_au.value, 63_241.077_084_266_275);
+    assert_approx(km_to_au.value, 63_241.077_084_266_275);
 
     let km_to_ly = km.to(Unit::Astronomic(Lightyear));
     assert_eq!(km_to_ly.unit, Unit::Astronomic(Lightyear));
-    assert_eq!(km_to_ly.value, 1.0);
+    assert_approx(km_to_ly.value, 1.0);
 }
 
 #[test]
@@ -467,11 +489,11 @@ fn test_imperial_to_astronomic() {
 
     let mi_to_au = mi.to(Unit::Astronomic(AstronomicalUnit));
     assert_eq!(mi_to_au.unit, Unit::Astronomic(AstronomicalUnit));
-    assert_eq!(mi_to_au.value, 63_241.077_084_266_275);
+    assert_approx(mi_to_au.value, 63_241.077_084_266_275);
 
     let mi_to_ly = mi.to(Unit::Astronomic(Lightyear));
     assert_eq!(mi_to_ly.unit, Unit::Astronomic(Lightyear));
-    assert_eq!(mi_to_ly.value, 1.0);
+    assert_approx(mi_to_ly.value, 1.0);
 }
 
 #[test]
@@ -615,3 +637,3620 @@ fn test_normalize() {
     assert_eq!(5.0, five_meter_as_km_normalized.value);
     assert_eq!(Unit::Metric(Meter), five_meter_as_km_normalized.unit);
 }
+
+#[test]
+fn test_conformance() {
+    let report = length::conformance::verify_all();
+
+    assert_eq!(report.failures(), vec![]);
+    assert!(report.is_ok());
+}
+
+#[test]
+fn test_format_into() {
+    let length = Length::new_value_unit(2.54, Unit::Metric(Centimeter));
+    let mut output = String::new();
+
+    length
+        .format_into(&mut output, DisplayOptions::default())
+        .unwrap();
+    assert_eq!(output, "2.54 cm");
+
+    output.clear();
+    length
+        .format_into(
+            &mut output,
+            DisplayOptions {
+                precision: Some(0),
+                ..DisplayOptions::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(output, "3 cm");
+}
+
+#[test]
+fn test_area_to() {
+    let area = Area::new_value_unit(1, Unit::Imperial(Foot));
+    let in_square_inch = area.to(Unit::Imperial(Inch));
+
+    assert_eq!(in_square_inch.value, 144.0);
+    assert_eq!(in_square_inch.unit, Unit::Imperial(Inch));
+    assert_eq!(in_square_inch.to_string(), "144 in²");
+}
+
+#[test]
+fn test_geometry() {
+    let rectangle = Rectangle::new(
+        Length::new_string("6m").unwrap(),
+        Length::new_string("800cm").unwrap(),
+    );
+    assert_eq!(rectangle.perimeter().value, 28.0);
+    assert_eq!(rectangle.area().value, 48.0);
+    assert_eq!(rectangle.diagonal().value, 10.0);
+    assert_eq!(rectangle.diagonal().unit, Unit::Metric(Meter));
+
+    let square = Square::new(Length::new_string("2in").unwrap());
+    assert_eq!(square.perimeter().value, 8.0);
+    assert_eq!(square.area().value, 4.0);
+
+    let circle = Circle::new(Length::new_string("3km").unwrap());
+    assert_eq!(circle.diameter().value, 6.0);
+    assert_eq!(circle.area().unit, Unit::Metric(Kilometer));
+}
+
+#[test]
+fn test_error_bound() {
+    let untracked = Length::new_string("1mi")
+        .unwrap()
+        .to(Unit::Metric(Kilometer));
+    assert_eq!(untracked.error_bound(), None);
+
+    let measured = Length::new_string("1mi").unwrap().with_error_bound(0.001);
+    let mut converted = measured.clone();
+    for _ in 0..10 {
+        converted = converted
+            .to(Unit::Metric(Kilometer))
+            .to(Unit::Imperial(Mile));
+    }
+    let error_bound = converted.error_bound().unwrap();
+    assert!(error_bound > 0.001);
+    assert!(error_bound < 0.001 + 1e-12);
+
+    let sum = measured.add(Length::new_string("1km").unwrap().with_error_bound(0.002));
+    assert!(sum.error_bound().unwrap() > 0.001 + 0.002 / 1.609344);
+}
+
+#[test]
+fn test_unit_codes() {
+    assert_eq!(Unit::Metric(Quectometer).code(), 100);
+    assert_eq!(Unit::Metric(Kilometer).code(), 115);
+    assert_eq!(Unit::Imperial(Inch).code(), 200);
+    assert_eq!(Unit::Astronomic(Megaparsec).code(), 308);
+
+    for code in 0..1000 {
+        if let Some(unit) = Unit::try_from_code(code) {
+            assert_eq!(unit.code(), code);
+        }
+    }
+    assert_eq!(Unit::try_from_code(399), None);
+}
+
+#[test]
+fn test_to_bytes_and_from_bytes() {
+    let length = Length::new_value_unit(-2.5, Unit::Imperial(Foot));
+    let bytes = length.to_bytes();
+    assert_eq!(bytes.len(), 12);
+    assert_eq!(&bytes[..4], &[1, 0, 201, 0]);
+
+    let decoded = Length::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.value, -2.5);
+    assert_eq!(decoded.unit, Unit::Imperial(Foot));
+    assert_eq!(decoded.get_original_string(), "");
+
+    let parsed = Length::new_string("3 ly").unwrap();
+    let decoded = Length::from_bytes(&parsed.to_bytes()).unwrap();
+    assert_eq!(decoded.get_original_string(), "3 ly");
+
+    assert!(Length::from_bytes(&[]).is_none());
+    assert!(Length::from_bytes(&[2, 0, 201, 0, 0, 0, 0, 0, 0, 0, 0, 0]).is_none());
+    assert!(Length::from_bytes(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]).is_none());
+    assert!(Length::from_bytes(&parsed.to_bytes()[..17]).is_none());
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(Length::from_bytes(&trailing).is_none());
+    let mut trailing = parsed.to_bytes();
+    trailing.extend_from_slice(b"!");
+    assert!(Length::from_bytes(&trailing).is_none());
+    let mut huge = parsed.to_bytes();
+    huge[12..16].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert!(Length::from_bytes(&huge).is_none());
+}
+
+#[cfg(feature = "ts-rs")]
+#[test]
+fn test_ts_rs_declarations() {
+    use ts_rs::TS;
+
+    assert_eq!(
+        <Length>::decl(),
+        "type Length = { unit: Unit, value: number, };"
+    );
+    let historical = if cfg!(feature = "historical") {
+        "{ \"Historical\": HistoricalUnit } | "
+    } else {
+        ""
+    };
+    assert!(Unit::decl().starts_with(&format!(
+        "type Unit = {{ \"Astronomic\": AstronomicUnit }} | {{ \"Custom\": CustomUnit }} | {}{{ \"Imperial\": ImperialUnit }} |",
+        historical
+    )));
+    assert_eq!(
+        length::ImperialUnit::decl(),
+        "type ImperialUnit = \"Thou\" | \"Inch\" | \"Hand\" | \"Foot\" | \"Yard\" | \"Rod\" | \"Chain\" | \"Furlong\" | \"Mile\" | \"League\";"
+    );
+}
+
+#[cfg(feature = "uniffi")]
+#[test]
+fn test_mobile_length() {
+    use length::mobile::MobileLength;
+
+    let five_km = MobileLength::parse(String::from("5 km")).unwrap();
+    let in_miles = five_km.to(String::from("mi")).unwrap();
+    assert_eq!(in_miles.unit(), "mi");
+    assert_eq!(in_miles.format(Some(2)), "3.11 mi");
+
+    let sum = five_km.add(MobileLength::new(500.0, String::from("m")).unwrap());
+    assert_eq!(sum.value(), 5.5);
+
+    assert!(MobileLength::parse(String::from("5 parsnips")).is_err());
+    assert!(five_km.to(String::from("xx")).is_err());
+}
+
+#[cfg(feature = "bevy")]
+#[test]
+fn test_bevy_reflection() {
+    use bevy_reflect::{FromReflect, PartialReflect, ReflectRef, TypeRegistry};
+    use length::CustomUnit;
+    use std::any::TypeId;
+
+    let mut registry = TypeRegistry::default();
+    length::bevy::register_types(&mut registry);
+    assert!(registry.contains(TypeId::of::<Length>()));
+    assert!(registry.contains(TypeId::of::<CustomUnit>()));
+
+    let pace = CustomUnit::register("bevy_pace", 0.75).unwrap();
+    let length = Length::new_value_unit(4, Unit::Custom(pace));
+    let ReflectRef::Struct(reflected) = length.reflect_ref() else {
+        panic!("Length is not reflected as a struct");
+    };
+    assert_eq!(
+        reflected.field("unit").unwrap().try_downcast_ref::<Unit>(),
+        Some(&Unit::Custom(pace))
+    );
+
+    let cloned = Length::<f64>::from_reflect(length.as_partial_reflect()).unwrap();
+    assert_eq!(cloned.unit, Unit::Custom(pace));
+    assert_eq!(cloned.to(Meter).value, 3.0);
+}
+
+#[cfg(feature = "node")]
+#[test]
+fn test_node_length() {
+    use length::node::JsLength;
+
+    let five_km = JsLength::parse(String::from("5 km")).unwrap();
+    let in_miles = five_km.to(String::from("mi")).unwrap();
+    assert_eq!(in_miles.unit(), "mi");
+    assert_eq!(in_miles.format(Some(2)), "3.11 mi");
+    assert_eq!(in_miles.format(None), in_miles.to_js_string());
+
+    let normalized = JsLength::new(1_500.0, String::from("m"))
+        .unwrap()
+        .normalize();
+    assert_eq!(normalized.value(), 1.5);
+    assert_eq!(normalized.unit(), "km");
+
+    assert!(JsLength::parse(String::from("5 parsnips")).is_err());
+    assert!(five_km.to(String::from("xx")).is_err());
+}
+
+#[test]
+fn test_typographic_units() {
+    let twelve_pt = Length::new_string("12pt").unwrap();
+    assert_eq!(twelve_pt.unit, Unit::Typographic(Point));
+    assert_eq!(twelve_pt.to_string(), "12 pt");
+
+    let in_mm = twelve_pt.to(Unit::Metric(Millimeter));
+    assert!((in_mm.value - 4.233_333_333_333).abs() < 1e-9);
+
+    let one_cicero = Length::new_string("1 cc").unwrap();
+    let in_didot = one_cicero.to(Unit::Typographic(DidotPoint));
+    assert!((in_didot.value - 12.0).abs() < 1e-12);
+
+    let in_points = one_cicero.to(Unit::Typographic(Point));
+    assert!((in_points.value - 12.792_132).abs() < 1e-6);
+
+    let one_inch = Length::new_string("1in").unwrap();
+    assert!((one_inch.to(Unit::Typographic(Point)).value - 72.0).abs() < 1e-12);
+    assert!((one_inch.to(Unit::Typographic(ScaledPoint)).value - 4_736_286.72).abs() < 1e-5);
+
+    let one_pt_in_ly = Length::new_value_unit(1, Unit::Typographic(Point))
+        .to(Unit::Astronomic(Lightyear))
+        .to(Unit::Typographic(Point));
+    assert!((one_pt_in_ly.value - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_pica_and_pixel() {
+    let one_pica = Length::new_string("1 pica").unwrap();
+    assert_eq!(one_pica.unit, Unit::Typographic(Pica));
+    assert_eq!(one_pica.to_string(), "1 pica");
+    assert_eq!(one_pica.to(Unit::Typographic(Point)).value, 12.0);
+    assert_eq!(one_pica.to(Unit::Typographic(Pixel)).value, 16.0);
+    assert_eq!(one_pica.to_pixels(192.0), 32.0);
+
+    assert_eq!(
+        Length::new_string("1 pc").unwrap().unit,
+        Unit::Astronomic(Parsec)
+    );
+
+    let pixels = Length::new_string("48px").unwrap();
+    assert_eq!(pixels.unit, Unit::Typographic(Pixel));
+    assert!((pixels.to(Unit::Imperial(Inch)).value - 0.5).abs() < 1e-12);
+    assert!((pixels.to_pixels(Length::DEFAULT_DPI) - 48.0).abs() < 1e-12);
+    assert!((Length::new_string("2 cm").unwrap().to_pixels(254.0) - 200.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_length_range_new_string() {
+    let range = LengthRange::new_string("3 to 7 ft").unwrap();
+    assert_eq!(range.start.value, 3.0);
+    assert_eq!(range.end.value, 7.0);
+    assert_eq!(range.start.unit, Unit::Imperial(Foot));
+    assert_eq!(range.span().value, 4.0);
+
+    let range = LengthRange::new_string("2 m - 300 cm").unwrap();
+    assert_eq!(range.start.unit, Unit::Metric(Meter));
+    assert_eq!(range.end.unit, Unit::Metric(Centimeter));
+    assert_eq!(range.span().value, 1.0);
+    assert!(range.contains(&Length::new_string("250cm").unwrap()));
+
+    let range = LengthRange::new_string("10 mm +/- 0.5 mm").unwrap();
+    assert_eq!(range.start.value, 9.5);
+    assert_eq!(range.end.value, 10.5);
+
+    let nominal = Length::new_string("1 in").unwrap();
+    let range = LengthRange::new_tolerance_string("± 1 in", &nominal).unwrap();
+    assert_eq!(range.start.value, 0.0);
+    assert_eq!(range.end.value, 2.0);
+
+    assert!(LengthRange::new_string("5 km").is_none());
+    assert!(LengthRange::new_string("5-10").is_none());
+    assert!(LengthRange::new_tolerance_string("2 mm", &nominal).is_none());
+}
+
+#[test]
+fn test_operators() {
+    let five_kilometer = Length::new_string("5km").unwrap();
+    let twohundred_meter = Length::new_string("200m").unwrap();
+
+    let sum = five_kilometer.clone() + twohundred_meter.clone();
+    assert_eq!(sum.value, 5.2);
+    assert_eq!(sum.unit, Unit::Metric(Kilometer));
+
+    let difference = five_kilometer.clone() - twohundred_meter.clone();
+    assert_eq!(difference.value, 4.8);
+    assert_eq!(difference.unit, Unit::Metric(Kilometer));
+
+    assert_eq!((five_kilometer.clone() * 2.0).value, 10.0);
+    assert_eq!((2.0 * five_kilometer.clone()).value, 10.0);
+    assert_eq!((five_kilometer.clone() / 2.0).value, 2.5);
+    assert_eq!((-five_kilometer.clone()).value, -5.0);
+
+    let mut length = five_kilometer;
+    length += twohundred_meter.clone();
+    assert_eq!(length.value, 5.2);
+    length -= twohundred_meter;
+    assert_eq!(length.value, 5.0);
+    length *= 4.0;
+    assert_eq!(length.value, 20.0);
+    length /= 8.0;
+    assert_eq!(length.value, 2.5);
+    assert_eq!(length.unit, Unit::Metric(Kilometer));
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(Length::parse("").err(), Some(ParseLengthError::EmptyInput));
+    assert_eq!(
+        Length::parse(" 1.2.3 km").err(),
+        Some(ParseLengthError::InvalidNumber {
+            number: String::from("1.2.3"),
+            span: 1..6,
+        })
+    );
+    assert_eq!(
+        Length::parse("12 ").err(),
+        Some(ParseLengthError::MissingUnit { span: 0..2 })
+    );
+    assert_eq!(
+        Length::parse("12 parsnips").err(),
+        Some(ParseLengthError::UnknownUnit {
+            unit: String::from("parsnips"),
+            span: 3..11,
+        })
+    );
+    assert_eq!(
+        Length::parse("km").err(),
+        Some(ParseLengthError::MalformedFormat {
+            input: String::from("km"),
+        })
+    );
+    assert_eq!(
+        Length::parse("5 k m").err(),
+        Some(ParseLengthError::SpacedUnit {
+            unit: String::from("k m"),
+            span: 2..5,
+        })
+    );
+    assert_eq!(
+        Length::parse("5 x y").err().unwrap().to_string(),
+        "\"5 x y\" is not of the form <number> <unit>"
+    );
+}
+
+#[test]
+fn test_from_str() {
+    let length: Length = "2.5 km".parse().unwrap();
+    assert_eq!(length.value, 2.5);
+    assert_eq!(length.unit, Unit::Metric(Kilometer));
+    assert_eq!(length.get_original_string(), "2.5 km");
+
+    let error = "2.5 lightyards".parse::<Length>().err();
+    assert_eq!(
+        error,
+        Some(ParseLengthError::UnknownUnit {
+            unit: String::from("lightyards"),
+            span: 4..14,
+        })
+    );
+}
+
+#[test]
+fn test_partial_ord() {
+    let one_km = Length::new_string("1 km").unwrap();
+    let ninehundred_m = Length::new_string("900 m").unwrap();
+    let one_mile = Length::new_string("1 mi").unwrap();
+
+    assert!(one_km > ninehundred_m);
+    assert!(ninehundred_m < one_km);
+    assert!(one_mile >= one_km);
+    assert!(one_km <= Length::new_string("1000 m").unwrap());
+
+    let nan = Length::new_value_unit(f64::NAN, Unit::Metric(Meter));
+    assert_eq!(nan.partial_cmp(&one_km), None);
+    assert_eq!(nan, Length::new_value_unit(f64::NAN, Unit::Imperial(Foot)));
+    assert_eq!(nan.partial_cmp(&nan), Some(std::cmp::Ordering::Equal));
+    assert_eq!(nan.total_cmp(&one_km), std::cmp::Ordering::Greater);
+}
+
+#[test]
+fn test_partial_eq() {
+    assert!(Length::new_string("1 km").unwrap() == Length::new_string("1000 m").unwrap());
+    assert!(Length::new_string("3 ft").unwrap() == Length::new_string("1 yd").unwrap());
+    assert!(Length::new_string("1 km").unwrap() != Length::new_string("1 mi").unwrap());
+
+    let tolerance = Length::new_string("1 mm").unwrap();
+    let one_mile = Length::new_string("1 mi").unwrap();
+    assert!(one_mile.eq_within(&Length::new_string("1609.3445 m").unwrap(), &tolerance));
+    assert!(!one_mile.eq_within(&Length::new_string("1609.346 m").unwrap(), &tolerance));
+}
+
+#[test]
+fn test_display_with_format_specifiers() {
+    let length = Length::new_value_unit(1.609344, Unit::Metric(Kilometer));
+    assert_eq!(format!("{}", length), "1.609344 km");
+    assert_eq!(format!("{:.2}", length), "1.61 km");
+    assert_eq!(format!("{:>10.2}", length), "   1.61 km");
+    assert_eq!(format!("{:10.0}", length), "      2 km");
+    assert_eq!(format!("{:-<9.1}", length), "1.6 km---");
+    assert_eq!(format!("{:3}", length), "1.609344 km");
+
+    assert_eq!(format!("{:>4}", Unit::Metric(Kilometer)), "  km");
+    assert_eq!(format!("{:<4}|", Unit::Imperial(Foot)), "ft  |");
+}
+
+#[test]
+fn test_additional_imperial_units() {
+    let furlong = Length::new_string("1 fur").unwrap();
+    assert_eq!(furlong.unit, Unit::Imperial(Furlong));
+    assert_eq!(furlong.to(Unit::Imperial(Chain)).value, 10.0);
+    assert_eq!(furlong.to(Unit::Imperial(Rod)).value, 40.0);
+    assert_eq!(furlong.to(Unit::Imperial(Yard)).value, 220.0);
+
+    let horse = Length::new_string("16 hh").unwrap();
+    assert_eq!(horse.to(Unit::Imperial(Inch)).value, 64.0);
+    assert_eq!(horse.to_string(), "16 hh");
+
+    let thou = Length::new_string("5 mil").unwrap();
+    assert_eq!(thou.unit, Unit::Imperial(Thou));
+    assert_eq!(thou.to_string(), "5 th");
+    assert!((thou.to(Unit::Metric(Micrometer)).value - 127.0).abs() < 1e-9);
+
+    let league = Length::new_string("2 lea").unwrap();
+    assert_eq!(league.to(Unit::Imperial(Mile)).value, 6.0);
+
+    let normalized = Length::new_string("880 yd").unwrap().normalize();
+    assert_eq!(normalized.unit, Unit::Imperial(Furlong));
+    assert_eq!(normalized.value, 4.0);
+}
+
+#[test]
+fn test_us_survey_units() {
+    let survey_foot = Length::new_string("1 ftUS").unwrap();
+    assert_eq!(survey_foot.unit, Unit::UsSurvey(SurveyFoot));
+    assert_eq!(survey_foot.to_string(), "1 ftUS");
+    assert!((survey_foot.to(Unit::Metric(Meter)).value - 1_200.0 / 3_937.0).abs() < 1e-15);
+
+    let survey_mile = Length::new_string("1 miUS").unwrap();
+    assert_eq!(survey_mile.to(Unit::UsSurvey(SurveyChain)).value, 80.0);
+    let in_international_feet = survey_mile.to(Unit::Imperial(Foot)).value;
+    assert!((in_international_feet - 5_280.010_56).abs() < 1e-5);
+    assert!(survey_mile != Length::new_string("1 mi").unwrap());
+
+    let back = Length::new_string("5280 ft")
+        .unwrap()
+        .to(Unit::UsSurvey(SurveyFoot));
+    assert!((back.value - 5_279.989_44).abs() < 1e-5);
+
+    let normalized = Length::new_string("10560 ftUS").unwrap().normalize();
+    assert_eq!(normalized.unit, Unit::UsSurvey(SurveyMile));
+    assert_eq!(normalized.value, 2.0);
+
+    assert_eq!(Unit::UsSurvey(SurveyMile).code(), 502);
+}
+
+#[test]
+fn test_angstrom_and_aliases() {
+    let angstrom = Length::new_string("1.5 Å").unwrap();
+    assert_eq!(angstrom.unit, Unit::Metric(Angstrom));
+    assert_eq!(angstrom.to_string(), "1.5 Å");
+    assert!((angstrom.to(Unit::Metric(Picometer)).value - 150.0).abs() < 1e-9);
+
+    assert_eq!(
+        Length::new_string("2 angstrom").unwrap().unit,
+        Unit::Metric(Angstrom)
+    );
+    assert_eq!(
+        Length::new_string("3 micron").unwrap().unit,
+        Unit::Metric(Micrometer)
+    );
+    assert_eq!(
+        Length::new_string("3µm").unwrap().unit,
+        Unit::Metric(Micrometer)
+    );
+    assert_eq!(
+        Length::new_string("0.8 fermi").unwrap().unit,
+        Unit::Metric(Femtometer)
+    );
+
+    let normalized = Length::new_string("500 pm").unwrap().normalize();
+    assert_eq!(normalized.unit, Unit::Metric(Angstrom));
+    assert!((normalized.value - 5.0).abs() < 1e-9);
+
+    let range = LengthRange::new_string("1-2 Å").unwrap();
+    assert_eq!(range.end.unit, Unit::Metric(Angstrom));
+}
+
+#[test]
+fn test_subatomic_units() {
+    let bohr = Length::new_string("2 a0").unwrap();
+    assert_eq!(bohr.unit, Unit::Subatomic(BohrRadius));
+    assert_eq!(bohr.to_string(), "2 a0");
+    assert!((bohr.to(Unit::Metric(Angstrom)).value - 1.058_354_421_088).abs() < 1e-12);
+
+    let planck = Length::new_string("1 lP").unwrap();
+    assert_eq!(planck.unit, Unit::Subatomic(PlanckLength));
+    let in_meter = planck.to(Unit::Metric(Meter)).value;
+    assert!((in_meter - 1.616_255e-35).abs() < 1e-47);
+
+    let back = Length::new_value_unit(1.616_255e-35, Unit::Metric(Meter))
+        .to(Unit::Subatomic(PlanckLength));
+    assert!((back.value - 1.0).abs() < 1e-12);
+
+    let normalized = Length::new_value_unit(1e30, Unit::Subatomic(PlanckLength)).normalize();
+    assert_eq!(normalized.unit, Unit::Subatomic(BohrRadius));
+}
+
+#[test]
+fn test_additional_astronomic_units() {
+    let sun = Length::new_string("1 R☉").unwrap();
+    assert_eq!(sun.unit, Unit::Astronomic(SolarRadius));
+    assert_eq!(sun.to_string(), "1 R☉");
+    assert!((sun.to(Unit::Metric(Kilometer)).value - 695_700.0).abs() < 1e-6);
+    assert_eq!(
+        Length::new_string("2 Rsun").unwrap().unit,
+        Unit::Astronomic(SolarRadius)
+    );
+
+    let earth = Length::new_string("1 Rearth").unwrap();
+    assert_eq!(earth.unit, Unit::Astronomic(EarthRadius));
+    assert_eq!(earth.to_string(), "1 R⊕");
+    assert!((earth.to(Unit::Metric(Kilometer)).value - 6_378.1).abs() < 1e-6);
+    assert!((sun.to(Unit::Astronomic(EarthRadius)).value - 109.076).abs() < 1e-3);
+
+    let lunar_distance = Length::new_string("1 LD").unwrap();
+    assert!((lunar_distance.to(Unit::Imperial(Mile)).value - 238_854.465).abs() < 1e-3);
+
+    let gigaparsec = Length::new_string("1 Gpc").unwrap();
+    assert!((gigaparsec.to(Unit::Astronomic(Megaparsec)).value - 1_000.0).abs() < 1e-9);
+
+    let normalized = Length::new_string("1 au").unwrap().normalize();
+    assert_eq!(normalized.unit, Unit::Astronomic(AstronomicalUnit));
+    let normalized = Length::new_string("2000000 km")
+        .unwrap()
+        .to(Unit::Astronomic(Lightsecond))
+        .normalize();
+    assert_eq!(normalized.unit, Unit::Astronomic(SolarRadius));
+}
+
+#[test]
+fn test_custom_units() {
+    let smoot = CustomUnit::register("smoot", 1.7018).unwrap();
+    assert_eq!(smoot.symbol(), "smoot");
+    assert_eq!(smoot.meters(), 1.7018);
+    assert_eq!("smoot".parse::<Unit>(), Ok(Unit::Custom(smoot)));
+
+    let bridge = Length::new_string("364.4 smoot").unwrap();
+    assert_eq!(bridge.unit, Unit::Custom(smoot));
+    assert_eq!(bridge.to_string(), "364.4 smoot");
+    assert!((bridge.to(Unit::Metric(Meter)).value - 620.135_92).abs() < 1e-9);
+
+    let in_smoot = Length::new_string("17.018 m")
+        .unwrap()
+        .to(Unit::Custom(smoot));
+    assert!((in_smoot.value - 10.0).abs() < 1e-12);
+
+    let span = CustomUnit::register("span", 0.2286).unwrap();
+    let in_span = Length::new_value_unit(1, Unit::Custom(smoot)).to(Unit::Custom(span));
+    assert!((in_span.value - 1.7018 / 0.2286).abs() < 1e-12);
+    assert!(
+        (Length::new_value_unit(1, Unit::Custom(span))
+            .to(Unit::Imperial(Inch))
+            .value
+            - 9.0)
+            .abs()
+            < 1e-12
+    );
+
+    assert_eq!(CustomUnit::register("span", 0.2286), Some(span));
+    assert_eq!(CustomUnit::register("km", 1000.0), None);
+    assert_eq!(CustomUnit::register("two words", 1.0), None);
+    assert_eq!(CustomUnit::register("nothing", 0.0), None);
+
+    let code = Unit::Custom(smoot).code();
+    assert!(code >= 1000);
+    assert_eq!(Unit::try_from_code(code), Some(Unit::Custom(smoot)));
+}
+
+#[test]
+fn test_meters_per_unit() {
+    assert_eq!(Unit::Metric(Kilometer).meters_per_unit(), 1_000.0);
+    assert_eq!(Unit::Imperial(Mile).meters_per_unit(), 1_609.344);
+    assert_eq!(
+        Unit::Astronomic(Lightyear).meters_per_unit(),
+        9_460_730_472_580_800.0
+    );
+    assert_approx(Unit::Typographic(Point).meters_per_unit(), 0.0254 / 72.0);
+
+    let expected = [
+        ("1 mi", Unit::Metric(Kilometer), 1.609_344),
+        ("1 km", Unit::Imperial(Yard), 1_093.613_298_337_707_8),
+        (
+            "1 ly",
+            Unit::Astronomic(AstronomicalUnit),
+            63_241.077_084_266_275,
+        ),
+        ("1 pc", Unit::Astronomic(Lightyear), 3.261_563_777_167_433_6),
+        ("1 au", Unit::Imperial(Mile), 92_955_807.273_026_2),
+        ("1 ft", Unit::Imperial(Inch), 12.0),
+        ("1 cm", Unit::Metric(Decimeter), 0.1),
+    ];
+    for (input, unit, value) in expected {
+        assert_approx(Length::new_string(input).unwrap().to(unit).value, value);
+    }
+
+    let units: Vec<Unit> = (0..1000).filter_map(Unit::try_from_code).collect();
+    for from in &units {
+        for to in &units {
+            let round_trip = Length::new_value_unit(1, *from).to(*to).to(*from);
+            assert_approx(round_trip.value, 1.0);
+        }
+    }
+}
+
+#[test]
+fn test_to_exact() {
+    let one_foot = Length::new_string("1 ft").unwrap();
+    assert_eq!(one_foot.to(Unit::Imperial(Inch)).value, 12.0);
+    assert_eq!(
+        one_foot.to_exact(Unit::Metric(Meter)).unwrap().value,
+        0.3048
+    );
+
+    for value in [0.1, 0.3, 1.0, 2.5, 7.0, 42.42, 1e-7, 123_456.789] {
+        let original = Length::new_value_unit(value, Unit::Metric(Kilometer));
+        let round_trip = original
+            .to_exact(Unit::Imperial(Mile))
+            .and_then(|miles| miles.to_exact(Unit::Imperial(Foot)))
+            .and_then(|feet| feet.to_exact(Unit::Typographic(Point)))
+            .and_then(|points| points.to_exact(Unit::Metric(Kilometer)))
+            .unwrap();
+        assert_eq!(round_trip.value, value);
+
+        let inch = Length::new_value_unit(value, Unit::Imperial(Inch));
+        let back = inch
+            .to_exact(Unit::Metric(Centimeter))
+            .and_then(|cm| cm.to_exact(Unit::Imperial(Inch)))
+            .unwrap();
+        assert_eq!(back.value, value);
+    }
+
+    let mut modified = Length::new_string("1 km")
+        .unwrap()
+        .to_exact(Unit::Metric(Meter))
+        .unwrap();
+    modified.value = 2_000.0;
+    assert_eq!(
+        modified.to_exact(Unit::Metric(Kilometer)).unwrap().value,
+        2.0
+    );
+
+    assert!(Length::new_string("1 pc")
+        .unwrap()
+        .to_exact(Unit::Metric(Meter))
+        .is_none());
+    assert!(Length::new_string("1 lP")
+        .unwrap()
+        .to_exact(Unit::Metric(Meter))
+        .is_none());
+}
+
+#[test]
+fn test_generic_scalar() {
+    let five_km = Length::from_scalar(5.0_f32, Unit::Metric(Kilometer));
+    let in_meters = five_km.to(Unit::Metric(Meter));
+    assert_eq!(in_meters.value, 5_000.0_f32);
+
+    let sum = five_km.clone() + Length::from_scalar(500.0_f32, Unit::Metric(Meter));
+    assert_eq!(sum.value, 5.5_f32);
+    assert_eq!(sum.unit, Unit::Metric(Kilometer));
+    assert_eq!(sum.multiply_by(2.0_f32).value, 11.0_f32);
+    assert_eq!(sum.to_string(), "5.5 km");
+    assert!(sum > five_km);
+
+    let normalized = Length::from_scalar(2_500.0_f32, Unit::Metric(Meter)).normalize();
+    assert_eq!(normalized.value, 2.5_f32);
+    assert_eq!(normalized.unit, Unit::Metric(Kilometer));
+
+    let twelve_inch = Length::from_scalar(1.0_f32, Unit::Imperial(Foot)).to(Unit::Imperial(Inch));
+    assert_eq!(twelve_inch.value, 12.0_f32);
+
+    let parsed = Length::new_string("3 ft").unwrap().cast::<f32>().unwrap();
+    assert_eq!(parsed.value, 3.0_f32);
+    assert_eq!(parsed.get_original_string(), "3 ft");
+    assert_eq!(parsed.cast::<f64>().unwrap().value, 3.0);
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn test_decimal_scalar() {
+    use length::Decimal;
+
+    let one_tenth = Length::from_scalar(Decimal::new(1, 1), Unit::Metric(Kilometer));
+    let in_miles = one_tenth.to(Unit::Imperial(Mile));
+    assert_eq!(
+        in_miles.to(Unit::Metric(Kilometer)).value.round_dp(20),
+        Decimal::new(1, 1)
+    );
+
+    let three_tenths = one_tenth.clone() + one_tenth.clone() + one_tenth;
+    assert_eq!(three_tenths.value, Decimal::new(3, 1));
+    assert_eq!(
+        three_tenths.to(Unit::Metric(Meter)).value,
+        Decimal::new(300, 0)
+    );
+    assert_eq!(format!("{:.2}", three_tenths), "0.30 km");
+
+    let one_inch = Length::from_scalar(Decimal::ONE, Unit::Imperial(Inch));
+    assert_eq!(
+        one_inch.to(Unit::Metric(Centimeter)).value,
+        Decimal::new(254, 2)
+    );
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn test_decimal_extreme_units() {
+    use length::Decimal;
+
+    let one_quettameter = Length::from_scalar(Decimal::ONE, Unit::Metric(Quettameter));
+    assert!(one_quettameter.checked_to(Unit::Metric(Meter)).is_none());
+    assert_eq!(
+        one_quettameter
+            .checked_to(Unit::Metric(Ronnameter))
+            .unwrap()
+            .value,
+        Decimal::new(1_000, 0)
+    );
+
+    let one_quectometer = Length::from_scalar(Decimal::ONE, Unit::Metric(Quectometer));
+    assert!(one_quectometer.checked_to(Unit::Metric(Meter)).is_none());
+    assert_eq!(
+        one_quectometer
+            .checked_to(Unit::Metric(Rontometer))
+            .unwrap()
+            .value,
+        Decimal::new(1, 3)
+    );
+
+    let one_meter = Length::from_scalar(Decimal::ONE, Unit::Metric(Meter));
+    assert!(one_meter
+        .checked_to(Unit::Subatomic(PlanckLength))
+        .is_none());
+    assert!(
+        Length::from_scalar(Decimal::ONE, Unit::Subatomic(PlanckLength))
+            .checked_to(Unit::Metric(Meter))
+            .is_none()
+    );
+    assert_eq!(
+        one_meter.checked_to(Unit::Metric(Kilometer)).unwrap().value,
+        Decimal::new(1, 3)
+    );
+    assert!(Length::from_scalar(Decimal::MAX, Unit::Metric(Kilometer))
+        .checked_to(Unit::Metric(Meter))
+        .is_none());
+
+    // Comparisons and normalize() do not panic for factors, that a Decimal can not represent.
+    let one_planck_length = Length::from_scalar(Decimal::ONE, Unit::Subatomic(PlanckLength));
+    assert_ne!(one_meter, one_planck_length);
+    assert!(one_planck_length < one_meter);
+    assert!(one_quettameter > one_meter);
+    assert_eq!(
+        one_quettameter,
+        Length::from_scalar(Decimal::new(1_000, 0), Unit::Metric(Ronnameter))
+    );
+    assert!(Length::from_scalar(Decimal::MAX, Unit::Metric(Kilometer)) > one_quettameter);
+    assert_eq!(one_quectometer.normalize().unit, Unit::Metric(Quectometer));
+    assert_eq!(one_quettameter.normalize().unit, Unit::Metric(Quettameter));
+}
+
+#[test]
+fn test_exact_length() {
+    let one_mile = ExactLength::new(1, Unit::Imperial(Mile)).unwrap();
+    assert_eq!(one_mile.picometers(), 1_609_344_000_000_000);
+    assert_eq!(
+        Some(one_mile),
+        ExactLength::new(5_280, Unit::Imperial(Foot))
+    );
+    assert_eq!(
+        ExactLength::new(1, Unit::Astronomic(Lightyear))
+            .unwrap()
+            .picometers(),
+        9_460_730_472_580_800_000_000_000_000
+    );
+    assert_eq!(ExactLength::new(1, Unit::Astronomic(Parsec)), None);
+    assert_eq!(ExactLength::new(1, Unit::Metric(Quettameter)), None);
+
+    let tenth = ExactLength::from_length(&Length::new_string("0.1 m").unwrap()).unwrap();
+    let mut sum = ExactLength::default();
+    for _ in 0..10 {
+        sum += tenth;
+    }
+    assert_eq!(sum, ExactLength::new(1, Unit::Metric(Meter)).unwrap());
+    assert_eq!(
+        sum - tenth - tenth,
+        ExactLength::new(80, Unit::Metric(Centimeter)).unwrap()
+    );
+    assert_eq!((-tenth).picometers(), -100_000_000_000);
+    assert!(tenth < sum);
+
+    assert_eq!(
+        ExactLength::from_length(&Length::new_string("1 ly").unwrap())
+            .unwrap()
+            .picometers(),
+        9_460_730_472_580_800_000_000_000_000
+    );
+    assert_eq!(
+        ExactLength::from_length(&Length::new_value_unit(-2.5, Unit::Imperial(Inch)))
+            .unwrap()
+            .picometers(),
+        -63_500_000_000
+    );
+    assert_eq!(
+        ExactLength::from_length(&Length::new_value_unit(1.0, Unit::Metric(Femtometer)))
+            .unwrap()
+            .picometers(),
+        0
+    );
+    assert_eq!(
+        ExactLength::from_length(&Length::new_value_unit(1.0, Unit::Astronomic(Parsec)))
+            .unwrap()
+            .picometers(),
+        30_856_775_814_913_673_303_127_228_416
+    );
+    assert!(
+        ExactLength::from_length(&Length::new_value_unit(f64::NAN, Unit::Metric(Meter))).is_none()
+    );
+    assert!(
+        ExactLength::from_length(&Length::new_value_unit(1.0, Unit::Metric(Quettameter))).is_none()
+    );
+
+    let in_meters: Length = sum.into();
+    assert_eq!(in_meters.value, 1.0);
+    assert_eq!(in_meters.unit, Unit::Metric(Meter));
+    assert_eq!(one_mile.to_length(Unit::Imperial(Yard)).value, 1_760.0);
+    assert_eq!(
+        ExactLength::from_picometers(i128::MAX).checked_add(tenth),
+        None
+    );
+    assert_eq!(tenth.checked_sub(tenth), Some(ExactLength::default()));
+}
+
+#[test]
+fn test_unit_names() {
+    let five_km = Length::new_string("5 kilometers").unwrap();
+    assert_eq!(five_km.value, 5.0);
+    assert_eq!(five_km.unit, Unit::Metric(Kilometer));
+    assert_eq!(five_km.get_original_string(), "5 kilometers");
+
+    assert_eq!(
+        Length::new_string("3 feet").unwrap().unit,
+        Unit::Imperial(Foot)
+    );
+    assert_eq!(
+        Length::new_string("1 Foot").unwrap().unit,
+        Unit::Imperial(Foot)
+    );
+    assert_eq!(
+        Length::new_string("12 INCHES").unwrap().unit,
+        Unit::Imperial(Inch)
+    );
+    assert_eq!(
+        Length::new_string("1 parsec").unwrap().unit,
+        Unit::Astronomic(Parsec)
+    );
+    assert_eq!(
+        Length::new_string("250 metres").unwrap().unit,
+        Unit::Metric(Meter)
+    );
+    assert_eq!(
+        Length::new_string("4 solar radii").unwrap().unit,
+        Unit::Astronomic(SolarRadius)
+    );
+    assert_eq!(
+        Length::new_string("1 astronomical unit").unwrap().unit,
+        Unit::Astronomic(AstronomicalUnit)
+    );
+    assert_eq!(
+        Length::new_string("100 survey feet").unwrap().unit,
+        Unit::UsSurvey(SurveyFoot)
+    );
+
+    for text in [
+        "2.3 light years",
+        "2.3 light-years",
+        "2.3 lightyears",
+        "2.3  Light Year",
+    ] {
+        let length = Length::new_string(text).unwrap();
+        assert_eq!(length.value, 2.3);
+        assert_eq!(length.unit, Unit::Astronomic(Lightyear));
+    }
+
+    assert!(Length::new_string("5 KM").is_none());
+    assert_eq!("kilometres".parse::<Unit>(), Ok(Unit::Metric(Kilometer)));
+    assert_eq!(
+        Length::parse("2 light yeers").err(),
+        Some(ParseLengthError::MalformedFormat {
+            input: String::from("2 light yeers"),
+        })
+    );
+}
+
+#[test]
+fn test_signed_values() {
+    let displacement = Length::new_string("-3.5 m").unwrap();
+    assert_eq!(displacement.value, -3.5);
+    assert_eq!(displacement.unit, Unit::Metric(Meter));
+    assert_eq!(displacement.to_string(), "-3.5 m");
+    assert!(displacement.is_negative());
+    assert_eq!(displacement.signum(), -1.0);
+    assert_eq!(displacement.abs().value, 3.5);
+    assert_eq!(displacement.to(Unit::Metric(Centimeter)).value, -350.0);
+
+    let forward: Length = "+2 km".parse().unwrap();
+    assert_eq!(forward.value, 2.0);
+    assert!(!forward.is_negative());
+    assert_eq!(forward.signum(), 1.0);
+    assert_eq!(forward.abs().value, 2.0);
+    assert_eq!((forward + displacement).value, 1.9965);
+
+    assert_eq!(Length::new_string("-0 m").unwrap().signum(), 0.0);
+    assert!(Length::new_string("- 2 km").is_none());
+    assert_eq!(
+        Length::parse("--2 km").err(),
+        Some(ParseLengthError::InvalidNumber {
+            number: String::from("--2"),
+            span: 0..3,
+        })
+    );
+    assert_eq!(
+        Length::parse("-2").err(),
+        Some(ParseLengthError::MissingUnit { span: 0..2 })
+    );
+}
+
+#[test]
+fn test_length_parser() {
+    let german = LengthParser::new()
+        .decimal_comma(true)
+        .thousands_separator('.');
+    let length = german.parse("1.234,5 km").unwrap();
+    assert_eq!(length.value, 1_234.5);
+    assert_eq!(length.unit, Unit::Metric(Kilometer));
+    assert_eq!(length.get_original_string(), "1.234,5 km");
+    assert_eq!(german.parse("-2,5 m").unwrap().value, -2.5);
+    assert_eq!(german.parse("1.234.567 m").unwrap().value, 1_234_567.0);
+    assert_eq!(
+        german.parse("1.5 km").err(),
+        Some(ParseLengthError::InvalidNumber {
+            number: String::from("1.5"),
+            span: 0..3,
+        })
+    );
+    assert_eq!(
+        german.parse("1.234,5 parsnips").err(),
+        Some(ParseLengthError::UnknownUnit {
+            unit: String::from("parsnips"),
+            span: 8..16,
+        })
+    );
+    assert_eq!(
+        german.parse("1.234,5").err(),
+        Some(ParseLengthError::MissingUnit { span: 0..7 })
+    );
+
+    let english = LengthParser::new().thousands_separator(',');
+    assert_eq!(english.parse("1,234.5 mi").unwrap().value, 1_234.5);
+    assert_eq!(
+        english.parse("1,234.5 mi").unwrap().unit,
+        Unit::Imperial(Mile)
+    );
+    assert!(english.parse("1,23 mi").is_err());
+
+    let french = LengthParser::new()
+        .decimal_comma(true)
+        .thousands_separator(' ');
+    assert_eq!(french.parse("1 234,5 m").unwrap().value, 1_234.5);
+    assert_eq!(french.parse("5 m").unwrap().value, 5.0);
+
+    let default = LengthParser::new();
+    for text in [
+        "2m", "5 km", "-3.5 m", "", "km", "1,5 km", "1.2.3 km", "12 ", "5 k m", "2 xyz",
+    ] {
+        assert_eq!(
+            default
+                .parse(text)
+                .map(|length| (length.value, length.unit)),
+            Length::parse(text).map(|length| (length.value, length.unit))
+        );
+    }
+}
+
+#[test]
+fn test_compound_lengths() {
+    let height = Length::new_string("6 ft 2 in").unwrap();
+    assert_eq!(height.value, 74.0);
+    assert_eq!(height.unit, Unit::Imperial(Inch));
+    assert_eq!(height.get_original_string(), "6 ft 2 in");
+
+    let metric = Length::new_string("1 m 75 cm").unwrap();
+    assert_eq!(metric.value, 175.0);
+    assert_eq!(metric.unit, Unit::Metric(Centimeter));
+
+    let distance: Length = "1 km 200 m".parse().unwrap();
+    assert_eq!(distance.value, 1_200.0);
+    assert_eq!(distance.unit, Unit::Metric(Meter));
+
+    assert_eq!(Length::new_string("5ft 10in").unwrap().value, 70.0);
+    assert_eq!(
+        Length::new_string("5 feet 10.5 inches").unwrap().value,
+        70.5
+    );
+    assert_eq!(
+        Length::new_string("1 mi 10 yd 2 ft").unwrap().value,
+        5_312.0
+    );
+    assert_eq!(Length::new_string("-1 m 50 cm").unwrap().value, -150.0);
+
+    assert_eq!(
+        Length::parse("6 ft 2 xyz").err(),
+        Some(ParseLengthError::UnknownUnit {
+            unit: String::from("xyz"),
+            span: 7..10,
+        })
+    );
+    assert_eq!(
+        Length::parse("6 ft 2").err(),
+        Some(ParseLengthError::MalformedFormat {
+            input: String::from("6 ft 2"),
+        })
+    );
+}
+
+#[test]
+fn test_feet_inches_shorthand() {
+    let feet = Length::new_string("5'").unwrap();
+    assert_eq!(feet.value, 5.0);
+    assert_eq!(feet.unit, Unit::Imperial(Foot));
+
+    let inches = Length::new_string("10\"").unwrap();
+    assert_eq!(inches.value, 10.0);
+    assert_eq!(inches.unit, Unit::Imperial(Inch));
+
+    let height = Length::new_string("5'10\"").unwrap();
+    assert_eq!(height.value, 70.0);
+    assert_eq!(height.unit, Unit::Imperial(Inch));
+    assert_eq!(height.get_original_string(), "5'10\"");
+
+    assert_eq!(Length::new_string("5' 10 1/2\"").unwrap().value, 70.5);
+    assert_eq!(Length::new_string("6′ 2″").unwrap().value, 74.0);
+    assert_eq!(Length::new_string("5'10").unwrap().value, 70.0);
+    assert_eq!(Length::new_string("-1'6\"").unwrap().value, -18.0);
+    assert_eq!(
+        Length::parse("5' 10 1/0\"").err(),
+        Some(ParseLengthError::InvalidNumber {
+            number: String::from("1/0"),
+            span: 6..9,
+        })
+    );
+}
+
+#[test]
+fn test_fractions() {
+    let half_inch = Length::new_string("1/2 in").unwrap();
+    assert_eq!(half_inch.value, 0.5);
+    assert_eq!(half_inch.unit, Unit::Imperial(Inch));
+    assert_eq!(half_inch.get_original_string(), "1/2 in");
+
+    assert_eq!(Length::new_string("3 5/8 in").unwrap().value, 3.625);
+    assert_eq!(Length::new_string("½ in").unwrap().value, 0.5);
+    assert_eq!(Length::new_string("3½ in").unwrap().value, 3.5);
+    assert_eq!(Length::new_string("2 ¾ inches").unwrap().value, 2.75);
+    assert_eq!(Length::new_string("⅛\"").unwrap().value, 0.125);
+    assert_eq!(Length::new_string("-1 1/4 mi").unwrap().value, -1.25);
+    assert_eq!(
+        Length::new_string("1/3 yd")
+            .unwrap()
+            .to(Unit::Imperial(Foot))
+            .value,
+        1.0
+    );
+
+    assert_eq!(
+        Length::parse("3 5/0 in").err(),
+        Some(ParseLengthError::InvalidNumber {
+            number: String::from("5/0"),
+            span: 2..5,
+        })
+    );
+    assert_eq!(
+        Length::parse("1/2 xyz").err(),
+        Some(ParseLengthError::UnknownUnit {
+            unit: String::from("xyz"),
+            span: 4..7,
+        })
+    );
+}
+
+#[test]
+fn test_components() {
+    let feet_inches = [Unit::Imperial(Foot), Unit::Imperial(Inch)];
+    let height = Length::new_string("1.86 m").unwrap();
+    let components = height.to_components(&feet_inches);
+    assert_eq!(components.len(), 2);
+    assert_eq!(components[0].value, 6.0);
+    assert_eq!(components[0].unit, Unit::Imperial(Foot));
+    assert_approx(components[1].value, 1.228_346_456_692_9);
+    assert_eq!(components[1].unit, Unit::Imperial(Inch));
+    assert_eq!(height.format_mixed(&feet_inches), "6 ft 1 in");
+
+    let metric = [
+        Unit::Metric(Kilometer),
+        Unit::Metric(Meter),
+        Unit::Metric(Centimeter),
+    ];
+    let distance = Length::new_string("1234.5 m").unwrap();
+    assert_eq!(distance.format_mixed(&metric), "1 km 234 m 50 cm");
+    assert_eq!(
+        Length::new_string("1 km").unwrap().format_mixed(&metric),
+        "1 km"
+    );
+    assert_eq!(
+        Length::new_string("0.1 mm").unwrap().format_mixed(&metric),
+        "0 cm"
+    );
+    assert_eq!(
+        Length::new_string("1.2 m").unwrap().to_components(&metric)[1].value,
+        1.0
+    );
+
+    assert_eq!(
+        Length::new_string("5 ft 11.7 in")
+            .unwrap()
+            .format_mixed(&feet_inches),
+        "6 ft"
+    );
+    assert_eq!(
+        Length::new_string("-70 in")
+            .unwrap()
+            .format_mixed(&feet_inches),
+        "-5 ft 10 in"
+    );
+    assert_eq!(
+        Length::new_string("-70 in")
+            .unwrap()
+            .to_components(&feet_inches)[1]
+            .value,
+        -10.0
+    );
+    assert!(height.to_components(&[]).is_empty());
+    assert_eq!(height.format_mixed(&[]), "");
+}
+
+#[test]
+fn test_format_feet_inches() {
+    let height = Length::new_string("5' 10 1/2\"").unwrap();
+    assert_eq!(height.format_feet_inches(2), "5' 10 1/2\"");
+    assert_eq!(height.format_feet_inches(16), "5' 10 1/2\"");
+    assert_eq!(height.format_feet_inches(1), "5' 11\"");
+
+    let board = Length::new_string("1 m").unwrap();
+    assert_eq!(board.format_feet_inches(16), "3' 3 3/8\"");
+    assert_eq!(board.format_feet_inches(4), "3' 3 1/4\"");
+
+    assert_eq!(
+        Length::new_string("11.97 in")
+            .unwrap()
+            .format_feet_inches(8),
+        "1' 0\""
+    );
+    assert_eq!(
+        Length::new_string("0.25 in").unwrap().format_feet_inches(8),
+        "0' 1/4\""
+    );
+    assert_eq!(
+        Length::new_string("-18 in").unwrap().format_feet_inches(4),
+        "-1' 6\""
+    );
+    assert_eq!(
+        Length::new_string("-0.01 in")
+            .unwrap()
+            .format_feet_inches(4),
+        "0' 0\""
+    );
+    assert_eq!(
+        Length::new_string("2 ft").unwrap().format_feet_inches(0),
+        "2' 0\""
+    );
+}
+
+#[test]
+fn test_normalize_to_system() {
+    let distance = Length::new_string("1800 m").unwrap();
+    let imperial = distance.normalize_to_system(UnitSystem::Imperial);
+    assert_eq!(imperial.unit, Unit::Imperial(Mile));
+    assert_approx(imperial.value, 1.118_468_146_027_201);
+
+    let far = Length::new_value_unit(2e16, Unit::Metric(Meter));
+    let astronomic = far.normalize_to_system(UnitSystem::Astronomic);
+    assert_eq!(astronomic.unit, Unit::Astronomic(Lightyear));
+    assert_approx(astronomic.value, 2.114_001_668_049_231);
+
+    let metric = Length::new_string("3 mi").unwrap();
+    let normalized = metric.normalize_to_system(UnitSystem::Metric);
+    assert_eq!(normalized.unit, Unit::Metric(Kilometer));
+    assert_approx(normalized.value, 4.828_032);
+
+    assert_eq!(
+        Length::new_string("12 pt")
+            .unwrap()
+            .normalize_to_system(UnitSystem::Typographic)
+            .unit,
+        Unit::Typographic(Point)
+    );
+    assert_eq!(
+        distance.normalize_to_system(UnitSystem::Custom).unit,
+        Unit::Metric(Meter)
+    );
+
+    // Sub-meter and very large lengths get a commonly used unit, not the hand or the league.
+    let small = Length::new_string("18 cm").unwrap();
+    let in_inches = small.normalize_to_system(UnitSystem::Imperial);
+    assert_eq!(in_inches.unit, Unit::Imperial(Inch));
+    assert_approx(in_inches.value, 7.086_614_173_228_346);
+    assert_eq!(
+        Length::new_string("0.1 in")
+            .unwrap()
+            .normalize_to_system(UnitSystem::Metric)
+            .unit,
+        Unit::Metric(Millimeter)
+    );
+    assert_eq!(
+        Length::new_value_unit(0.5, Meter)
+            .normalize_to_system(UnitSystem::Imperial)
+            .unit,
+        Unit::Imperial(Foot)
+    );
+    assert_eq!(
+        Length::new_value_unit(2e7, Meter)
+            .normalize_to_system(UnitSystem::Imperial)
+            .unit,
+        Unit::Imperial(Mile)
+    );
+    let huge = Length::new_value_unit(5e9, Lightyear);
+    let in_megaparsecs = huge.normalize_to_system(UnitSystem::Astronomic);
+    assert_eq!(in_megaparsecs.unit, Unit::Astronomic(Megaparsec));
+    assert_eq!(in_megaparsecs.value, huge.to(Megaparsec).value);
+    assert_eq!(
+        Length::new_value_unit(1e11, Kilometer)
+            .normalize_to_system(UnitSystem::Astronomic)
+            .unit,
+        Unit::Astronomic(AstronomicalUnit)
+    );
+}
+
+#[test]
+fn test_humanize() {
+    assert_eq!(
+        Unit::best_for(1_800.0, UnitSystem::Imperial),
+        Unit::Imperial(Mile)
+    );
+    assert_eq!(
+        Unit::best_for(0.5, UnitSystem::Metric),
+        Unit::Metric(Centimeter)
+    );
+    assert_eq!(
+        Unit::best_for(1e-12, UnitSystem::Metric),
+        Unit::Metric(Nanometer)
+    );
+    assert_eq!(
+        Unit::best_for(2e16, UnitSystem::Astronomic),
+        Unit::Astronomic(Lightyear)
+    );
+    assert_eq!(
+        Unit::best_for(0.0, UnitSystem::Imperial),
+        Unit::Imperial(Foot)
+    );
+    assert_eq!(
+        Unit::best_for(-5.0, UnitSystem::Custom),
+        Unit::Metric(Meter)
+    );
+
+    assert_eq!(
+        Length::new_string("3217 m").unwrap().humanize(),
+        "about 3.2 km"
+    );
+    assert_eq!(
+        Length::new_string("0.18 m")
+            .unwrap()
+            .to(Unit::Imperial(Yard))
+            .humanize(),
+        "about 7.1 in"
+    );
+    assert_eq!(
+        Length::new_string("1 rd").unwrap().humanize(),
+        "about 17 ft"
+    );
+    assert_eq!(Length::new_string("7 in").unwrap().humanize(), "7 in");
+    assert_eq!(
+        Length::new_string("-1234 mm").unwrap().humanize(),
+        "about -1.2 m"
+    );
+    assert_eq!(Length::new_string("0 km").unwrap().humanize(), "0 m");
+}
+
+#[test]
+fn test_rounding_in_unit() {
+    let length = Length::new_string("1.2345 km").unwrap();
+    let rounded = length.round_to(Unit::Metric(Kilometer), 2);
+    assert_eq!(rounded.value, 1.23);
+    assert_eq!(rounded.unit, Unit::Metric(Kilometer));
+    assert_eq!(length.round_to(Unit::Metric(Meter), 0).value, 1_235.0);
+    assert_eq!(length.round_to(Unit::Imperial(Mile), 3).value, 0.767);
+
+    let screw = Length::new_string("17.3 mm").unwrap();
+    assert_eq!(screw.ceil_to(Unit::Metric(Millimeter)).value, 18.0);
+    assert_eq!(screw.floor_to(Unit::Metric(Millimeter)).value, 17.0);
+    assert_eq!(screw.trunc_to(Unit::Metric(Centimeter)).value, 1.0);
+    assert_eq!(
+        screw.ceil_to(Unit::Metric(Centimeter)).unit,
+        Unit::Metric(Centimeter)
+    );
+
+    let negative = Length::new_string("-17.3 mm").unwrap();
+    assert_eq!(negative.floor_to(Unit::Metric(Millimeter)).value, -18.0);
+    assert_eq!(negative.ceil_to(Unit::Metric(Millimeter)).value, -17.0);
+    assert_eq!(negative.trunc_to(Unit::Metric(Millimeter)).value, -17.0);
+    assert_eq!(
+        Length::new_string("3 ft")
+            .unwrap()
+            .floor_to(Unit::Imperial(Inch))
+            .value,
+        36.0
+    );
+}
+
+#[test]
+fn test_snap_to_fraction() {
+    let drill = Length::new_string("6.5 mm").unwrap();
+
+    let (snapped, residual) = drill.snap_to_fraction(64);
+    assert_eq!(snapped.value, 16.0 / 64.0);
+    assert_eq!(snapped.unit, Unit::Imperial(Inch));
+    assert_eq!(residual.unit, Unit::Imperial(Inch));
+    assert_approx(residual.value, 6.5 / 25.4 - 0.25);
+    assert_approx((snapped + residual).to(Millimeter).value, 6.5);
+
+    let (snapped, _) = drill.snap_to_fraction(32);
+    assert_eq!(snapped.value, 8.0 / 32.0);
+    let (snapped, _) = Length::new_string("-1.4 cm").unwrap().snap_to_fraction(16);
+    assert_eq!(snapped.value, -9.0 / 16.0);
+
+    let (snapped, residual) = Length::new_string("3 5/8 in").unwrap().snap_to_fraction(16);
+    assert_eq!(snapped.value, 3.625);
+    assert_eq!(residual.value, 0.0);
+}
+
+#[test]
+fn test_length_ratio() {
+    let total = Length::new_string("10 m").unwrap();
+    let segment = Length::new_string("1 ft").unwrap();
+
+    assert_approx(total.ratio_to(&segment), 10.0 / 0.3048);
+    assert_approx(total.clone() / segment.clone(), 10.0 / 0.3048);
+    assert_approx(segment / total, 0.3048 / 10.0);
+
+    let track = Length::new_string("1 mi").unwrap();
+    let lap = Length::new_string("440 yd").unwrap();
+    assert_eq!(track.ratio_to(&lap), 4.0);
+    assert_eq!(track / lap, 4.0);
+
+    let negative = Length::new_string("-2 km").unwrap();
+    assert_eq!(negative / Length::new_string("500 m").unwrap(), -4.0);
+    assert!(
+        (Length::new_string("1 m").unwrap() / Length::new_string("0 m").unwrap()).is_infinite()
+    );
+}
+
+#[test]
+fn test_length_remainder() {
+    let stock = Length::new_string("10 m").unwrap();
+    let piece = Length::new_string("3 ft").unwrap();
+
+    let leftover = stock.rem(piece.clone());
+    assert_eq!(leftover.unit, Unit::Metric(Meter));
+    assert_approx(leftover.value, 10.0 - 10.0 * 0.9144);
+
+    let leftover = stock.clone() % piece.clone();
+    assert_approx(leftover.value, 10.0 - 10.0 * 0.9144);
+    assert_approx(
+        stock.ratio_to(&piece).floor() * 0.9144 + leftover.value,
+        10.0,
+    );
+
+    let mut board = Length::new_string("8 ft").unwrap();
+    board %= Length::new_string("30 in").unwrap();
+    assert_eq!(board.value, 0.5);
+    assert_eq!(board.unit, Unit::Imperial(Foot));
+
+    let negative = Length::new_string("-7 m").unwrap() % Length::new_string("2 m").unwrap();
+    assert_eq!(negative.value, -1.0);
+    assert!(
+        (Length::new_string("1 m").unwrap() % Length::new_string("0 m").unwrap())
+            .value
+            .is_nan()
+    );
+}
+
+#[test]
+fn test_interpolation() {
+    let start = Length::new_string("2 km").unwrap();
+    let end = Length::new_string("1 mi").unwrap();
+
+    assert_eq!(start.lerp(&end, 0.0).value, 2.0);
+    assert_eq!(start.lerp(&end, 0.0).unit, Unit::Metric(Kilometer));
+    assert_approx(start.lerp(&end, 1.0).value, 1.609_344);
+    assert_approx(start.lerp(&end, 0.5).value, (2.0 + 1.609_344) / 2.0);
+    assert_approx(start.lerp(&end, 2.0).value, 2.0 - 2.0 * (2.0 - 1.609_344));
+    assert_approx(start.midpoint(&end).value, 1.804_672);
+    assert_approx(end.midpoint(&start).value, 1.804_672 / 1.609_344);
+    assert_eq!(end.midpoint(&start).unit, Unit::Imperial(Mile));
+
+    let negative = Length::new_string("-4 m").unwrap();
+    assert_eq!(
+        negative.midpoint(&Length::new_string("2 m").unwrap()).value,
+        -1.0
+    );
+
+    let single: Length<f32> = Length::from_scalar(1.0_f32, Meter);
+    assert_eq!(
+        single.midpoint(&Length::from_scalar(2.0_f32, Meter)).value,
+        1.5_f32
+    );
+}
+
+#[test]
+fn test_min_max_clamp() {
+    let mile = Length::new_string("1 mi").unwrap();
+    let kilometers = Length::new_string("1.5 km").unwrap();
+
+    assert_eq!(mile.min(kilometers.clone()).unit, Unit::Metric(Kilometer));
+    assert_eq!(mile.max(kilometers.clone()).unit, Unit::Imperial(Mile));
+    assert_eq!(kilometers.min(mile.clone()).value, 1.5);
+    assert_eq!(kilometers.max(mile.clone()).value, 1.0);
+
+    let min = Length::new_string("1 mm").unwrap();
+    let max = Length::new_string("10 km").unwrap();
+
+    let too_small = Length::new_string("0.01 in").unwrap();
+    let clamped = too_small.clamp(min.clone(), max.clone());
+    assert_eq!(clamped.unit, Unit::Imperial(Inch));
+    assert_approx(clamped.value, 1.0 / 25.4);
+
+    let too_large = Length::new_string("7 mi").unwrap();
+    assert_approx(
+        too_large.clamp(min.clone(), max.clone()).value,
+        10.0 / 1.609_344,
+    );
+
+    let inside = Length::new_string("3 ft").unwrap();
+    assert_eq!(inside.clamp(min.clone(), max.clone()).value, 3.0);
+
+    let nan = Length::new_value_unit(f64::NAN, Meter);
+    assert!(nan.min(mile.clone()).value.is_nan());
+    assert_eq!(mile.min(nan).value, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "min must not be greater than max")]
+fn test_clamp_with_invalid_interval() {
+    let length = Length::new_string("1 m").unwrap();
+
+    length.clamp(
+        Length::new_string("1 km").unwrap(),
+        Length::new_string("1 mm").unwrap(),
+    );
+}
+
+#[test]
+fn test_sum_and_stats() {
+    let segments = vec![
+        Length::new_string("1 km").unwrap(),
+        Length::new_string("500 m").unwrap(),
+        Length::new_string("1 mi").unwrap(),
+        Length::new_string("250 m").unwrap(),
+    ];
+
+    let total: Length = segments.iter().sum();
+    assert_eq!(total.unit, Unit::Metric(Kilometer));
+    assert_approx(total.value, 3.359_344);
+    let total: Length = segments.clone().into_iter().sum();
+    assert_approx(total.value, 3.359_344);
+    let empty: Length = Vec::<Length>::new().into_iter().sum();
+    assert_eq!(empty.value, 0.0);
+    assert_eq!(empty.unit, Unit::Metric(Meter));
+
+    assert_approx(stats::mean(&segments).unwrap().value, 3.359_344 / 4.0);
+    assert_eq!(
+        stats::mean(&segments).unwrap().unit,
+        Unit::Metric(Kilometer)
+    );
+    assert_approx(stats::median(&segments).unwrap().value, 0.75);
+    assert_eq!(stats::median(&segments[..3]).unwrap().value, 1.0);
+    assert_eq!(stats::min(&segments).unwrap().value, 0.25);
+    assert_eq!(stats::min(&segments).unwrap().unit, Unit::Metric(Kilometer));
+    assert_approx(stats::max(&segments).unwrap().value, 1.609_344);
+
+    assert!(stats::mean::<f64>(&[]).is_none());
+    assert!(stats::median::<f64>(&[]).is_none());
+    assert!(stats::min::<f64>(&[]).is_none());
+    assert!(stats::max::<f64>(&[]).is_none());
+}
+
+#[test]
+fn test_sum_compensated() {
+    let segments: Vec<Length> = (0..100_000)
+        .map(|index| {
+            if index % 2 == 0 {
+                Length::new_string("0.1 m").unwrap()
+            } else {
+                Length::new_string("0.0001 km").unwrap()
+            }
+        })
+        .collect();
+
+    let naive: Length = segments.iter().sum();
+    let compensated = Length::sum_compensated(segments.clone());
+    assert_eq!(compensated.unit, Unit::Metric(Meter));
+    assert_approx(compensated.value, 10_000.0);
+    assert!((compensated.value - 10_000.0).abs() < (naive.value - 10_000.0).abs());
+
+    let big_and_small = vec![
+        Length::new_value_unit(1.0, Meter),
+        Length::new_value_unit(1e100, Meter),
+        Length::new_value_unit(1.0, Meter),
+        Length::new_value_unit(-1e100, Meter),
+    ];
+    assert_eq!(Length::sum_compensated(big_and_small).value, 2.0);
+
+    let empty = Length::sum_compensated(Vec::new());
+    assert_eq!(empty.value, 0.0);
+    assert_eq!(empty.unit, Unit::Metric(Meter));
+}
+
+#[test]
+fn test_volume() {
+    let width = Length::new_string("2 ft").unwrap();
+    let depth = Length::new_string("18 in").unwrap();
+    let height = Length::new_string("1 yd").unwrap();
+
+    let tank = Area::from_lengths(&width, &depth) * height.clone();
+    assert_eq!(tank.value, 9.0);
+    assert_eq!(tank.unit, VolumeUnit::Cubic(Unit::Imperial(Foot)));
+    assert_eq!(tank.to_string(), "9 ft³");
+    assert_eq!(Volume::from_lengths(&width, &depth, &height), tank);
+    assert_approx(tank.to(Inch).value, 9.0 * 1_728.0);
+    assert_approx(tank.to(VolumeUnit::Liter).value, 9.0 * 28.316_846_592);
+    assert_approx(tank.to(VolumeUnit::UsGallon).value, 9.0 * 1_728.0 / 231.0);
+    assert!(tank.to(VolumeUnit::UsGallon).to_string().ends_with(" gal"));
+
+    let cube = Length::new_string("1 m").unwrap().cubed();
+    assert_eq!(cube.to(Millimeter).value, 1e9);
+    assert_eq!(
+        cube.to(VolumeUnit::Liter).to(VolumeUnit::Milliliter).value,
+        1e6
+    );
+    assert_approx(
+        cube.to(VolumeUnit::ImperialGallon).value,
+        1.0 / 0.004_546_09,
+    );
+    assert_eq!(
+        Volume::new_value_unit(3, VolumeUnit::Liter)
+            .to(VolumeUnit::Liter)
+            .value,
+        3.0
+    );
+    assert_eq!(Volume::default().to_string(), "0 m³");
+}
+
+#[test]
+fn test_light_travel_time() {
+    let sun = Length::new_string("1 au").unwrap();
+    let travel_time = sun.light_travel_time();
+    assert_eq!(travel_time.as_secs(), 499);
+    assert_approx(travel_time.as_secs_f64(), 149_597_870_700.0 / 299_792_458.0);
+
+    assert_eq!(
+        Length::new_string("2 lh").unwrap().light_travel_time(),
+        Duration::from_secs(7_200)
+    );
+    assert_eq!(
+        Length::new_string("-1 ls").unwrap().light_travel_time(),
+        Duration::from_secs(1)
+    );
+    assert_eq!(
+        Length::new_string("299792458 m")
+            .unwrap()
+            .light_travel_time(),
+        Duration::from_secs(1)
+    );
+    assert_eq!(
+        Length::new_value_unit(f64::INFINITY, Meter).light_travel_time(),
+        Duration::MAX
+    );
+
+    let year = Length::from_light_travel(Duration::from_secs(365 * 86_400 + 6 * 3_600));
+    assert_eq!(year.unit, Unit::Astronomic(Lightsecond));
+    assert_approx(year.to(Lightyear).value, 1.0);
+    assert_eq!(
+        Length::from_light_travel(sun.light_travel_time())
+            .to(AstronomicalUnit)
+            .value
+            .round(),
+        1.0
+    );
+}
+
+#[test]
+fn test_from_coordinates() {
+    let paris_to_london = Length::from_coordinates(48.8566, 2.3522, 51.5074, -0.1278);
+    assert_eq!(paris_to_london.unit, Unit::Metric(Meter));
+    assert!((paris_to_london.value - 343_556.535).abs() < 0.01);
+
+    let new_york_to_london = Length::from_coordinates(40.7128, -74.0060, 51.5074, -0.1278);
+    assert!((new_york_to_london.value - 5_570_229.874).abs() < 0.01);
+    assert_approx(
+        Length::from_coordinates(0.0, 0.0, 0.0, 180.0).value,
+        geo::MEAN_EARTH_RADIUS * PI,
+    );
+    assert_approx(
+        Length::from_coordinates(0.0, 0.0, 90.0, 0.0).value,
+        geo::MEAN_EARTH_RADIUS * PI / 2.0,
+    );
+    assert_eq!(Length::from_coordinates(12.5, 7.0, 12.5, 7.0).value, 0.0);
+    assert_eq!(
+        Length::from_coordinates(48.8566, 2.3522, 51.5074, -0.1278).value,
+        Length::from_coordinates(51.5074, -0.1278, 48.8566, 2.3522).value
+    );
+}
+
+#[cfg(feature = "vincenty")]
+#[test]
+fn test_from_coordinates_vincenty() {
+    let quarter_meridian = Length::from_coordinates_vincenty(0.0, 0.0, 90.0, 0.0).unwrap();
+    assert!((quarter_meridian.value - 10_001_965.729).abs() < 0.001);
+
+    let equator = Length::from_coordinates_vincenty(0.0, 0.0, 0.0, 90.0).unwrap();
+    assert!((equator.value - 6_378_137.0 * PI / 2.0).abs() < 0.001);
+
+    assert_eq!(
+        Length::from_coordinates_vincenty(12.5, 7.0, 12.5, 7.0)
+            .unwrap()
+            .value,
+        0.0
+    );
+    assert!(Length::from_coordinates_vincenty(0.0, 0.0, 0.5, 179.7).is_none());
+}
+
+#[cfg(feature = "geo")]
+#[test]
+fn test_geo_geometries() {
+    use ::geo::{line_string, point, Area as _, BoundingRect, Line};
+
+    let line = Line::new((0.0, 0.0), (30.0, 40.0));
+    assert_eq!(Length::from(&line).value, 50.0);
+    assert_eq!(Length::from(&line).unit, Unit::Metric(Meter));
+
+    let fence = line_string![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 5.0)];
+    assert_eq!(Length::from(&fence).value, 15.0);
+    let in_feet = Length::from_geometry(&fence, Foot);
+    assert_eq!(in_feet.value, 15.0);
+    assert_approx(in_feet.to(Meter).value, 15.0 * 0.3048);
+
+    let zone = Length::new_string("3 ft")
+        .unwrap()
+        .buffer_geometry(&point!(x: 0.0, y: 0.0), Meter);
+    let bounds = zone.bounding_rect().unwrap();
+    assert_approx(bounds.max().x, 0.9144);
+    assert!((zone.unsigned_area() / (PI * 0.9144 * 0.9144) - 1.0).abs() < 0.01);
+}
+
+#[cfg(feature = "measurements")]
+#[test]
+fn test_measurements_conversions() {
+    use measurements::Length as MeasurementsLength;
+
+    let length: MeasurementsLength = Length::new_string("12 in").unwrap().into();
+    assert_approx(length.as_meters(), 0.3048);
+    assert_approx(length.as_inches(), 12.0);
+
+    let back = Length::from(MeasurementsLength::from_centimeters(30.48));
+    assert_eq!(back.unit, Unit::Metric(Meter));
+    assert_approx(back.to(Foot).value, 1.0);
+}
+
+#[cfg(feature = "uom")]
+#[test]
+fn test_uom_conversions() {
+    use uom::si::f64::Length as UomLength;
+    use uom::si::length::{centimeter, inch, meter};
+
+    let length: UomLength = Length::new_string("12 in").unwrap().into();
+    assert_approx(length.get::<meter>(), 0.3048);
+    assert_approx(length.get::<inch>(), 12.0);
+
+    let back = Length::from(UomLength::new::<centimeter>(30.48));
+    assert_eq!(back.unit, Unit::Metric(Meter));
+    assert_approx(back.to(Foot).value, 1.0);
+
+    let area = length * UomLength::new::<meter>(2.0);
+    assert_approx(area.value, 0.6096);
+}
+
+#[test]
+fn test_scientific_notation() {
+    let length = Length::new_string("1.5e3 m").unwrap();
+    assert_eq!(length.value, 1_500.0);
+    assert_eq!(length.unit, Unit::Metric(Meter));
+    assert_eq!(length.get_original_string(), "1.5e3 m");
+
+    assert_eq!(Length::new_string("2E-6 km").unwrap().value, 2e-6);
+    assert_eq!(Length::new_string("-4e+2mm").unwrap().value, -400.0);
+    assert_eq!(
+        Length::new_string("1e5 ft").unwrap().unit,
+        Unit::Imperial(Foot)
+    );
+    assert_eq!(
+        Length::parse("1e5").err(),
+        Some(ParseLengthError::UnknownUnit {
+            unit: String::from("e5"),
+            span: 1..3,
+        })
+    );
+    assert_eq!(
+        Length::parse("1e5x").err(),
+        Some(ParseLengthError::UnknownUnit {
+            unit: String::from("x"),
+            span: 3..4,
+        })
+    );
+    assert!(Length::new_string("1e m").is_none());
+}
+
+#[test]
+fn test_const_lengths() {
+    const ORIGIN: Length = Length::const_new(0.0, Unit::Metric(Meter));
+    const MAX_RANGE: Length = Length::const_new(5.0, Unit::Metric(Kilometer));
+    static WIDTH: Length = Length::const_new(8.5, Unit::Imperial(Inch));
+    const FOOT_IN_METERS: f64 = Foot.meters_per_unit();
+    const LIGHTMINUTE_IN_LIGHTSECONDS: f64 =
+        Lightminute.meters_per_unit() / Lightsecond.meters_per_unit();
+
+    assert_eq!(ORIGIN.value, 0.0);
+    assert_eq!(MAX_RANGE.value, 5.0);
+    assert_eq!(MAX_RANGE.unit, Unit::Metric(Kilometer));
+    assert_eq!(MAX_RANGE.to(Meter).value, 5_000.0);
+    assert_approx(WIDTH.to(Centimeter).value, 21.59);
+    assert_eq!(FOOT_IN_METERS, Unit::Imperial(Foot).meters_per_unit());
+    assert_eq!(LIGHTMINUTE_IN_LIGHTSECONDS, 60.0);
+    assert_eq!(
+        SurveyMile.meters_per_unit(),
+        Unit::UsSurvey(SurveyMile).meters_per_unit()
+    );
+}
+
+#[test]
+fn test_length_macro() {
+    const MAX_RANGE: Length = length!("2.5 km");
+    assert_eq!(MAX_RANGE.value, 2.5);
+    assert_eq!(MAX_RANGE.unit, Unit::Metric(Kilometer));
+
+    let literals = [
+        "2.5 km",
+        "  -3 ft ",
+        "+12.125in",
+        "1.5e3 m",
+        "2E-6 km",
+        "0.1 mm",
+        "42 µm",
+        "8 R☉",
+        "5 ″",
+        "3 Light Years",
+        "7 metres",
+        "2 survey-feet",
+        "123456789.123456 nm",
+    ];
+    for literal in literals {
+        let expected = Length::parse(literal).unwrap();
+        let actual = Length::const_parse(literal).unwrap();
+        assert_eq!(actual.value, expected.value, "{}", literal);
+        assert_eq!(actual.unit, expected.unit, "{}", literal);
+    }
+
+    assert_eq!(length!("-0.75 mi").value, -0.75);
+    assert!(Length::const_parse("2.5 kmx").is_none());
+    assert!(Length::const_parse("km").is_none());
+    assert!(Length::const_parse("2.5").is_none());
+    assert!(Length::const_parse("2. km").is_none());
+    assert!(Length::const_parse("6 ft 2 in").is_none());
+    assert!(Length::const_parse("1/2 in").is_none());
+    assert!(Length::const_parse("1e30 m").is_none());
+    assert!(Length::const_parse("12345678901234567890 m").is_none());
+}
+
+#[test]
+fn test_unit_symbols_and_names() {
+    let kilometer = Unit::Metric(Kilometer);
+    assert_eq!(kilometer.symbol(), "km");
+    assert_eq!(kilometer.name(), "kilometer");
+    assert_eq!(kilometer.plural_name(), "kilometers");
+
+    assert_eq!(Foot.symbol(), "ft");
+    assert_eq!(Foot.name(), "foot");
+    assert_eq!(Foot.plural_name(), "feet");
+    assert_eq!(Unit::Astronomic(SolarRadius).plural_name(), "solar radii");
+    assert_eq!(Unit::UsSurvey(SurveyFoot).name(), "survey foot");
+    assert_eq!(
+        DidotPoint.to_string(),
+        Unit::Typographic(DidotPoint).symbol()
+    );
+
+    for unit in [
+        Unit::Astronomic(Parsec),
+        Unit::Imperial(Mile),
+        Unit::Metric(Angstrom),
+        Unit::Subatomic(PlanckLength),
+        Unit::Typographic(Pica),
+        Unit::UsSurvey(SurveyChain),
+    ] {
+        assert_eq!(unit.symbol().parse::<Unit>(), Ok(unit));
+        assert_eq!(unit.name().parse::<Unit>(), Ok(unit));
+        assert_eq!(unit.plural_name().parse::<Unit>(), Ok(unit));
+    }
+
+    let hide = CustomUnit::register("hide", 0.4).unwrap();
+    assert_eq!(Unit::Custom(hide).symbol(), "hide");
+    assert_eq!(Unit::Custom(hide).name(), "hide");
+    assert_eq!(Unit::Custom(hide).plural_name(), "hide");
+}
+
+#[test]
+fn test_unit_enumeration() {
+    let units: Vec<Unit> = Unit::all().collect();
+    assert!(units.len() >= 60);
+    assert!(units
+        .windows(2)
+        .all(|pair| pair[0].meters_per_unit() <= pair[1].meters_per_unit()));
+    assert_eq!(units.first(), Some(&Unit::Subatomic(PlanckLength)));
+
+    let metric = UnitSystem::Metric.units();
+    assert_eq!(metric.len(), 26);
+    assert_eq!(metric[0], Unit::Metric(Quectometer));
+    assert_eq!(metric[7], Unit::Metric(Angstrom));
+    assert_eq!(metric[25], Unit::Metric(Quettameter));
+    assert_eq!(
+        UnitSystem::UsSurvey.units(),
+        vec![
+            Unit::UsSurvey(SurveyFoot),
+            Unit::UsSurvey(SurveyChain),
+            Unit::UsSurvey(SurveyMile),
+        ]
+    );
+    assert_eq!(UnitSystem::Astronomic.units().len(), 13);
+    assert_eq!(UnitSystem::Typographic.units().len(), 6);
+
+    let league = CustomUnit::register("lieue", 4_444.0).unwrap();
+    assert!(UnitSystem::Custom.units().contains(&Unit::Custom(league)));
+}
+
+#[test]
+fn test_unit_ordering() {
+    assert!(Unit::Metric(Micrometer) < Unit::Metric(Millimeter));
+    assert!(Unit::Imperial(Inch) > Unit::Metric(Centimeter));
+    assert!(Unit::Imperial(Mile) < Unit::UsSurvey(SurveyMile));
+    assert!(Unit::Astronomic(Lightminute) < Unit::Astronomic(AstronomicalUnit));
+    assert!(Unit::Typographic(Point) < Unit::Typographic(Pica));
+    assert!(Foot < Yard);
+    assert!(Kilometer > Hectometer);
+    assert!(PlanckLength < BohrRadius);
+    assert!(SurveyChain < SurveyMile);
+    assert!(Lightyear < Parsec);
+    assert!(Cicero > DidotPoint);
+
+    let mut units = vec![
+        Unit::Imperial(Foot),
+        Unit::Metric(Millimeter),
+        Unit::Astronomic(Lightyear),
+        Unit::Typographic(Point),
+        Unit::Metric(Meter),
+    ];
+    units.sort();
+    assert_eq!(
+        units,
+        vec![
+            Unit::Typographic(Point),
+            Unit::Metric(Millimeter),
+            Unit::Imperial(Foot),
+            Unit::Metric(Meter),
+            Unit::Astronomic(Lightyear),
+        ]
+    );
+    assert_eq!(units.iter().max(), Some(&Unit::Astronomic(Lightyear)));
+
+    let metre = CustomUnit::register("metre-alias", 1.0).unwrap();
+    assert_ne!(
+        Unit::Custom(metre).cmp(&Unit::Metric(Meter)),
+        std::cmp::Ordering::Equal
+    );
+}
+
+#[test]
+fn test_unit_system_names() {
+    let systems = [
+        UnitSystem::Astronomic,
+        UnitSystem::Custom,
+        UnitSystem::Imperial,
+        UnitSystem::Metric,
+        UnitSystem::Subatomic,
+        UnitSystem::Typographic,
+        UnitSystem::UsSurvey,
+    ];
+    for system in systems {
+        assert_eq!(system.to_string().parse::<UnitSystem>(), Ok(system));
+    }
+
+    assert_eq!("METRIC".parse::<UnitSystem>(), Ok(UnitSystem::Metric));
+    assert_eq!(" imperial ".parse::<UnitSystem>(), Ok(UnitSystem::Imperial));
+    assert_eq!("US_Survey".parse::<UnitSystem>(), Ok(UnitSystem::UsSurvey));
+    assert!("".parse::<UnitSystem>().is_err());
+    assert!("nautical".parse::<UnitSystem>().is_err());
+    assert_eq!(format!("{:>8}", UnitSystem::Metric), "  metric");
+    assert_eq!(Unit::Imperial(Foot).system().to_string(), "imperial");
+
+    let set: std::collections::HashSet<UnitSystem> = systems.into_iter().collect();
+    assert_eq!(set.len(), 7);
+}
+
+#[test]
+fn test_length_debug_and_hash() {
+    use std::collections::{HashMap, HashSet};
+
+    let debug = format!("{:?}", Length::new_string("2.5 km").unwrap());
+    assert!(debug.starts_with("Length {"), "{}", debug);
+    assert!(debug.contains("Metric(Kilometer)"), "{}", debug);
+    assert!(debug.contains("2.5"), "{}", debug);
+
+    let mut lengths = HashSet::new();
+    lengths.insert(Length::new_string("1 km").unwrap());
+    lengths.insert(Length::new_string("1000 m").unwrap());
+    lengths.insert(Length::new_string("100000 cm").unwrap());
+    lengths.insert(Length::new_string("1 mi").unwrap());
+    lengths.insert(Length::new_value_unit(0.0, Meter));
+    lengths.insert(Length::new_value_unit(-0.0, Foot));
+    assert_eq!(lengths.len(), 3);
+    lengths.insert(Length::new_value_unit(f64::NAN, Meter));
+    lengths.insert(Length::new_value_unit(-f64::NAN, Mile));
+    assert_eq!(lengths.len(), 4);
+    assert!(lengths.contains(&Length::new_value_unit(f64::NAN, Inch)));
+
+    let mut names = HashMap::new();
+    names.insert(Length::new_string("1 ft").unwrap(), "foot");
+    assert_eq!(
+        names.get(&Length::new_string("12 in").unwrap()),
+        Some(&"foot")
+    );
+    assert_eq!(names.get(&Length::new_string("13 in").unwrap()), None);
+
+    assert_eq!(
+        Length::parse("2.5 km"),
+        Ok(Length::new_value_unit(2_500, Meter))
+    );
+}
+
+#[test]
+fn test_lenient_parsing() {
+    let lenient = |string: &str| Length::new_string_lenient(string).map(|length| length.unit);
+
+    assert_eq!(lenient("5 KM"), Some(Unit::Metric(Kilometer)));
+    assert_eq!(lenient("5 Km"), Some(Unit::Metric(Kilometer)));
+    assert_eq!(lenient("5 MM"), Some(Unit::Metric(Millimeter)));
+    assert_eq!(lenient("5 mM"), Some(Unit::Metric(Millimeter)));
+    assert_eq!(lenient("5 Mm"), Some(Unit::Metric(Megameter)));
+    assert_eq!(lenient("5 PM"), Some(Unit::Metric(Picometer)));
+    assert_eq!(lenient("5 Ld"), Some(Unit::Astronomic(Lightday)));
+    assert_eq!(lenient("5 LD"), Some(Unit::Astronomic(LunarDistance)));
+    assert_eq!(lenient("5 FTus"), Some(Unit::UsSurvey(SurveyFoot)));
+    assert_eq!(lenient("6 FT 2 IN"), Some(Unit::Imperial(Inch)));
+    assert_eq!(lenient("3 1/2 IN"), Some(Unit::Imperial(Inch)));
+    assert_eq!(lenient("5 xyz"), None);
+    assert!(Length::new_string("5 KM").is_none());
+
+    assert_eq!(
+        Length::parse("5 k m").err(),
+        Some(ParseLengthError::SpacedUnit {
+            unit: String::from("k m"),
+            span: 2..5,
+        })
+    );
+    assert_eq!(
+        LengthParser::new()
+            .lenient(true)
+            .parse("5 K M")
+            .err()
+            .map(|error| error.to_string()),
+        Some(String::from(
+            "unit \"K M\" at 2..5 must not contain whitespace"
+        ))
+    );
+    assert_eq!(
+        LengthParser::new()
+            .thousands_separator(',')
+            .parse("1,500 k m")
+            .err(),
+        Some(ParseLengthError::SpacedUnit {
+            unit: String::from("k m"),
+            span: 6..9,
+        })
+    );
+    assert_eq!(
+        Length::parse("5 x y").err(),
+        Some(ParseLengthError::MalformedFormat {
+            input: String::from("5 x y"),
+        })
+    );
+}
+
+#[test]
+fn test_micro_sign_variants_and_ascii_display() {
+    for string in ["3 µm", "3 \u{3BC}m", "3 um", "3 mcm", "3 micrometers"] {
+        let length = Length::new_string(string).unwrap();
+        assert_eq!(length.unit, Unit::Metric(Micrometer), "{}", string);
+        assert_eq!(length.value, 3.0);
+    }
+
+    let length = Length::new_string("3 μm").unwrap();
+    assert_eq!(length.to_string(), "3 µm");
+    assert_eq!(format!("{:#}", length), "3 um");
+    assert_eq!(format!("{:#.1}", length), "3.0 um");
+    assert_eq!(format!("{:>#7}", length), "   3 um");
+
+    let units: Vec<Unit> = Unit::all().collect();
+    for unit in units
+        .into_iter()
+        .filter(|unit| unit.system() != UnitSystem::Custom)
+    {
+        let ascii_symbol = unit.ascii_symbol();
+        assert!(ascii_symbol.is_ascii(), "{}", ascii_symbol);
+        assert_eq!(
+            ascii_symbol.parse::<Unit>().ok(),
+            unit.symbol().parse::<Unit>().ok()
+        );
+    }
+}
+
+#[test]
+fn test_find_all() {
+    let text = "The bridge is 1.2 km long and 15 m wide.";
+    let found: Vec<(String, &str)> = Length::find_all(text)
+        .into_iter()
+        .map(|(length, span)| (length.to_string(), &text[span]))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (String::from("1.2 km"), "1.2 km"),
+            (String::from("15 m"), "15 m")
+        ]
+    );
+
+    let text = "He is 6 ft 2 in (about 1.88 m), the shelf is 3 1/2 in deep, the door 6'8\".";
+    let spans: Vec<&str> = Length::find_all(text)
+        .into_iter()
+        .map(|(_, span)| &text[span])
+        .collect();
+    assert_eq!(spans, vec!["6 ft 2 in", "1.88 m", "3 1/2 in", "6'8\""]);
+
+    let text = "Proxima Centauri is 4.24 light years away; route A4 has 12 lanes, v1.5 km3 -3 °C.";
+    let found = Length::find_all(text);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].0.unit, Unit::Astronomic(Lightyear));
+    assert_eq!(&text[found[0].1.clone()], "4.24 light years");
+
+    let text = "Δh = -2.5 m, Δx = 1e3 mm";
+    let found = Length::find_all(text);
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].0.value, -2.5);
+    assert_eq!(found[1].0.value, 1_000.0);
+    assert_eq!(&text[found[1].1.clone()], "1e3 mm");
+
+    assert!(Length::find_all("").is_empty());
+    assert!(Length::find_all("no lengths here, only 42").is_empty());
+}
+
+#[test]
+fn test_convert_all() {
+    use length::text::{self, ConvertOptions};
+
+    assert_eq!(
+        text::convert_all(
+            "The trail is 3 mi long and climbs 1200 ft; the car park is 200 m away.",
+            UnitSystem::Metric
+        ),
+        "The trail is 4.83 km long and climbs 365.76 m; the car park is 200 m away."
+    );
+    assert_eq!(
+        text::convert_all("A 100 m sprint, 5'10\" tall.", UnitSystem::Imperial),
+        "A 328.08 ft sprint, 5'10\" tall."
+    );
+    assert_eq!(text::convert_all("", UnitSystem::Metric), "");
+    assert_eq!(
+        text::convert_all("nothing to convert", UnitSystem::Metric),
+        "nothing to convert"
+    );
+
+    let options = ConvertOptions::new()
+        .precision(0)
+        .units(&[Unit::Metric(Millimeter), Unit::Metric(Meter)]);
+    assert_eq!(
+        text::convert_all_with(
+            "Boards of 2 cm and 1.5 m, screws of 3/4 in.",
+            UnitSystem::Metric,
+            &options
+        ),
+        "Boards of 20 mm and 1.5 m, screws of 19 mm."
+    );
+}
+
+#[test]
+fn test_convert_slice() {
+    let values: Vec<f64> = (0..1_000).map(|i| i as f64 * 0.37 - 50.0).collect();
+    let converted = Length::convert_slice(&values, Unit::Imperial(Foot), Unit::Metric(Meter));
+    for (value, converted) in values.iter().zip(&converted) {
+        assert_eq!(
+            *converted,
+            Length::new_value_unit(*value, Foot).to(Meter).value
+        );
+    }
+
+    let mut in_place = values.clone();
+    Length::convert_slice_in_place(&mut in_place, Lightyear, Parsec);
+    for (value, converted) in values.iter().zip(&in_place) {
+        assert_eq!(
+            *converted,
+            Length::new_value_unit(*value, Lightyear).to(Parsec).value
+        );
+    }
+
+    let single: Vec<f32> = Length::convert_slice(&[1.0_f32, 2.0], Kilometer, Meter);
+    assert_eq!(single, vec![1_000.0, 2_000.0]);
+    assert!(Length::convert_slice(&[] as &[f64], Inch, Centimeter).is_empty());
+    assert_eq!(Length::convert_slice(&[7.5], Inch, Inch), vec![7.5]);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_parallel_conversions() {
+    let values: Vec<f64> = (0..50_000).map(|i| f64::from(i) * 0.013 - 100.0).collect();
+    assert_eq!(
+        Length::par_convert_slice(&values, Mile, Kilometer),
+        Length::convert_slice(&values, Mile, Kilometer)
+    );
+
+    let mut in_place = values.clone();
+    Length::par_convert_slice_in_place(&mut in_place, Inch, Centimeter);
+    assert_eq!(in_place, Length::convert_slice(&values, Inch, Centimeter));
+
+    let lengths: Vec<Length> = (1..=10_000)
+        .map(|i| Length::new_value_unit(f64::from(i), Centimeter))
+        .collect();
+    let total = Length::par_sum(&lengths);
+    assert_eq!(total.unit, Unit::Metric(Centimeter));
+    assert_approx(total.value, 50_005_000.0);
+    assert_eq!(Length::par_sum(&[] as &[Length]).value, 0.0);
+}
+
+#[test]
+fn test_conversions_without_original_string() {
+    let length = Length::new_string("1500 m").unwrap().with_error_bound(0.5);
+
+    let same_unit = length.to(Meter);
+    assert_eq!(same_unit.value, 1_500.0);
+    assert_eq!(same_unit.get_original_string(), "");
+    assert_eq!(same_unit.error_bound(), Some(0.5));
+
+    let normalized = length.normalize();
+    assert_eq!(normalized.unit, Unit::Metric(Kilometer));
+    assert_eq!(normalized.get_original_string(), "");
+    assert!(normalized.error_bound().unwrap() >= 0.0005);
+
+    let mut in_place = Length::new_string("1500 m").unwrap();
+    in_place.to_by_ref(Kilometer);
+    assert_eq!(in_place.value, 1.5);
+    assert_eq!(in_place.get_original_string(), "1500 m");
+    in_place.normalize_by_ref();
+    assert_eq!(in_place.unit, Unit::Metric(Kilometer));
+}
+
+#[test]
+fn test_factor_table_conversions() {
+    let units: Vec<Unit> = Unit::all().collect();
+    for from in &units {
+        for to in &units {
+            let converted = Length::new_value_unit(1.5, *from).to(*to);
+            assert_eq!(*to, converted.unit);
+            assert_approx(
+                converted.value,
+                1.5 * from.meters_per_unit() / to.meters_per_unit(),
+            );
+        }
+    }
+
+    assert_eq!(63_360.0, Length::new_value_unit(1.0, Mile).to(Inch).value);
+    assert_eq!(
+        1_200.0,
+        Length::new_value_unit(3_937.0, SurveyFoot).to(Meter).value
+    );
+    assert_eq!(
+        0.1,
+        Length::new_value_unit(0.1, Kilometer)
+            .to(Meter)
+            .to(Kilometer)
+            .value
+    );
+    assert_eq!(
+        Length::convert_slice(&[2.5], Lightyear, Parsec)[0],
+        Length::new_value_unit(2.5, Lightyear).to(Parsec).value
+    );
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    let meter = Length::new_value_unit(1.0, Meter);
+    let huge = Length::new_value_unit(f64::MAX, Meter);
+
+    assert_eq!(
+        Some(Length::new_value_unit(2.0, Meter)),
+        meter.checked_add(meter.clone())
+    );
+    assert_eq!(
+        Some(Length::new_value_unit(0.0, Meter)),
+        meter.checked_sub(meter.clone())
+    );
+    assert_eq!(None, huge.checked_add(huge.clone()));
+    assert_eq!(None, huge.checked_sub(-huge.clone()));
+    assert_eq!(
+        None,
+        meter.checked_add(Length::new_value_unit(1e300, Yottameter).to(Yoctometer))
+    );
+
+    assert_eq!(
+        Some(Length::new_value_unit(3.0, Meter)),
+        meter.checked_mul(3)
+    );
+    assert_eq!(None, huge.checked_mul(2));
+    assert_eq!(None, meter.checked_mul(f64::INFINITY));
+    assert_eq!(None, meter.checked_mul(f64::NAN));
+
+    assert_eq!(
+        Some(Length::new_value_unit(0.5, Meter)),
+        meter.checked_div(2)
+    );
+    assert_eq!(None, meter.checked_div(0));
+    assert_eq!(None, meter.checked_div(-0.0));
+    assert_eq!(None, huge.checked_div(0.5));
+
+    assert!(meter.is_finite());
+    assert!(!Length::new_value_unit(f64::NEG_INFINITY, Meter).is_finite());
+    assert!(!Length::new_value_unit(f64::NAN, Meter).is_finite());
+    assert!(!Length::new_value_unit(1e300, Yottameter)
+        .to(Yoctometer)
+        .is_finite());
+    assert!(Length::from_scalar(1e30_f32, Meter).is_finite());
+    assert!(!Length::from_scalar(1e30_f32, Meter)
+        .to(Picometer)
+        .is_finite());
+}
+
+#[test]
+fn test_validated_constructor() {
+    assert_eq!(
+        Ok(Length::new_value_unit(-2.5, Foot)),
+        Length::try_new(-2.5, Foot)
+    );
+    assert_eq!(
+        Err(LengthError::NotFinite {
+            value: f64::NEG_INFINITY
+        }),
+        Length::try_new(f64::NEG_INFINITY, Meter)
+    );
+    assert!(matches!(
+        Length::try_new(f64::NAN, Meter),
+        Err(LengthError::NotFinite { value }) if value.is_nan()
+    ));
+
+    let non_negative = Validation {
+        non_negative: true,
+        ..Validation::default()
+    };
+    assert_eq!(
+        Ok(Length::new_value_unit(3, Kilometer)),
+        Length::try_new_with(3, Kilometer, non_negative)
+    );
+    assert!(Length::try_new_with(-0.0, Meter, non_negative).is_ok());
+    assert_eq!(
+        Err(LengthError::Negative { value: -0.1 }),
+        Length::try_new_with(-0.1, Meter, non_negative)
+    );
+    assert_eq!(
+        Err(LengthError::NotFinite {
+            value: f64::INFINITY
+        }),
+        Length::try_new_with(f64::INFINITY, Meter, non_negative)
+    );
+
+    let unchecked = Validation {
+        finite: false,
+        non_negative: false,
+    };
+    assert!(Length::try_new_with(f64::INFINITY, Meter, unchecked).is_ok());
+    assert_eq!(
+        "value -0.1 must not be negative",
+        LengthError::Negative { value: -0.1 }.to_string()
+    );
+    assert_eq!(
+        "value inf is not finite",
+        LengthError::NotFinite {
+            value: f64::INFINITY
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn test_range_step() {
+    let values = |start: Length, end: Length, step: Length| -> Vec<f64> {
+        Length::range_step(start, end, step)
+            .map(|length| length.value)
+            .collect()
+    };
+
+    assert_eq!(
+        vec![
+            0.0,
+            0.1,
+            0.2,
+            0.30000000000000004,
+            0.4,
+            0.5,
+            0.6000000000000001,
+            0.7000000000000001,
+            0.8,
+            0.9,
+            1.0
+        ],
+        values(
+            Length::new_value_unit(0.0, Meter),
+            Length::new_value_unit(1.0, Meter),
+            Length::new_value_unit(0.1, Meter),
+        )
+    );
+    assert_eq!(
+        vec![0.0, 0.1, 0.2, 0.3],
+        values(
+            Length::new_value_unit(0.0, Meter),
+            Length::new_value_unit(0.3, Meter),
+            Length::new_value_unit(0.1, Meter),
+        )
+    );
+    assert_eq!(
+        vec![0.0, 12.0, 24.0, 36.0],
+        values(
+            Length::new_value_unit(0.0, Inch),
+            Length::new_value_unit(1.0, Yard),
+            Length::new_value_unit(1.0, Foot),
+        )
+    );
+    assert_eq!(
+        vec![1.0, 0.75, 0.5],
+        values(
+            Length::new_value_unit(1.0, Meter),
+            Length::new_value_unit(40.0, Centimeter),
+            Length::new_value_unit(-25.0, Centimeter),
+        )
+    );
+    assert_eq!(
+        vec![5.0],
+        values(
+            Length::new_value_unit(5.0, Meter),
+            Length::new_value_unit(5.0, Meter),
+            Length::new_value_unit(1.0, Meter),
+        )
+    );
+    assert_eq!(
+        vec![5.0],
+        values(
+            Length::new_value_unit(5.0, Meter),
+            Length::new_value_unit(10.0, Meter),
+            Length::new_value_unit(0.0, Meter),
+        )
+    );
+    assert!(values(
+        Length::new_value_unit(5.0, Meter),
+        Length::new_value_unit(10.0, Meter),
+        Length::new_value_unit(-1.0, Meter),
+    )
+    .is_empty());
+
+    let ruler = Length::range_step(
+        Length::new_value_unit(0.0, Millimeter),
+        Length::new_value_unit(1.0, Kilometer),
+        Length::new_value_unit(1.0, Millimeter),
+    );
+    let last = ruler.last().unwrap();
+    assert_eq!(1_000_000.0, last.value);
+    assert_eq!(Unit::Metric(Millimeter), last.unit);
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn test_approx_comparisons() {
+    use approx::{abs_diff_eq, assert_relative_eq, relative_eq, ulps_eq};
+
+    let mile = Length::new_value_unit(1.0, Mile);
+    let round_trip = mile.to(Kilometer).to(Furlong).to(Mile);
+
+    assert_relative_eq!(mile, round_trip);
+    assert_relative_eq!(Length::new_value_unit(1.609344, Kilometer), mile);
+    assert!(ulps_eq!(mile, round_trip));
+    assert!(!relative_eq!(mile, Length::new_value_unit(1.61, Kilometer)));
+    assert!(relative_eq!(
+        mile,
+        Length::new_value_unit(1.61, Kilometer),
+        max_relative = 0.001
+    ));
+
+    // The epsilon is given in the unit of the left-hand side.
+    let meter = Length::new_value_unit(1.0, Meter);
+    let slightly_more = Length::new_value_unit(100.4, Centimeter);
+    assert!(abs_diff_eq!(meter, slightly_more, epsilon = 0.005));
+    assert!(!abs_diff_eq!(slightly_more, meter, epsilon = 0.005));
+    assert!(!abs_diff_eq!(
+        meter,
+        Length::new_value_unit(f64::NAN, Meter)
+    ));
+
+    assert_relative_eq!(
+        Length::from_scalar(2.54_f32, Centimeter),
+        Length::from_scalar(1.0_f32, Inch)
+    );
+}
+
+#[test]
+fn test_fuzz_entry_point() {
+    use length::fuzz;
+
+    assert_eq!(
+        Some(Length::new_value_unit(74.0, Inch)),
+        fuzz::parse_any(b"6 ft 2 in")
+    );
+    assert_eq!(
+        Some(Length::new_value_unit(0.5, Inch)),
+        fuzz::parse_any("½ in".as_bytes())
+    );
+
+    let malformed: [&[u8]; 12] = [
+        b"",
+        b"   ",
+        b"\xff\xfe",
+        b"5 \xc3",
+        b"1/0 in",
+        b"0/0 in",
+        b"5'10\"\"",
+        b"- 5 m",
+        b"1e99999 m",
+        b"\x00 m",
+        b"\xc2\xb1\xc2\xb1 2 mm",
+        b"3 5/ in",
+    ];
+    for bytes in malformed {
+        fuzz::parse_any(bytes);
+    }
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli() {
+    use std::io::Write;
+    use std::process::{Command, Output, Stdio};
+
+    let run = |arguments: &[&str], stdin: &str| -> Output {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_length"))
+            .args(arguments)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(stdin.as_bytes())
+            .unwrap();
+        child.wait_with_output().unwrap()
+    };
+    let stdout = |output: Output| String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!(
+        "3.107 mi\n",
+        stdout(run(
+            &["convert", "5 km", "--to", "mi", "--precision", "3"],
+            ""
+        ))
+    );
+    assert_eq!("5 km\n", stdout(run(&["normalize", "5000 m"], "")));
+    assert_eq!(
+        "12 in\n36 in\n",
+        stdout(run(&["convert", "--to", "inches"], "1 ft\n\n1 yd\n"))
+    );
+    assert_eq!(
+        "4.718688 km\n",
+        stdout(run(&["sum"], "1 km\n500 m\n2 mi\n"))
+    );
+
+    let failed = run(&["sum", "-"], "1 m\nabc\n");
+    assert!(!failed.status.success());
+    assert_eq!(
+        "length: line 2: \"abc\" is not of the form <number> <unit>\n",
+        String::from_utf8(failed.stderr).unwrap()
+    );
+    assert!(
+        !run(&["convert", "5 km", "--to", "furlongs per fortnight"], "")
+            .status
+            .success()
+    );
+}
+
+#[test]
+fn test_conversion_tables() {
+    use length::table::{self, TableFormat};
+
+    let units = [
+        Unit::Metric(Centimeter),
+        Unit::Imperial(Inch),
+        Unit::Imperial(Foot),
+    ];
+
+    assert_eq!(
+        "|  | cm | in | ft |\n\
+         |---|---:|---:|---:|\n\
+         | 1 cm | 1 | 0.393701 | 0.0328084 |\n\
+         | 1 in | 2.54 | 1 | 0.0833333 |\n\
+         | 1 ft | 30.48 | 12 | 1 |\n",
+        table::generate(&units, TableFormat::Markdown)
+    );
+    assert_eq!(
+        ",cm,in,ft\n\
+         1 cm,1,0.393701,0.0328084\n\
+         1 in,2.54,1,0.0833333\n\
+         1 ft,30.48,12,1\n",
+        table::generate(&units, TableFormat::Csv)
+    );
+    assert_eq!(
+        "+------+-------+----------+-----------+\n\
+         |      |    cm |       in |        ft |\n\
+         +------+-------+----------+-----------+\n\
+         | 1 cm |     1 | 0.393701 | 0.0328084 |\n\
+         | 1 in |  2.54 |        1 | 0.0833333 |\n\
+         | 1 ft | 30.48 |       12 |         1 |\n\
+         +------+-------+----------+-----------+\n",
+        table::generate(&units, TableFormat::Ascii)
+    );
+
+    let astronomic = [Unit::Metric(Nanometer), Unit::Astronomic(Lightyear)];
+    assert_eq!(
+        ",nm,ly\n1 nm,1,1.057e-25\n1 ly,9.46073e24,1\n",
+        table::generate(&astronomic, TableFormat::Csv)
+    );
+
+    let quoted = CustomUnit::register("\"q,", 2.0).unwrap();
+    assert_eq!(
+        ",m,\"\"\"q,\"\n1 m,1,0.5\n\"1 \"\"q,\",2,1\n",
+        table::generate(
+            &[Unit::Metric(Meter), Unit::Custom(quoted)],
+            TableFormat::Csv
+        )
+    );
+    assert_eq!("|  |\n|---|\n", table::generate(&[], TableFormat::Markdown));
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn test_c_bindings() {
+    use length::ffi::{self, CLength};
+    use std::ffi::{CStr, CString};
+
+    let mut parsed = CLength {
+        value: 0.0,
+        unit: 0,
+    };
+    let mut feet = 0;
+    let mut converted = CLength {
+        value: 0.0,
+        unit: 0,
+    };
+    unsafe {
+        assert!(ffi::length_parse(c"6 ft 2 in".as_ptr(), &mut parsed));
+        assert_eq!(
+            CLength {
+                value: 74.0,
+                unit: Unit::Imperial(Inch).code()
+            },
+            parsed
+        );
+        assert!(ffi::length_unit_code(c"feet".as_ptr(), &mut feet));
+        assert!(ffi::length_convert(parsed, feet, &mut converted));
+    }
+    assert_eq!(Unit::Imperial(Foot).code(), feet);
+    assert_approx(converted.value, 74.0 / 12.0);
+
+    let string = ffi::length_to_string(CLength {
+        value: 2.5,
+        unit: Unit::Metric(Kilometer).code(),
+    });
+    assert_eq!(
+        "2.5 km",
+        unsafe { CStr::from_ptr(string) }.to_str().unwrap()
+    );
+    unsafe { ffi::length_string_free(string) };
+
+    let invalid = CString::new("5 parsecs per hour").unwrap();
+    let unchanged = parsed;
+    unsafe {
+        assert!(!ffi::length_parse(invalid.as_ptr(), &mut parsed));
+        assert!(!ffi::length_parse(std::ptr::null(), &mut parsed));
+        assert!(!ffi::length_parse(c"5 m".as_ptr(), std::ptr::null_mut()));
+        assert!(!ffi::length_unit_code(
+            c"parsecs per hour".as_ptr(),
+            &mut feet
+        ));
+        assert!(!ffi::length_convert(parsed, 0, &mut converted));
+        ffi::length_string_free(std::ptr::null_mut());
+    }
+    assert_eq!(unchanged, parsed);
+    assert!(ffi::length_to_string(CLength {
+        value: 1.0,
+        unit: 0
+    })
+    .is_null());
+}
+
+#[cfg(feature = "python")]
+#[test]
+fn test_python_bindings() {
+    use pyo3::prelude::*;
+    use pyo3::types::PyDict;
+
+    Python::initialize();
+    Python::attach(|py| {
+        let module = PyModule::new(py, "length").unwrap();
+        length::python::python_module(&module).unwrap();
+        let globals = PyDict::new(py);
+        globals.set_item("length", module).unwrap();
+
+        py.run(
+            cr#"
+Length, Unit = length.Length, length.Unit
+
+miles = Length("5 km").to("mi")
+assert abs(miles.value - 3.1068559611866697) < 1e-12, miles.value
+assert miles.unit == Unit("mi") == "miles"
+assert str(Length(5, "ft").to(Unit("in"))) == "60 in"
+assert repr(Length("1500 m").normalize()) == "Length('1.5 km')"
+assert Length("1 km") == Length(1000, "m")
+assert Length("1 ft") < Length("1 m")
+nan = Length(float("nan"), "m")
+assert nan == Length(float("nan"), "ft")
+assert nan != Length("1 m") and not nan < Length("1 m") and not nan >= Length("1 m")
+assert str(Length("1 km") + Length("500 m")) == "1.5 km"
+assert str(2 * Length("3 m") - Length("1 m")) == "5 m"
+assert str(-Length("3 m") / 2) == "-1.5 m"
+assert Unit("km").meters_per_unit == 1000.0
+assert Unit("km").symbol == "km" and Unit("km").name == "kilometer"
+assert len({Unit("km"), Unit("kilometers")}) == 1
+assert Unit("m") in Unit.all()
+
+for invalid in [lambda: Length("5 kmx"), lambda: Unit("kmx"), lambda: Length(5, "kmx")]:
+    try:
+        invalid()
+        raise AssertionError("no ValueError")
+    except ValueError:
+        pass
+try:
+    Length(5, 3)
+    raise AssertionError("no TypeError")
+except TypeError:
+    pass
+"#,
+            Some(&globals),
+            None,
+        )
+        .unwrap();
+    });
+}
+
+#[cfg(feature = "diesel-sqlite")]
+#[test]
+fn test_diesel_columns() {
+    use diesel::prelude::*;
+    use length::sql::Meters;
+
+    diesel::table! {
+        routes (id) {
+            id -> Integer,
+            distance -> Text,
+            meters -> Double,
+        }
+    }
+
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query(
+        "CREATE TABLE routes (id INTEGER PRIMARY KEY, distance TEXT NOT NULL, meters DOUBLE NOT NULL)",
+    )
+    .execute(&mut connection)
+    .unwrap();
+
+    for (id, string) in [(1, "2.5 km"), (2, "1 mi")] {
+        let length = Length::new_string(string).unwrap();
+        diesel::insert_into(routes::table)
+            .values((
+                routes::id.eq(id),
+                routes::distance.eq(&length),
+                routes::meters.eq(Meters::from(length.clone())),
+            ))
+            .execute(&mut connection)
+            .unwrap();
+    }
+
+    let (distance, meters): (Length, Meters) = routes::table
+        .select((routes::distance, routes::meters))
+        .filter(routes::id.eq(2))
+        .first(&mut connection)
+        .unwrap();
+    assert_eq!(1.0, distance.value);
+    assert_eq!(Unit::Imperial(Mile), distance.unit);
+    assert_eq!(Meters(Length::new_value_unit(1_609.344, Meter)), meters);
+
+    let total: Option<f64> = routes::table
+        .select(diesel::dsl::sum(routes::meters))
+        .first(&mut connection)
+        .unwrap();
+    assert_eq!(Some(4_109.344), total);
+
+    diesel::sql_query("UPDATE routes SET distance = '5 furlongs per fortnight' WHERE id = 1")
+        .execute(&mut connection)
+        .unwrap();
+    assert!(routes::table
+        .select(routes::distance)
+        .filter(routes::id.eq(1))
+        .first::<Length>(&mut connection)
+        .is_err());
+}
+
+#[test]
+fn test_localized_unit_names() {
+    use length::locale::Locale;
+
+    assert_eq!(
+        "Kilometer",
+        Locale::German.unit_name(Unit::Metric(Kilometer))
+    );
+    assert_eq!(
+        "Zoll",
+        Locale::German.unit_plural_name(Unit::Imperial(Inch))
+    );
+    assert_eq!(
+        "pouces",
+        Locale::French.unit_plural_name(Unit::Imperial(Inch))
+    );
+    assert_eq!("milla", Locale::Spanish.unit_name(Unit::Imperial(Mile)));
+    assert_eq!("mile", Locale::English.unit_name(Unit::Imperial(Mile)));
+
+    let distance = Length::new_value_unit(2.5, Kilometer);
+    assert_eq!("2,5 Kilometer", distance.format_localized(Locale::German));
+    assert_eq!("2,5 kilomètres", distance.format_localized(Locale::French));
+    assert_eq!("2,5 kilómetros", distance.format_localized(Locale::Spanish));
+    assert_eq!("2.5 kilometers", distance.format_localized(Locale::English));
+    assert_eq!(
+        "1 Meile",
+        Length::new_value_unit(1, Mile).format_localized(Locale::German)
+    );
+    assert_eq!(
+        "1,5 pied",
+        Length::new_value_unit(1.5, Foot).format_localized(Locale::French)
+    );
+    assert_eq!(
+        "4 años luz",
+        Length::new_value_unit(4, Lightyear).format_localized(Locale::Spanish)
+    );
+
+    for (string, locale, value, unit) in [
+        (
+            "2,5 Kilometer",
+            Locale::German,
+            2.5,
+            Unit::Metric(Kilometer),
+        ),
+        ("12 zoll", Locale::German, 12.0, Unit::Imperial(Inch)),
+        (
+            "3 Astronomische Einheiten",
+            Locale::German,
+            3.0,
+            Unit::Astronomic(AstronomicalUnit),
+        ),
+        ("1,5 pouce", Locale::French, 1.5, Unit::Imperial(Inch)),
+        (
+            "4 années-lumière",
+            Locale::French,
+            4.0,
+            Unit::Astronomic(Lightyear),
+        ),
+        ("7 millas", Locale::Spanish, 7.0, Unit::Imperial(Mile)),
+        ("2 mi", Locale::Spanish, 2.0, Unit::Imperial(Mile)),
+        ("2.5 miles", Locale::English, 2.5, Unit::Imperial(Mile)),
+    ] {
+        let length = Length::parse_localized(string, locale).unwrap();
+        assert_eq!(value, length.value, "{}", string);
+        assert_eq!(unit, length.unit, "{}", string);
+    }
+
+    for locale in [Locale::German, Locale::French, Locale::Spanish] {
+        for unit in Unit::all().filter(|unit| !matches!(unit, Unit::Custom(_))) {
+            let length = Length::new_value_unit(3.25, unit);
+            assert_eq!(
+                length,
+                Length::parse_localized(&length.format_localized(locale), locale).unwrap()
+            );
+        }
+    }
+
+    assert!(Length::parse_localized("3 Meilen", Locale::French).is_err());
+}
+
+#[test]
+fn test_to_words() {
+    for (length, words) in [
+        (Length::new_value_unit(5, Kilometer), "five kilometers"),
+        (Length::new_value_unit(3.5, Inch), "three and a half inches"),
+        (Length::new_value_unit(1, Foot), "one foot"),
+        (Length::new_value_unit(1.5, Foot), "one and a half feet"),
+        (Length::new_value_unit(0, Meter), "zero meters"),
+        (Length::new_value_unit(0.5, Inch), "half an inch"),
+        (Length::new_value_unit(0.25, Mile), "a quarter of a mile"),
+        (
+            Length::new_value_unit(0.75, Meter),
+            "three quarters of a meter",
+        ),
+        (
+            Length::new_value_unit(12.25, Yard),
+            "twelve and a quarter yards",
+        ),
+        (
+            Length::new_value_unit(2.75, Meter),
+            "two and three quarters meters",
+        ),
+        (
+            Length::new_value_unit(0.1, Millimeter),
+            "zero point one millimeters",
+        ),
+        (
+            Length::new_value_unit(-2.05, Meter),
+            "minus two point zero five meters",
+        ),
+        (
+            Length::new_value_unit(123_456, Meter),
+            "one hundred twenty-three thousand four hundred fifty-six meters",
+        ),
+        (
+            Length::new_value_unit(1_000_000, Meter),
+            "one million meters",
+        ),
+        (Length::new_value_unit(40, Lightyear), "forty light-years"),
+        (
+            Length::new_value_unit(1.5e21, Meter),
+            "one point five times ten to the power of twenty-one meters",
+        ),
+        (
+            Length::new_value_unit(1e-9, Meter),
+            "one times ten to the power of minus nine meters",
+        ),
+        (
+            Length::new_value_unit(f64::NEG_INFINITY, Meter),
+            "minus infinity meters",
+        ),
+    ] {
+        assert_eq!(words, length.to_words());
+    }
+}
+
+#[test]
+fn test_format_engineering() {
+    for (length, text) in [
+        (Length::new_value_unit(0.000042, Meter), "42 µm"),
+        (Length::new_value_unit(1.3e7, Meter), "13 Mm"),
+        (Length::new_value_unit(-2_500, Meter), "-2.5 km"),
+        (Length::new_value_unit(1, Meter), "1 m"),
+        (Length::new_value_unit(1_000, Meter), "1 km"),
+        (Length::new_value_unit(999, Meter), "999 m"),
+        (Length::new_value_unit(12, Centimeter), "120 mm"),
+        (Length::new_value_unit(1, Inch), "25.4 mm"),
+        (Length::new_value_unit(1, Lightyear), "9.4607304725808 Pm"),
+        (Length::new_value_unit(1, PlanckLength), "0.00001616255 qm"),
+        (Length::new_value_unit(0, Kilometer), "0 m"),
+        (Length::new_value_unit(1e-31, Meter), "0.1 qm"),
+        (Length::new_value_unit(5e33, Meter), "5000 Qm"),
+    ] {
+        assert_eq!(text, length.format_engineering());
+    }
+}
+
+#[test]
+fn test_exp_formatting() {
+    let length = Length::new_value_unit(1500, Meter);
+    assert_eq!("1.5e3 m", format!("{:e}", length));
+    assert_eq!("1.5E3 m", format!("{:E}", length));
+    assert_eq!("1.500e3 m", format!("{:.3e}", length));
+    assert_eq!("1.5e3 m   ", format!("{:<10e}", length));
+    assert_eq!(
+        "-2.5e-9 m",
+        format!("{:e}", Length::new_value_unit(-2.5e-9, Meter))
+    );
+    assert_eq!(
+        "4.2e-6 µm",
+        format!("{:e}", Length::new_value_unit(4.2e-6, Micrometer))
+    );
+    assert_eq!(
+        "4.2e-6 um",
+        format!("{:#e}", Length::new_value_unit(4.2e-6, Micrometer))
+    );
+    assert_eq!(
+        "1.2E10 ly",
+        format!("{:E}", Length::new_value_unit(1.2e10, Lightyear))
+    );
+    assert_eq!(
+        "2.5e0 km",
+        format!("{:e}", Length::new_value_unit(2.5_f32, Kilometer))
+    );
+}
+
+#[test]
+fn test_format_with() {
+    let length = Length::new_value_unit(2.5, Kilometer);
+
+    assert_eq!("2.5 km", length.format_with(&DisplayOptions::new()));
+    assert_eq!(
+        "2.500 km",
+        length.format_with(&DisplayOptions::new().precision(3))
+    );
+    assert_eq!(
+        "2.5 km",
+        length.format_with(&DisplayOptions::new().precision(3).trim_zeros(true))
+    );
+    assert_eq!(
+        "3 km",
+        Length::new_value_unit(3, Kilometer)
+            .format_with(&DisplayOptions::new().precision(2).trim_zeros(true))
+    );
+    let trimmed = DisplayOptions::new().precision(4).trim_zeros(true);
+    assert_eq!(
+        "100 m",
+        Length::new_value_unit(100, Meter).format_with(&trimmed)
+    );
+    assert_eq!(
+        "10.0501 m",
+        Length::new_value_unit(10.0501, Meter).format_with(&trimmed)
+    );
+    assert_eq!(
+        "-0.002 m",
+        Length::new_value_unit(-0.002, Meter).format_with(&trimmed)
+    );
+    assert_eq!(
+        "1.2 µm",
+        Length::new_value_unit(1.2, Micrometer).format_with(&trimmed)
+    );
+    assert_eq!(
+        "2.5km",
+        length.format_with(&DisplayOptions::new().space(false))
+    );
+    assert_eq!(
+        "2.5 kilometers",
+        length.format_with(&DisplayOptions::new().names(true))
+    );
+    assert_eq!(
+        "1 foot",
+        Length::new_value_unit(1, Foot).format_with(&DisplayOptions::new().names(true))
+    );
+    assert_eq!(
+        "2500 m",
+        length.format_with(&DisplayOptions::new().unit(Meter))
+    );
+    assert_eq!(
+        "1.55mi",
+        length.format_with(&DisplayOptions::new().unit(Mile).precision(2).space(false))
+    );
+    assert_eq!(
+        "3000um",
+        Length::new_value_unit(3, Millimeter).format_with(
+            &DisplayOptions::new()
+                .unit(Micrometer)
+                .ascii(true)
+                .space(false)
+        )
+    );
+
+    let options = DisplayOptions {
+        precision: Some(1),
+        names: true,
+        ..DisplayOptions::default()
+    };
+    assert_eq!(options, DisplayOptions::new().precision(1).names(true));
+    assert_eq!("2.5 kilometers", length.format_with(&options));
+}
+
+#[test]
+fn test_with_original_string() {
+    let parsed = Length::new_string("6 ft 2 in").unwrap();
+    let height = parsed
+        .to(Centimeter)
+        .with_original_string(parsed.get_original_string());
+    assert_eq!(height.get_original_string(), "6 ft 2 in");
+    assert_approx(height.value, 187.96);
+
+    let total = Length::new_string("2 km")
+        .unwrap()
+        .add(Length::new_string("500 m").unwrap())
+        .with_original_string("2 km + 500 m");
+    assert_eq!(total.get_original_string(), "2 km + 500 m");
+    assert_eq!(total.value, 2.5);
+
+    let decoded = Length::from_bytes(&total.to_bytes()).unwrap();
+    assert_eq!(decoded.get_original_string(), "2 km + 500 m");
+
+    let cleared = Length::new_string("3 m").unwrap().with_original_string("");
+    assert_eq!(cleared.get_original_string(), "");
+}
+
+#[test]
+fn test_to_checked() {
+    for (length, unit) in [
+        (Length::new_value_unit(3, Kilometer), Unit::Metric(Meter)),
+        (
+            Length::new_value_unit(1, Yoctometer),
+            Unit::Metric(Yottameter),
+        ),
+        (
+            Length::new_value_unit(12.7, Lightyear),
+            Unit::Subatomic(PlanckLength),
+        ),
+        (Length::new_value_unit(0, Foot), Unit::Metric(Meter)),
+        (Length::new_value_unit(-5.5, Mile), Unit::Imperial(Inch)),
+    ] {
+        let converted = length.to_checked(unit).unwrap();
+        assert_eq!(length.to(unit), converted);
+    }
+
+    let underflow = Length::new_value_unit(3e-275, Yoctometer)
+        .to_checked(Yottameter)
+        .unwrap_err();
+    assert!(underflow.relative_error > 1e-3);
+    assert_eq!(underflow.epsilon, Length::DEFAULT_PRECISION_EPSILON);
+    assert_eq!(underflow.converted.unit, Unit::Metric(Yottameter));
+
+    let overflow = Length::new_value_unit(1e300, Yottameter)
+        .to_checked(Yoctometer)
+        .unwrap_err();
+    assert!(overflow.converted.value.is_infinite());
+    assert!(overflow.relative_error.is_infinite());
+    assert!(overflow
+        .to_string()
+        .starts_with("conversion into ym has a relative error of inf"));
+
+    let tiny = Length::new_value_unit(3e-275, Yoctometer);
+    assert!(tiny.to_checked_with(Yottameter, 0.5).is_ok());
+    assert!(tiny.to_checked_with(Yottameter, 1e-3).is_err());
+}
+
+#[test]
+fn test_traditional_units() {
+    use length::TraditionalUnit;
+
+    let li = Length::new_string("2 li").unwrap();
+    assert_eq!(li.unit, Unit::Traditional(TraditionalUnit::Li));
+    assert_eq!(li.to_string(), "2 li");
+    assert_eq!(li.to(Kilometer).value, 1.0);
+    assert_eq!(li.to(TraditionalUnit::Chi).value, 3_000.0);
+    assert_eq!(
+        Length::new_string("1 zhang")
+            .unwrap()
+            .to(TraditionalUnit::Cun)
+            .value,
+        100.0
+    );
+
+    assert_eq!(
+        Length::new_string("1 ken")
+            .unwrap()
+            .to(TraditionalUnit::Shaku)
+            .value,
+        6.0
+    );
+    assert_eq!(
+        Length::new_string("1 ri")
+            .unwrap()
+            .to(TraditionalUnit::Ken)
+            .value,
+        2_160.0
+    );
+    assert_approx(
+        Length::new_string("1 shaku").unwrap().to(Meter).value,
+        10.0 / 33.0,
+    );
+
+    assert_eq!(
+        Length::new_string("1 verst")
+            .unwrap()
+            .to(TraditionalUnit::Arshin)
+            .value,
+        1_500.0
+    );
+    assert_eq!(Length::new_string("1 arshin").unwrap().to(Inch).value, 28.0);
+    assert_eq!(Length::new_string("3 versts").unwrap().value, 3.0);
+    assert_eq!(
+        Length::new_string("3 versts").unwrap().unit,
+        Unit::Traditional(TraditionalUnit::Verst)
+    );
+
+    let normalized = Length::new_value_unit(3_000, TraditionalUnit::Chi).normalize();
+    assert_eq!(normalized.unit, Unit::Traditional(TraditionalUnit::Li));
+    assert_eq!(normalized.value, 2.0);
+    let normalized = Length::new_value_unit(12, TraditionalUnit::Shaku).normalize();
+    assert_eq!(normalized.unit, Unit::Traditional(TraditionalUnit::Ken));
+    assert_eq!(normalized.value, 2.0);
+    let normalized = Length::new_value_unit(3_000, TraditionalUnit::Arshin).normalize();
+    assert_eq!(normalized.unit, Unit::Traditional(TraditionalUnit::Verst));
+    assert_eq!(normalized.value, 2.0);
+
+    let in_japanese = Length::new_string("5 km")
+        .unwrap()
+        .normalize_to_system(UnitSystem::Japanese);
+    assert_eq!(in_japanese.unit, Unit::Traditional(TraditionalUnit::Ri));
+    assert_eq!(
+        Unit::Traditional(TraditionalUnit::Zhang).system(),
+        UnitSystem::Chinese
+    );
+    assert_eq!(
+        Unit::Traditional(TraditionalUnit::Verst).system(),
+        UnitSystem::Russian
+    );
+    assert_eq!(Ok(UnitSystem::Chinese), "Chinese".parse());
+    assert_eq!(UnitSystem::Russian.to_string(), "russian");
+    assert_eq!(UnitSystem::Japanese.units().len(), 3);
+    assert!(Unit::Traditional(TraditionalUnit::Cun).is_traditional());
+
+    assert_eq!(Unit::Traditional(TraditionalUnit::Verst).code(), 708);
+    assert_eq!(
+        Unit::try_from_code(704),
+        Some(Unit::Traditional(TraditionalUnit::Shaku))
+    );
+    assert_eq!(
+        Unit::Traditional(TraditionalUnit::Arshin).plural_name(),
+        "arshins"
+    );
+}
+
+#[test]
+fn test_scandinavian_mil() {
+    use length::locale::Locale;
+    use length::{LengthParser, TraditionalUnit, UnitSystem};
+
+    let mil = Unit::Traditional(TraditionalUnit::Mil);
+    assert_eq!(Length::new_value_unit(1.5, mil).to(Kilometer).value, 15.0);
+    assert_eq!(Length::new_value_unit(1.5, mil).to_string(), "1.5 smil");
+    assert_eq!(Length::new_string("1.5 smil").unwrap().unit, mil);
+    assert_eq!(mil.system(), UnitSystem::Scandinavian);
+    assert_eq!(
+        "scandinavian".parse::<UnitSystem>().unwrap(),
+        UnitSystem::Scandinavian
+    );
+    assert_eq!("Scandinavian miles".parse::<Unit>().ok(), Some(mil));
+    assert_eq!(Unit::try_from_code(mil.code()), Some(mil));
+
+    // "mil" stays a thou, unless the parser is told otherwise.
+    assert_eq!("mil".parse::<Unit>().unwrap(), Unit::Imperial(Thou));
+    assert_eq!(
+        Length::new_string("3 mil").unwrap().unit,
+        Unit::Imperial(Thou)
+    );
+    let parser = LengthParser::new().scandinavian_mil(true);
+    let length = parser.parse("3 mil").unwrap();
+    assert_eq!(length.unit, mil);
+    assert_eq!(length.value, 3.0);
+    assert_eq!(length.get_original_string(), "3 mil");
+    assert_eq!(parser.parse("3 th").unwrap().unit, Unit::Imperial(Thou));
+    assert_eq!(
+        parser.parse("3 MIL").unwrap_err(),
+        LengthParser::new().parse("3 MIL").unwrap_err()
+    );
+    assert_eq!(parser.lenient(true).parse("3 MIL").unwrap().unit, mil);
+    assert_eq!(
+        LengthParser::new()
+            .decimal_comma(true)
+            .thousands_separator('.')
+            .scandinavian_mil(true)
+            .parse("1.234,5 mil")
+            .unwrap()
+            .value,
+        1_234.5
+    );
+
+    assert_eq!(
+        Length::new_value_unit(2, mil).format_localized(Locale::German),
+        "2 Skandinavische Meilen"
+    );
+    assert_eq!(
+        Length::parse_localized("2 Skandinavische Meilen", Locale::German)
+            .unwrap()
+            .unit,
+        mil
+    );
+}
+
+#[test]
+fn test_display_parse_round_trip() {
+    for unit in Unit::all() {
+        // Custom units shadow the historical units with the same symbol, see test_custom_units.
+        if CustomUnit::find(&unit.symbol()).is_some_and(|custom| Unit::Custom(custom) != unit) {
+            continue;
+        }
+
+        let length = Length::new_value_unit(1.5, unit);
+        let parsed = length.to_string().parse::<Length>().unwrap();
+
+        assert_eq!(parsed.unit, unit, "{} does not round-trip", length);
+        assert_eq!(parsed.value, 1.5);
+    }
+}
+
+#[cfg(feature = "historical")]
+#[test]
+fn test_historical_units() {
+    use length::locale::Locale;
+    use length::{HistoricalUnit, UnitSystem};
+
+    let bridge = Length::new_string("364.4 smoots").unwrap();
+    assert_eq!(bridge.unit, Unit::Historical(HistoricalUnit::Smoot));
+    assert_approx(bridge.to(Meter).value, 620.13592);
+
+    assert_eq!(Length::new_string("1 ftm").unwrap().to(Foot).value, 6.0);
+    assert_eq!(Length::new_string("2 cubits").unwrap().to(Inch).value, 36.0);
+    assert_eq!(
+        Length::new_value_unit(1, HistoricalUnit::FootballField)
+            .to(Yard)
+            .value,
+        100.0
+    );
+    assert_eq!(
+        Length::new_string("3 football fields").unwrap().unit,
+        Unit::Historical(HistoricalUnit::FootballField)
+    );
+    assert_eq!(
+        Length::new_value_unit(2.5, HistoricalUnit::FootballField).to_string(),
+        "2.5 fbf"
+    );
+    assert_eq!(
+        Length::new_value_unit(2.5, HistoricalUnit::FootballField).to_words(),
+        "two and a half football fields"
+    );
+    assert_eq!(
+        Length::new_value_unit(3, HistoricalUnit::Fathom).format_localized(Locale::German),
+        "3 Faden"
+    );
+
+    assert_eq!(
+        Unit::Historical(HistoricalUnit::Cubit).system(),
+        UnitSystem::Historical
+    );
+    assert_eq!(
+        Unit::try_from_code(803),
+        Some(Unit::Historical(HistoricalUnit::FootballField))
+    );
+    let normalized = Length::new_value_unit(1_000, HistoricalUnit::Cubit).normalize();
+    assert_eq!(normalized.unit, Unit::Historical(HistoricalUnit::Cubit));
+
+    // The land league is an imperial unit.
+    assert_eq!(Length::new_string("1 lea").unwrap().to(Mile).value, 3.0);
+
+    // A custom unit shadows the historical unit with the same symbol, like in test_custom_units.
+    let smoot = CustomUnit::register("smoot", 1.7018).unwrap();
+    assert_eq!("smoot".parse::<Unit>(), Ok(Unit::Custom(smoot)));
+    assert_eq!(
+        Length::new_string("2 smoots").unwrap().unit,
+        Unit::Historical(HistoricalUnit::Smoot)
+    );
+    assert_eq!(CustomUnit::register("ft", 0.3), None);
+}
+
+#[test]
+fn test_compare_to_common_objects() {
+    assert_eq!(
+        Length::new_value_unit(1, Meter).compare_to_common_objects(),
+        "about 0.011 football fields"
+    );
+    assert_eq!(
+        Length::new_value_unit(-1_000, Meter).compare_to_common_objects(),
+        "about 3 Eiffel Towers"
+    );
+    assert_eq!(
+        Length::new_value_unit(1_000, Kilometer).compare_to_common_objects(),
+        "about 3000 Eiffel Towers"
+    );
+    assert_eq!(
+        Length::new_value_unit(384_400, Kilometer).compare_to_common_objects(),
+        "1 distance to the Moon"
+    );
+    assert_eq!(
+        Length::new_value_unit(1.5, Unit::Astronomic(AstronomicalUnit)).compare_to_common_objects(),
+        "about 580 distances to the Moon"
+    );
+
+    assert!(length::register_common_object(
+        "marathon",
+        "marathons",
+        Length::new_value_unit(42.195, Kilometer)
+    ));
+    assert!(!length::register_common_object(
+        "nothing",
+        "nothings",
+        Length::new_value_unit(0, Meter)
+    ));
+    assert_eq!(
+        Length::new_value_unit(1_000, Kilometer).compare_to_common_objects(),
+        "about 24 marathons"
+    );
+    assert!(length::register_common_object(
+        "marathon",
+        "marathons",
+        Length::new_value_unit(26.2, Mile)
+    ));
+    assert_eq!(
+        Length::new_value_unit(262, Mile).compare_to_common_objects(),
+        "10 marathons"
+    );
+}
+
+#[test]
+fn test_tuple_conversions() {
+    let length = Length::from((3.0, Kilometer));
+    assert_eq!(length.value, 3.0);
+    assert_eq!(length.unit, Unit::Metric(Kilometer));
+    let length: Length = (1.5, Unit::Imperial(Mile)).into();
+    assert_eq!(length.unit, Unit::Imperial(Mile));
+    let length = Length::from((7_f32, Inch));
+    assert_eq!(length.value, 7.0);
+
+    let (value, unit): (f64, Unit) = Length::new_value_unit(4, Yard).into();
+    assert_eq!(value, 4.0);
+    assert_eq!(unit, Unit::Imperial(Yard));
+    let borrowed = Length::new_value_unit(2, Meter);
+    let (value, unit) = <(f64, Unit)>::from(&borrowed);
+    assert_eq!((value, unit), (2.0, Unit::Metric(Meter)));
+    assert_eq!(Length::from(<(f64, Unit)>::from(&borrowed)), borrowed);
+}
+
+#[test]
+fn test_value_accessors() {
+    let length = Length::new_value_unit(1, Mile);
+    assert_eq!(length.value_in(Foot), 5_280.0);
+    assert_eq!(length.value_in(Unit::Metric(Meter)), 1_609.344);
+    assert_eq!(length.as_meters(), 1_609.344);
+    assert_approx(length.as_kilometers(), 1.609_344);
+    assert_eq!(length.as_yards(), 1_760.0);
+    assert_eq!(length.as_feet(), 5_280.0);
+    assert_eq!(length.as_inches(), 63_360.0);
+    assert_eq!(length.as_miles(), 1.0);
+    assert_approx(length.as_centimeters(), 160_934.4);
+    assert_approx(length.as_millimeters(), 1_609_344.0);
+    assert_approx(
+        Length::new_value_unit(4.2465, Lightyear).as_light_years(),
+        4.2465,
+    );
+
+    // The length itself is not converted.
+    assert_eq!(length.unit, Unit::Imperial(Mile));
+    assert_eq!(Length::<f32>::from_scalar(3.0, Foot).as_inches(), 36.0);
+}
+
+#[test]
+fn test_unit_constructors() {
+    use length::TraditionalUnit;
+
+    assert_eq!(Length::meters(5.0), Length::new_value_unit(5, Meter));
+    assert_eq!(Length::kilometers(2.5).unit, Unit::Metric(Kilometer));
+    assert_eq!(Length::quectometers(1).unit, Unit::Metric(Quectometer));
+    assert_eq!(Length::inches(12).value, 12.0);
+    assert_eq!(Length::feet(3).to(Yard).value, 1.0);
+    assert_eq!(Length::thou(1).unit, Unit::Imperial(Thou));
+    assert_eq!(Length::light_years(4.2).unit, Unit::Astronomic(Lightyear));
+    assert_eq!(Length::solar_radii(1).unit, Unit::Astronomic(SolarRadius));
+    assert_eq!(Length::didot_points(12).unit, Unit::Typographic(DidotPoint));
+    assert_eq!(Length::survey_feet(1).unit, Unit::UsSurvey(SurveyFoot));
+    assert_eq!(
+        Length::planck_lengths(1).unit,
+        Unit::Subatomic(PlanckLength)
+    );
+    assert_eq!(
+        Length::scandinavian_miles(1).unit,
+        Unit::Traditional(TraditionalUnit::Mil)
+    );
+    assert_eq!(Length::li(2).to(Kilometer).value, 1.0);
+}
+
+#[test]
+fn test_scoped_default_unit() {
+    use length::config;
+
+    // Only the scoped override is tested here, as the global default unit would affect the
+    // tests, that run in parallel.
+    let (outer, inner) = config::with_default_unit(Foot, || {
+        let inner = config::with_default_unit(Kilometer, Length::default);
+        (Length::default(), inner)
+    });
+    assert_eq!(outer.unit, Unit::Imperial(Foot));
+    assert_eq!(outer.value, 0.0);
+    assert_eq!(inner.unit, Unit::Metric(Kilometer));
+    assert_eq!(Length::default().unit, Unit::Metric(Meter));
+    assert_eq!(config::default_unit(), Unit::Metric(Meter));
+
+    let other_thread = config::with_default_unit(Mile, || {
+        std::thread::spawn(|| Length::default().unit)
+            .join()
+            .unwrap()
+    });
+    assert_eq!(other_thread, Unit::Metric(Meter));
+
+    let panicked = std::panic::catch_unwind(|| {
+        config::with_default_unit(Yard, || panic!("restores the default unit"))
+    });
+    assert!(panicked.is_err());
+    assert_eq!(config::default_unit(), Unit::Metric(Meter));
+    assert_eq!(
+        config::with_default_unit(Yard, Length::new).unit,
+        Unit::Imperial(Yard)
+    );
+}
+
+#[test]
+fn test_lengths_collection() {
+    use length::Lengths;
+
+    let mut lengths = Lengths::from_values(vec![2.0, 0.5, 1.0], Kilometer);
+    lengths.push(Length::new_value_unit(1_500, Meter));
+    assert_eq!(lengths.len(), 4);
+    assert_eq!(lengths.values(), &[2.0, 0.5, 1.0, 1.5]);
+    assert_eq!(lengths.sum().value, 5.0);
+    assert_eq!(lengths.mean().unwrap().value, 1.25);
+    assert_eq!(lengths.min().unwrap().value, 0.5);
+    assert_eq!(lengths.max().unwrap().value, 2.0);
+    assert_eq!(lengths.max().unwrap().unit, Unit::Metric(Kilometer));
+
+    lengths.sort();
+    assert_eq!(lengths.values(), &[0.5, 1.0, 1.5, 2.0]);
+    lengths.convert_all(Meter);
+    assert_eq!(lengths.values(), &[500.0, 1_000.0, 1_500.0, 2_000.0]);
+    assert_eq!(lengths.get(0).unwrap().unit, Unit::Metric(Meter));
+
+    let long: Lengths = lengths
+        .iter()
+        .filter(|length| length.value > 1_000.0)
+        .collect();
+    assert_eq!(long.values(), &[1_500.0, 2_000.0]);
+    let mut in_feet: Vec<Length> = long.into_iter().map(|length| length.to(Foot)).collect();
+    assert_eq!(in_feet.len(), 2);
+
+    let empty = Lengths::<f64>::new(Inch);
+    assert!(empty.is_empty());
+    assert_eq!(empty.sum().value, 0.0);
+    assert!(empty.mean().is_none());
+    assert!(empty.min().is_none());
+
+    let mut collected: Lengths = Vec::new().into_iter().collect();
+    assert_eq!(collected.unit(), Unit::Metric(Meter));
+    collected.extend(in_feet.drain(..));
+    assert_eq!(collected.values(), &[1_500.0, 2_000.0]);
+    assert_eq!(
+        Lengths::from_values(vec![3_f32, 4.0], Foot).sum().value,
+        7.0
+    );
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn test_simd_conversion() {
+    let values: Vec<f64> = (-50..50).map(|value| f64::from(value) * 1.25).collect();
+    let exact = Length::convert_slice(&values, Mile, Kilometer);
+    let fast = length::simd::convert_slice(&values, Mile, Kilometer);
+    assert_eq!(fast.len(), values.len());
+    for (fast, exact) in fast.iter().zip(&exact) {
+        assert!((fast - exact).abs() <= exact.abs() * 4.0 * f64::EPSILON);
+    }
+
+    let mut in_place = values.clone();
+    length::simd::convert_slice_in_place(&mut in_place, Mile, Kilometer);
+    assert_eq!(
+        in_place,
+        length::simd::convert_slice(&values, Mile, Kilometer)
+    );
+    assert!(length::simd::convert_slice(&[], Foot, Meter).is_empty());
+}
+
+#[test]
+fn test_parse_lines() {
+    use std::error::Error;
+    use std::io::{BufReader, Cursor};
+
+    let input = "1 mi\r\n  \n5 ft 3 in\nfoo\n-2e3 mm\n";
+    let results: Vec<_> = length::io::parse_lines(BufReader::new(input.as_bytes())).collect();
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().unwrap().unit, Unit::Imperial(Mile));
+    assert_eq!(results[1].as_ref().unwrap().to(Inch).value, 63.0);
+    let error = results[2].as_ref().unwrap_err();
+    assert_eq!(
+        *error,
+        ParseLengthError::Line {
+            line: 4,
+            error: Box::new(Length::parse("foo").unwrap_err()),
+        }
+    );
+    assert_eq!(
+        error.source().unwrap().to_string(),
+        Length::parse("foo").unwrap_err().to_string()
+    );
+    assert_eq!(results[3].as_ref().unwrap().value, -2_000.0);
+
+    let invalid_utf8 = Cursor::new(b"1 m\n\xff\xfe m\n2 m\n".to_vec());
+    let results: Vec<_> = length::io::parse_lines(invalid_utf8).collect();
+    assert_eq!(results.len(), 2);
+    assert!(matches!(
+        results[1],
+        Err(ParseLengthError::Io {
+            line: 2,
+            kind: std::io::ErrorKind::InvalidData,
+            ..
+        })
+    ));
+    assert_eq!(length::io::parse_lines(Cursor::new("")).count(), 0);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_csv_column() {
+    use length::csv::{CsvColumn, CsvError};
+
+    let input = "id,length,note\n1,1 mi,\"a, b\"\n2,\"5280 ft\",c\n3,1609.344,d\n";
+    let column = CsvColumn::new("length").declared_unit(Meter);
+    let lengths = column.read(input.as_bytes()).unwrap();
+    assert_eq!(lengths.len(), 3);
+    assert_eq!(lengths[1].unit, Unit::Imperial(Foot));
+    assert_eq!(lengths[2].unit, Unit::Metric(Meter));
+
+    let mut output = Vec::new();
+    assert_eq!(
+        column.convert(input.as_bytes(), &mut output, Mile).unwrap(),
+        3
+    );
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "id,length,note\n1,1,\"a, b\"\n2,1,c\n3,1,d\n"
+    );
+
+    match CsvColumn::new("length").read(input.as_bytes()) {
+        Err(CsvError::Parse { line, error }) => {
+            assert_eq!(line, 4);
+            assert!(matches!(error, ParseLengthError::MissingUnit { .. }));
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert!(matches!(
+        CsvColumn::new("width").read(input.as_bytes()),
+        Err(CsvError::MissingColumn { .. })
+    ));
+    assert!(matches!(
+        column.read("length\n1 m\n2 m,3\n".as_bytes()),
+        Err(CsvError::Csv(_))
+    ));
+}